@@ -1,7 +1,6 @@
 #![feature(test)]
 
 extern crate test;
-use rand::prelude::*;
 use test::{black_box, Bencher};
 
 use specialized_div_rem::*;
@@ -20,6 +19,21 @@ pub fn i128_div_rem_std(duo: i128, div: i128) -> (i128, i128) {
     (duo / div, duo % div)
 }
 
+/// Returns a random value from a generator that is seeded once per thread
+fn random<T: Random>() -> T {
+    use std::{
+        cell::RefCell,
+        hash::{BuildHasher, Hasher},
+    };
+
+    thread_local! {
+        static RNG: RefCell<Rng> = RefCell::new(Rng::new(
+            std::collections::hash_map::RandomState::new().build_hasher().finish(),
+        ));
+    }
+    RNG.with(|rng| rng.borrow_mut().random())
+}
+
 /// This macro can create multiple benchmarking functions that run 8 pairs of random integers
 /// through a division function. Two masks are applied to `duo` and `div` for testing different
 /// ranges of integers.
@@ -38,7 +52,7 @@ macro_rules! bencher {
         $(
             #[bench]
             fn $test_name(bencher: &mut Bencher) {
-                let n = std::$ty::MAX.count_ones();
+                let n = $ty::MAX.count_ones();
                 let lhs = random::<$ty>() & ($ty::MAX >> (n - $arg0_sb));
                 let mut rhs = random::<$ty>() & ($ty::MAX >> (n - $arg1_sb));
                 if rhs == 0 {
@@ -62,6 +76,181 @@ fn u128_div_128_64_asymmetric(bencher: &mut Bencher) {
     bencher.iter(|| black_box(u128_div_asymmetric(black_box(lhs), black_box(rhs))))
 }
 
+// the 128 by 64 bit division step, with the hardware instruction where there is one and with the
+// reciprocal based division of targets without one
+#[bench]
+fn u128_by_u64_step_hardware(bencher: &mut Bencher) {
+    let div = random::<u64>() | 1;
+    let duo = (((random::<u64>() % div) as u128) << 64) | (random::<u64>() as u128);
+    bencher.iter(|| black_box(u128_by_u64_div_rem(black_box(duo), black_box(div))))
+}
+
+#[bench]
+fn u128_by_u64_step_reciprocal(bencher: &mut Bencher) {
+    let div = random::<u64>() | 1;
+    let duo = (((random::<u64>() % div) as u128) << 64) | (random::<u64>() as u128);
+    bencher.iter(|| {
+        black_box(u128_by_u64_div_rem_reciprocal(
+            black_box(duo),
+            black_box(div),
+        ))
+    })
+}
+
+// dividing many values by the same runtime divisor, with and without the precomputed divisors
+#[bench]
+fn u64_div_invariant_std(bencher: &mut Bencher) {
+    let duos: [u64; 64] = core::array::from_fn(|_| random());
+    let div = random::<u64>() >> 32 | 1;
+    bencher.iter(|| {
+        let div = black_box(div);
+        black_box(&duos).map(|duo| duo / div)
+    })
+}
+
+#[bench]
+fn u64_div_invariant_precomputed(bencher: &mut Bencher) {
+    let duos: [u64; 64] = core::array::from_fn(|_| random());
+    let div = random::<u64>() >> 32 | 1;
+    bencher.iter(|| {
+        let pre = PrecomputedDivisor64::new(black_box(div));
+        black_box(&duos).map(|duo| pre.div_of(duo))
+    })
+}
+
+#[bench]
+fn u128_div_invariant_std(bencher: &mut Bencher) {
+    let duos: [u128; 64] = core::array::from_fn(|_| random());
+    let div = random::<u128>() >> 32 | 1;
+    bencher.iter(|| {
+        let div = black_box(div);
+        black_box(&duos).map(|duo| duo / div)
+    })
+}
+
+#[bench]
+fn u128_div_invariant_precomputed(bencher: &mut Bencher) {
+    let duos: [u128; 64] = core::array::from_fn(|_| random());
+    let div = random::<u128>() >> 32 | 1;
+    bencher.iter(|| {
+        let pre = PrecomputedDivisor128::new(black_box(div));
+        black_box(&duos).map(|duo| pre.div_of(duo))
+    })
+}
+
+#[bench]
+fn u64_div_rem_slice_per_element(bencher: &mut Bencher) {
+    let duos: Vec<u64> = (0..256).map(|_| random()).collect();
+    let div = random::<u64>() >> 32 | 1;
+    let mut quos = vec![0; 256];
+    let mut rems = vec![0; 256];
+    bencher.iter(|| {
+        let div = black_box(div);
+        for ((duo, quo), rem) in duos.iter().zip(quos.iter_mut()).zip(rems.iter_mut()) {
+            (*quo, *rem) = target_defaults::u64_div_rem(*duo, div);
+        }
+        black_box((&quos, &rems));
+    })
+}
+
+#[bench]
+fn u64_div_rem_slice_precomputed(bencher: &mut Bencher) {
+    let duos: Vec<u64> = (0..256).map(|_| random()).collect();
+    let div = random::<u64>() >> 32 | 1;
+    let mut quos = vec![0; 256];
+    let mut rems = vec![0; 256];
+    bencher.iter(|| {
+        u64_div_rem_slice(black_box(&duos), black_box(div), &mut quos, &mut rems);
+        black_box((&quos, &rems));
+    })
+}
+
+// division that is known to be exact, with and without `u128_exact_div`
+#[bench]
+fn u128_exact_div_std(bencher: &mut Bencher) {
+    let div = random::<u128>() >> 32 | 1;
+    let duo = (random::<u128>() >> 96) * div;
+    bencher.iter(|| black_box(black_box(duo) / black_box(div)))
+}
+
+#[bench]
+fn u128_exact_div_inverse(bencher: &mut Bencher) {
+    let div = random::<u128>() >> 32 | 1;
+    let duo = (random::<u128>() >> 96) * div;
+    bencher.iter(|| black_box(u128_exact_div(black_box(duo), black_box(div))))
+}
+
+// divisibility tests, with and without `u64_is_multiple_of`
+#[bench]
+fn u64_is_multiple_of_std(bencher: &mut Bencher) {
+    let div = random::<u64>() >> 40 | 1;
+    let duo = random::<u64>();
+    bencher.iter(|| black_box(black_box(duo).is_multiple_of(black_box(div))))
+}
+
+#[bench]
+fn u64_is_multiple_of_inverse(bencher: &mut Bencher) {
+    let div = random::<u64>() >> 40 | 1;
+    let duo = random::<u64>();
+    bencher.iter(|| black_box(u64_is_multiple_of(black_box(duo), black_box(div))))
+}
+
+// modular multiplication by a fixed modulus, with and without `Barrett64` and `Montgomery64`
+#[bench]
+fn u64_mul_mod_std(bencher: &mut Bencher) {
+    let m = random::<u64>() | 1;
+    let (a, b) = (random::<u64>() % m, random::<u64>() % m);
+    bencher.iter(|| {
+        let m = black_box(m) as u128;
+        black_box((black_box(a) as u128 * black_box(b) as u128 % m) as u64)
+    })
+}
+
+#[bench]
+fn u64_mul_mod_barrett(bencher: &mut Bencher) {
+    let m = random::<u64>() | 1;
+    let (a, b) = (random::<u64>() % m, random::<u64>() % m);
+    let bar = barrett::Barrett64::new(m);
+    bencher.iter(|| black_box(black_box(bar).mul_mod(black_box(a), black_box(b))))
+}
+
+#[bench]
+fn u64_mul_mod_montgomery(bencher: &mut Bencher) {
+    let m = random::<u64>() | 1;
+    let mont = montgomery::Montgomery64::new(m);
+    let (a, b) = (mont.to_mont(random()), mont.to_mont(random()));
+    bencher.iter(|| black_box(black_box(mont).mont_mul(black_box(a), black_box(b))))
+}
+
+// `_table` against the loop of `_binary_long` for the widths where its setup dominates
+bencher!(
+    u8,
+    8,
+    8,
+    u8_div_rem_binary_long,
+    u8_div_rem_8_8_binary_long;
+    u8_div_rem_table,
+    u8_div_rem_8_8_table;
+);
+bencher!(
+    u16,
+    16,
+    8,
+    u16_div_rem_binary_long,
+    u16_div_rem_16_8_binary_long;
+    u16_div_rem_table,
+    u16_div_rem_16_8_table;
+);
+bencher!(
+    u16,
+    16,
+    16,
+    u16_div_rem_binary_long,
+    u16_div_rem_16_16_binary_long;
+    u16_div_rem_table,
+    u16_div_rem_16_16_table;
+);
+
 // These simulate the most common cases
 bencher!(
     u32,
@@ -99,6 +288,8 @@ bencher!(
     u64_div_rem_48_38_std;
     u64_div_rem_binary_long,
     u64_div_rem_48_38_binary_long;
+    u64_div_rem_float_reciprocal,
+    u64_div_rem_48_38_float_reciprocal;
     u64_div_rem_delegate,
     u64_div_rem_48_38_delegate;
     u64_div_rem_asymmetric,
@@ -114,6 +305,8 @@ bencher!(
     u64_div_rem_48_16_std;
     u64_div_rem_binary_long,
     u64_div_rem_48_16_binary_long;
+    u64_div_rem_float_reciprocal,
+    u64_div_rem_48_16_float_reciprocal;
     u64_div_rem_delegate,
     u64_div_rem_48_16_delegate;
     u64_div_rem_asymmetric,
@@ -129,6 +322,8 @@ bencher!(
     u64_div_rem_64_32_std;
     u64_div_rem_binary_long,
     u64_div_rem_64_32_binary_long;
+    u64_div_rem_float_reciprocal,
+    u64_div_rem_64_32_float_reciprocal;
     u64_div_rem_delegate,
     u64_div_rem_64_32_delegate;
     u64_div_rem_asymmetric,
@@ -147,6 +342,8 @@ bencher!(
     u128_div_rem_96_70_delegate;
     u128_div_rem_asymmetric,
     u128_div_rem_96_70_asymmetric;
+    u128_div_rem_asymmetric_float_reciprocal,
+    u128_div_rem_96_70_asymmetric_float_reciprocal;
     u128_div_rem_trifecta,
     u128_div_rem_96_70_trifecta;
 );
@@ -160,6 +357,8 @@ bencher!(
     u128_div_rem_96_32_delegate;
     u128_div_rem_asymmetric,
     u128_div_rem_96_32_asymmetric;
+    u128_div_rem_asymmetric_float_reciprocal,
+    u128_div_rem_96_32_asymmetric_float_reciprocal;
     u128_div_rem_trifecta,
     u128_div_rem_96_32_trifecta;
 );
@@ -188,6 +387,8 @@ bencher!(
     u128_div_rem_128_64_delegate;
     u128_div_rem_asymmetric,
     u128_div_rem_128_64_asymmetric;
+    u128_div_rem_asymmetric_float_reciprocal,
+    u128_div_rem_128_64_asymmetric_float_reciprocal;
     u128_div_rem_trifecta,
     u128_div_rem_128_64_trifecta;
 );
@@ -203,6 +404,8 @@ bencher!(
     u128_div_rem_128_96_delegate;
     u128_div_rem_asymmetric,
     u128_div_rem_128_96_asymmetric;
+    u128_div_rem_asymmetric_float_reciprocal,
+    u128_div_rem_128_96_asymmetric_float_reciprocal;
     u128_div_rem_trifecta,
     u128_div_rem_128_96_trifecta;
 );
@@ -218,6 +421,8 @@ bencher!(
     u128_div_rem_120_120_delegate;
     u128_div_rem_asymmetric,
     u128_div_rem_120_120_asymmetric;
+    u128_div_rem_asymmetric_float_reciprocal,
+    u128_div_rem_120_120_asymmetric_float_reciprocal;
     u128_div_rem_trifecta,
     u128_div_rem_120_120_trifecta;
 );
@@ -233,6 +438,201 @@ bencher!(
     u128_div_rem_128_8_delegate;
     u128_div_rem_asymmetric,
     u128_div_rem_128_8_asymmetric;
+    u128_div_rem_asymmetric_float_reciprocal,
+    u128_div_rem_128_8_asymmetric_float_reciprocal;
     u128_div_rem_trifecta,
     u128_div_rem_128_8_trifecta;
 );
+
+// On 32 bit x86, `_asymmetric` has no 128 by 64 bit division to use. These compare it with the
+// `_trifecta_asymmetric` chain of the 64 by 32 bit `div` instruction, which `target_defaults` does
+// not recommend until these show it to be faster than `_trifecta`.
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "x86"))]
+mod x86_bench {
+    use super::*;
+
+    bencher!(
+        u128,
+        96,
+        70,
+        u128_div_rem_std,
+        u128_div_rem_96_70_std_x86;
+        u128_div_rem_asymmetric,
+        u128_div_rem_96_70_asymmetric_x86;
+        u128_div_rem_trifecta,
+        u128_div_rem_96_70_trifecta_x86;
+        u128_div_rem_trifecta_asymmetric,
+        u128_div_rem_96_70_trifecta_asymmetric;
+    );
+    bencher!(
+        u128,
+        128,
+        64,
+        u128_div_rem_std,
+        u128_div_rem_128_64_std_x86;
+        u128_div_rem_asymmetric,
+        u128_div_rem_128_64_asymmetric_x86;
+        u128_div_rem_trifecta,
+        u128_div_rem_128_64_trifecta_x86;
+        u128_div_rem_trifecta_asymmetric,
+        u128_div_rem_128_64_trifecta_asymmetric;
+    );
+    bencher!(
+        u128,
+        128,
+        8,
+        u128_div_rem_std,
+        u128_div_rem_128_8_std_x86;
+        u128_div_rem_asymmetric,
+        u128_div_rem_128_8_asymmetric_x86;
+        u128_div_rem_trifecta,
+        u128_div_rem_128_8_trifecta_x86;
+        u128_div_rem_trifecta_asymmetric,
+        u128_div_rem_128_8_trifecta_asymmetric;
+    );
+}
+
+/// Benchmarks `compiler_builtins`, the crate that actually provides `__udivti3` and friends to
+/// Rust, against the algorithms of this crate. Unlike `bencher!`, the operands are generated from a
+/// fixed seed so that every function is run on exactly the same operand set.
+mod compiler_builtins_bench {
+    use super::*;
+
+    // `compiler_builtins` is linked into every Rust program, so its symbols can be declared here
+    // instead of depending on the crate, which only builds as a part of the standard library
+    extern "C" {
+        fn __udivmoddi4(duo: u64, div: u64, rem: *mut u64) -> u64;
+        fn __udivmodti4(duo: u128, div: u128, rem: *mut u128) -> u128;
+        fn __divmodti4(duo: i128, div: i128, rem: *mut i128) -> i128;
+        fn __udivti3(duo: u128, div: u128) -> u128;
+    }
+
+    pub fn u64_div_rem_compiler_builtins(duo: u64, div: u64) -> (u64, u64) {
+        let mut rem = 0;
+        let quo = unsafe { __udivmoddi4(duo, div, &mut rem) };
+        (quo, rem)
+    }
+    pub fn u128_div_rem_compiler_builtins(duo: u128, div: u128) -> (u128, u128) {
+        let mut rem = 0;
+        let quo = unsafe { __udivmodti4(duo, div, &mut rem) };
+        (quo, rem)
+    }
+    pub fn i128_div_rem_compiler_builtins(duo: i128, div: i128) -> (i128, i128) {
+        let mut rem = 0;
+        let quo = unsafe { __divmodti4(duo, div, &mut rem) };
+        (quo, rem)
+    }
+    pub fn u128_div_compiler_builtins(duo: u128, div: u128) -> u128 {
+        unsafe { __udivti3(duo, div) }
+    }
+
+    /// Like `bencher!`, except that 8 operand pairs are drawn from an `Rng` seeded by the masks,
+    /// and every iteration runs through all of them.
+    macro_rules! seeded_bencher {
+        (
+            $ty:tt,
+            $arg0_sb:expr,
+            $arg1_sb:expr,
+            $($fn_div_rem:ident, $test_name:ident);+;
+        ) => {
+            $(
+                #[bench]
+                fn $test_name(bencher: &mut Bencher) {
+                    let n = $ty::MAX.count_ones();
+                    let mut rng = Rng::new((($arg0_sb as u64) << 8) | $arg1_sb as u64);
+                    let mut operands = [(0, 1); 8];
+                    for operand in operands.iter_mut() {
+                        let lhs = rng.random::<$ty>() & ($ty::MAX >> (n - $arg0_sb));
+                        let mut rhs = rng.random::<$ty>() & ($ty::MAX >> (n - $arg1_sb));
+                        if rhs == 0 {
+                            rhs = 1;
+                        }
+                        *operand = (lhs, rhs);
+                    }
+                    bencher.iter(|| {
+                        for (lhs, rhs) in operands.iter() {
+                            black_box($fn_div_rem(black_box(*lhs), black_box(*rhs)));
+                        }
+                    })
+                }
+            )+
+        };
+    }
+
+    seeded_bencher!(
+        u64,
+        64,
+        32,
+        u64_div_rem_std,
+        u64_div_rem_64_32_seeded_std;
+        u64_div_rem_compiler_builtins,
+        u64_div_rem_64_32_seeded_compiler_builtins;
+        u64_div_rem_delegate,
+        u64_div_rem_64_32_seeded_delegate;
+        u64_div_rem_trifecta,
+        u64_div_rem_64_32_seeded_trifecta;
+        u64_div_rem_asymmetric,
+        u64_div_rem_64_32_seeded_asymmetric;
+    );
+    seeded_bencher!(
+        u128,
+        96,
+        70,
+        u128_div_rem_compiler_builtins,
+        u128_div_rem_96_70_seeded_compiler_builtins;
+        u128_div_rem_delegate,
+        u128_div_rem_96_70_seeded_delegate;
+        u128_div_rem_trifecta,
+        u128_div_rem_96_70_seeded_trifecta;
+        u128_div_rem_asymmetric,
+        u128_div_rem_96_70_seeded_asymmetric;
+    );
+    seeded_bencher!(
+        u128,
+        128,
+        64,
+        u128_div_rem_compiler_builtins,
+        u128_div_rem_128_64_seeded_compiler_builtins;
+        u128_div_rem_delegate,
+        u128_div_rem_128_64_seeded_delegate;
+        u128_div_rem_trifecta,
+        u128_div_rem_128_64_seeded_trifecta;
+        u128_div_rem_asymmetric,
+        u128_div_rem_128_64_seeded_asymmetric;
+    );
+    seeded_bencher!(
+        u128,
+        128,
+        8,
+        u128_div_rem_compiler_builtins,
+        u128_div_rem_128_8_seeded_compiler_builtins;
+        u128_div_rem_delegate,
+        u128_div_rem_128_8_seeded_delegate;
+        u128_div_rem_trifecta,
+        u128_div_rem_128_8_seeded_trifecta;
+        u128_div_rem_asymmetric,
+        u128_div_rem_128_8_seeded_asymmetric;
+    );
+    seeded_bencher!(
+        u128,
+        128,
+        96,
+        u128_div_compiler_builtins,
+        u128_div_128_96_seeded_compiler_builtins;
+        u128_div_asymmetric,
+        u128_div_128_96_seeded_asymmetric;
+    );
+    seeded_bencher!(
+        i128,
+        96,
+        32,
+        i128_div_rem_compiler_builtins,
+        i128_div_rem_96_32_seeded_compiler_builtins;
+        i128_div_rem_delegate,
+        i128_div_rem_96_32_seeded_delegate;
+        i128_div_rem_trifecta,
+        i128_div_rem_96_32_seeded_trifecta;
+        i128_div_rem_asymmetric,
+        i128_div_rem_96_32_seeded_asymmetric;
+    );
+}
@@ -2,7 +2,13 @@
 /// bitwidth as the largest operand in an asymmetrically sized division. For example, x86-64 has an
 /// assembly instruction that can divide a 128 bit integer by a 64 bit integer if the quotient fits
 /// in 64 bits. The 128 bit version of this algorithm would use that fast hardware division to
-/// construct a full 128 bit by 128 bit division.
+/// construct a full 128 bit by 128 bit division. `$asymmetric_division` is `unsafe` because it has
+/// this requirement on the quotient, but the generated functions check it before every call and
+/// are sound for all inputs.
+///
+/// On targets without a CLZ instruction, a software `leading_zeros` function for `$uX` (such as
+/// the ones from `impl_bit_scan`) can be passed with `leading_zeros = ` after
+/// `$asymmetric_division`.
 #[macro_export]
 macro_rules! impl_asymmetric {
     (
@@ -11,6 +17,7 @@ macro_rules! impl_asymmetric {
         $zero_div_fn:ident, // function called when division by zero is attempted
         $half_division:ident, // function for division of a $uX by a $uX
         $asymmetric_division:ident, // function for division of a $uD by a $uX
+        leading_zeros = $leading_zeros:path, // function for the leading zeros of a $uX
         $n_h:expr, // the number of bits in a $iH or $uH
         $uH:ident, // unsigned integer with half the bit width of $uX
         $uX:ident, // unsigned integer with half the bit width of $uD
@@ -25,27 +32,63 @@ macro_rules! impl_asymmetric {
             #[$unsigned_attr]
         )*
         pub fn $unsigned_name(duo: $uD, div: $uD) -> ($uD,$uD) {
+            // All uses of `$asymmetric_division` go through this, which checks its precondition
+            // instead of relying on the case analysis below, so that no `unsafe` has to be
+            // audited outside of it. The branches below never violate the precondition, but if
+            // they did, the upper half of `duo` would be reduced with `$half_division` first,
+            // giving the correct remainder and the lower half of the quotient.
+            #[inline(always)]
+            fn asymmetric_division(duo: $uD, div: $uX) -> ($uX, $uX) {
+                let n: u32 = $n_h * 2;
+                let duo_hi = $crate::cast!(duo >> n, $uX);
+                if $crate::hint::likely(duo_hi < div) {
+                    // Safety: the quotient fits in a `$uX`, which also implies `div != 0`
+                    $crate::unchecked_call!($asymmetric_division(duo, div))
+                } else {
+                    // this panics if `div == 0`
+                    let rem_hi = $half_division(duo_hi, div).1;
+                    let duo_lo = $crate::cast!(duo, $uX);
+                    let duo = $crate::cast!(duo_lo, $uD) | ($crate::cast!(rem_hi, $uD) << n);
+                    // Safety: `rem_hi < div`
+                    $crate::unchecked_call!($asymmetric_division(duo, div))
+                }
+            }
+
             let n: u32 = $n_h * 2;
 
-            let duo_lo = duo as $uX;
-            let duo_hi = (duo >> n) as $uX;
-            let div_lo = div as $uX;
-            let div_hi = (div >> n) as $uX;
+            let duo_lo = $crate::cast!(duo, $uX);
+            let duo_hi = $crate::cast!(duo >> n, $uX);
+            let div_lo = $crate::cast!(div, $uX);
+            let div_hi = $crate::cast!(div >> n, $uX);
             if div_hi == 0 {
-                if div_lo == 0 {
+                if $crate::hint::unlikely(div_lo == 0) {
                     $zero_div_fn()
                 }
+                // The branches of the tuning profiles (see `$crate::hint`). With quotient-heavy
+                // workloads, `duo_hi >= div_lo` is checked first so that the short division is the
+                // first branch taken.
+                if $crate::hint::SMALL_OPERANDS && (duo_hi == 0) {
+                    let (quo, rem) = $half_division(duo_lo, div_lo);
+                    return ($crate::cast!(quo, $uD), $crate::cast!(rem, $uD))
+                }
+                if $crate::hint::QUOTIENT_HEAVY && (duo_hi >= div_lo) {
+                    let (quo_hi, rem_hi) = $half_division(duo_hi, div_lo);
+                    let duo = $crate::cast!(duo_lo, $uD) | ($crate::cast!(rem_hi, $uD) << n);
+                    let tmp = asymmetric_division(duo, div_lo);
+                    let quo = $crate::cast!(tmp.0, $uD) | ($crate::cast!(quo_hi, $uD) << n);
+                    return (quo, $crate::cast!(tmp.1, $uD))
+                }
                 if duo_hi < div_lo {
                     // `$uD` by `$uX` division with a quotient that will fit into a `$uX`
-                    let (quo, rem) = unsafe { $asymmetric_division(duo, div_lo) };
-                    return (quo as $uD, rem as $uD)
+                    let (quo, rem) = asymmetric_division(duo, div_lo);
+                    return ($crate::cast!(quo, $uD), $crate::cast!(rem, $uD))
                 } else {
                     // Short division using the $uD by $uX division
                     let (quo_hi, rem_hi) = $half_division(duo_hi, div_lo);
-                    let tmp = unsafe {
-                        $asymmetric_division((duo_lo as $uD) | ((rem_hi as $uD) << n), div_lo)
-                    };
-                    return ((tmp.0 as $uD) | ((quo_hi as $uD) << n), tmp.1 as $uD)
+                    let duo = $crate::cast!(duo_lo, $uD) | ($crate::cast!(rem_hi, $uD) << n);
+                    let tmp = asymmetric_division(duo, div_lo);
+                    let quo = $crate::cast!(tmp.0, $uD) | ($crate::cast!(quo_hi, $uD) << n);
+                    return (quo, $crate::cast!(tmp.1, $uD))
                 }
             }
 
@@ -54,12 +97,10 @@ macro_rules! impl_asymmetric {
             // adapted from Hacker's Delight. This is similar to the two possibility algorithm
             // in that it uses only more significant parts of `duo` and `div` to divide a large
             // integer with a smaller division instruction.
-            let div_lz = div_hi.leading_zeros();
+            let div_lz = $leading_zeros(div_hi);
             let div_extra = n - div_lz;
-            let div_sig_n = (div >> div_extra) as $uX;
-            let tmp = unsafe {
-                $asymmetric_division(duo >> 1, div_sig_n)
-            };
+            let div_sig_n = $crate::cast!(div >> div_extra, $uX);
+            let tmp = asymmetric_division(duo >> 1, div_sig_n);
 
             let mut quo = tmp.0 >> ((n - 1) - div_lz);
             if quo != 0 {
@@ -67,12 +108,12 @@ macro_rules! impl_asymmetric {
             }
 
             // Note that this is a full `$uD` multiplication being used here
-            let mut rem = duo - (quo as $uD).wrapping_mul(div);
+            let mut rem = duo - $crate::cast!(quo, $uD).wrapping_mul(div);
             if div <= rem {
                 quo += 1;
                 rem -= div;
             }
-            return (quo as $uD, rem)
+            return ($crate::cast!(quo, $uD), rem)
         }
 
         /// Computes the quotient and remainder of `duo` divided by `div` and returns them as a
@@ -81,8 +122,8 @@ macro_rules! impl_asymmetric {
             #[$signed_attr]
         )*
         pub fn $signed_name(duo: $iD, div: $iD) -> ($iD, $iD) {
-            let duo_neg = duo < 0;
-            let div_neg = div < 0;
+            let duo_neg = duo.is_negative();
+            let div_neg = div.is_negative();
             let mut duo = duo;
             let mut div = div;
             if duo_neg {
@@ -91,9 +132,9 @@ macro_rules! impl_asymmetric {
             if div_neg {
                 div = div.wrapping_neg();
             }
-            let t = $unsigned_name(duo as $uD, div as $uD);
-            let mut quo = t.0 as $iD;
-            let mut rem = t.1 as $iD;
+            let t = $unsigned_name($crate::cast!(duo, $uD), $crate::cast!(div, $uD));
+            let mut quo = $crate::cast!(t.0, $iD);
+            let mut rem = $crate::cast!(t.1, $iD);
             if duo_neg {
                 rem = rem.wrapping_neg();
             }
@@ -102,5 +143,36 @@ macro_rules! impl_asymmetric {
             }
             (quo, rem)
         }
-    }
+    };
+    // the default, which uses the `leading_zeros` method of `$uX`
+    (
+        $unsigned_name:ident, // name of the unsigned division function
+        $signed_name:ident, // name of the signed division function
+        $zero_div_fn:ident, // function called when division by zero is attempted
+        $half_division:ident, // function for division of a $uX by a $uX
+        $asymmetric_division:ident, // function for division of a $uD by a $uX
+        $n_h:expr, // the number of bits in a $iH or $uH
+        $uH:ident, // unsigned integer with half the bit width of $uX
+        $uX:ident, // unsigned integer with half the bit width of $uD
+        $uD:ident, // unsigned integer type for the inputs and outputs of `$unsigned_name`
+        $iD:ident, // signed integer type for the inputs and outputs of `$signed_name`
+        $($unsigned_attr:meta),*; // attributes for the unsigned function
+        $($signed_attr:meta),* // attributes for the signed function
+    ) => {
+        $crate::impl_asymmetric!(
+            $unsigned_name,
+            $signed_name,
+            $zero_div_fn,
+            $half_division,
+            $asymmetric_division,
+            leading_zeros = $uX::leading_zeros,
+            $n_h,
+            $uH,
+            $uX,
+            $uD,
+            $iD,
+            $($unsigned_attr),*;
+            $($signed_attr),*
+        );
+    };
 }
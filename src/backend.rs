@@ -0,0 +1,250 @@
+/// Providers of the smaller divisions that the composite algorithms (`impl_delegate`,
+/// `impl_trifecta`, and `impl_asymmetric`) are built from. Implementing this for a type and passing
+/// it to `impl_div_rem_backend` plugs a different provider into all of the composite algorithms at
+/// once, such as a memory mapped hardware divider on a microcontroller, a helper provided by a
+/// hypervisor, or a mock that counts calls in tests. Every function has a default that uses the `/`
+/// and `%` operators, so only the divisions that the provider accelerates need to be implemented.
+pub trait DivRemBackend {
+    /// Computes the quotient and remainder of `duo` divided by `div`, which is the half division
+    /// of 32 bit `_delegate`.
+    ///
+    /// # Panics
+    ///
+    /// Can panic if `div == 0`, although the composite algorithms never pass it.
+    fn u16_div_rem(duo: u16, div: u16) -> (u16, u16) {
+        (duo / div, duo % div)
+    }
+
+    /// Computes the quotient and remainder of `duo` divided by `div`, which is the half division
+    /// of the 64 bit composite algorithms.
+    ///
+    /// # Panics
+    ///
+    /// Can panic if `div == 0`, although the composite algorithms never pass it.
+    fn u32_div_rem(duo: u32, div: u32) -> (u32, u32) {
+        (duo / div, duo % div)
+    }
+
+    /// Computes the quotient and remainder of `duo` divided by `div`, which is the half division
+    /// of the 128 bit composite algorithms.
+    ///
+    /// # Panics
+    ///
+    /// Can panic if `div == 0`, although the composite algorithms never pass it.
+    fn u64_div_rem(duo: u64, div: u64) -> (u64, u64) {
+        (duo / div, duo % div)
+    }
+
+    /// Computes the quotient and remainder of `duo` divided by `div`, which is the asymmetric
+    /// division of 64 bit `_asymmetric`. `impl_asymmetric` always checks that the quotient fits in
+    /// a `u32` (`(duo >> 32) < div`) before calling, like for a hardware asymmetric division
+    /// instruction.
+    ///
+    /// # Panics
+    ///
+    /// Can panic or produce wrong results if the quotient does not fit in a `u32`, although the
+    /// composite algorithms never pass such operands.
+    fn u64_by_u32_div_rem(duo: u64, div: u32) -> (u32, u32) {
+        ((duo / (div as u64)) as u32, (duo % (div as u64)) as u32)
+    }
+
+    /// Computes the quotient and remainder of `duo` divided by `div`, which is the asymmetric
+    /// division of 128 bit `_asymmetric`. `impl_asymmetric` always checks that the quotient fits in
+    /// a `u64` (`(duo >> 64) < div`) before calling, like for a hardware asymmetric division
+    /// instruction.
+    ///
+    /// # Panics
+    ///
+    /// Can panic or produce wrong results if the quotient does not fit in a `u64`, although the
+    /// composite algorithms never pass such operands.
+    fn u128_by_u64_div_rem(duo: u128, div: u64) -> (u64, u64) {
+        ((duo / (div as u128)) as u64, (duo % (div as u128)) as u64)
+    }
+}
+
+/// Creates a module named `$module` with the `_delegate`, `_trifecta`, and `_asymmetric`
+/// functions of the 32 (only `_delegate`), 64, and 128 bit widths, with the smaller divisions
+/// provided by the `DivRemBackend` implementation of `$backend`.
+///
+/// ```
+/// use specialized_div_rem::{impl_div_rem_backend, DivRemBackend};
+///
+/// // e.g. a peripheral that can only divide 32 bit integers
+/// struct Peripheral;
+///
+/// impl DivRemBackend for Peripheral {
+///     fn u32_div_rem(duo: u32, div: u32) -> (u32, u32) {
+///         // the registers of the peripheral would be accessed here
+///         (duo / div, duo % div)
+///     }
+/// }
+///
+/// impl_div_rem_backend!(peripheral, Peripheral);
+///
+/// fn main() {
+///     assert_eq!(peripheral::u64_div_rem_delegate(1 << 40, 3), ((1 << 40) / 3, 1));
+/// }
+/// ```
+#[macro_export]
+macro_rules! impl_div_rem_backend {
+    (
+        $module:ident, // name of the created module
+        $backend:ty // type that implements `DivRemBackend`
+    ) => {
+        pub mod $module {
+            #[allow(unused_imports)]
+            use super::*;
+            use $crate::DivRemBackend;
+
+            // The macros take functions by name, so the provided functions are wrapped
+            #[inline(always)]
+            fn u16_div_rem(duo: u16, div: u16) -> (u16, u16) {
+                <$backend as DivRemBackend>::u16_div_rem(duo, div)
+            }
+            #[inline(always)]
+            fn u32_div_rem(duo: u32, div: u32) -> (u32, u32) {
+                <$backend as DivRemBackend>::u32_div_rem(duo, div)
+            }
+            #[inline(always)]
+            fn u64_div_rem(duo: u64, div: u64) -> (u64, u64) {
+                <$backend as DivRemBackend>::u64_div_rem(duo, div)
+            }
+            // `impl_asymmetric` calls these with `unchecked_call`
+            $crate::unchecked_fn! {
+                #[inline(always)]
+                fn u64_by_u32_div_rem(duo: u64, div: u32) -> (u32, u32) {
+                    <$backend as DivRemBackend>::u64_by_u32_div_rem(duo, div)
+                }
+            }
+            // `impl_asymmetric` calls these with `unchecked_call`
+            $crate::unchecked_fn! {
+                #[inline(always)]
+                fn u128_by_u64_div_rem(duo: u128, div: u64) -> (u64, u64) {
+                    <$backend as DivRemBackend>::u128_by_u64_div_rem(duo, div)
+                }
+            }
+
+            #[cold]
+            #[track_caller]
+            fn zero_div_fn() -> ! {
+                panic!("attempt to divide by zero")
+            }
+
+            const USE_LZ: bool = true;
+            $crate::impl_normalization_shift!(u16_normalization_shift, USE_LZ, 16, u16, i16,);
+            $crate::impl_normalization_shift!(u32_normalization_shift, USE_LZ, 32, u32, i32,);
+            $crate::impl_normalization_shift!(u64_normalization_shift, USE_LZ, 64, u64, i64,);
+
+            $crate::impl_delegate!(
+                u32_div_rem_delegate,
+                i32_div_rem_delegate,
+                zero_div_fn,
+                u16_normalization_shift,
+                u16_div_rem,
+                8,
+                u8,
+                u16,
+                u32,
+                i32,
+                inline(never),
+                track_caller;
+                inline,
+                track_caller
+            );
+            $crate::impl_delegate!(
+                u64_div_rem_delegate,
+                i64_div_rem_delegate,
+                zero_div_fn,
+                u32_normalization_shift,
+                u32_div_rem,
+                16,
+                u16,
+                u32,
+                u64,
+                i64,
+                inline(never),
+                track_caller;
+                inline,
+                track_caller
+            );
+            $crate::impl_delegate!(
+                u128_div_rem_delegate,
+                i128_div_rem_delegate,
+                zero_div_fn,
+                u64_normalization_shift,
+                u64_div_rem,
+                32,
+                u32,
+                u64,
+                u128,
+                i128,
+                inline(never),
+                track_caller;
+                inline,
+                track_caller
+            );
+            $crate::impl_trifecta!(
+                u64_div_rem_trifecta,
+                i64_div_rem_trifecta,
+                zero_div_fn,
+                u32_div_rem,
+                16,
+                u16,
+                u32,
+                u64,
+                i64,
+                inline(never),
+                track_caller;
+                inline,
+                track_caller
+            );
+            $crate::impl_trifecta!(
+                u128_div_rem_trifecta,
+                i128_div_rem_trifecta,
+                zero_div_fn,
+                u64_div_rem,
+                32,
+                u32,
+                u64,
+                u128,
+                i128,
+                inline(never),
+                track_caller;
+                inline,
+                track_caller
+            );
+            $crate::impl_asymmetric!(
+                u64_div_rem_asymmetric,
+                i64_div_rem_asymmetric,
+                zero_div_fn,
+                u32_div_rem,
+                u64_by_u32_div_rem,
+                16,
+                u16,
+                u32,
+                u64,
+                i64,
+                inline(never),
+                track_caller;
+                inline,
+                track_caller
+            );
+            $crate::impl_asymmetric!(
+                u128_div_rem_asymmetric,
+                i128_div_rem_asymmetric,
+                zero_div_fn,
+                u64_div_rem,
+                u128_by_u64_div_rem,
+                32,
+                u32,
+                u64,
+                u128,
+                i128,
+                inline(never),
+                track_caller;
+                inline,
+                track_caller
+            );
+        }
+    };
+}
@@ -11,6 +11,8 @@ macro_rules! impl_binary_long {
         $signed_name:ident, // name of the signed division function
         $zero_div_fn:ident, // function called when division by zero is attempted
         $normalization_shift:ident, // function for finding the normalization shift
+        // function for the central loop of the SWAR algorithm (see `impl_swar_steps`)
+        swar_steps = $swar_steps:path,
         $n:tt, // the number of bits in a $iX or $uX
         $uX:ident, // unsigned integer type for the inputs and outputs of `$unsigned_name`
         $iX:ident, // signed integer type for the inputs and outputs of `$signed_name`
@@ -23,9 +25,11 @@ macro_rules! impl_binary_long {
             #[$unsigned_attr]
         )*
         pub fn $unsigned_name(duo: $uX, div: $uX) -> ($uX, $uX) {
+            $crate::impl_swar_steps!(rust_swar_steps, $n, $uX, $iX, allow(dead_code));
+
             let mut duo = duo;
             // handle edge cases before calling `$normalization_shift`
-            if div == 0 {
+            if $crate::hint::unlikely(div == 0) {
                 $zero_div_fn()
             }
             if duo < div {
@@ -396,7 +400,8 @@ macro_rules! impl_binary_long {
             // division, and for the default algorithm use a mask based restoring SWAR algorithm
             // without conditionals or branches. On almost all architectures, this Rust code is
             // guaranteed to compile down to 5 assembly instructions or less for each step, and LLVM
-            // will unroll it in a decent way.
+            // will unroll it in a decent way. The central loop is `$swar_steps`, so that assembly
+            // can guarantee this where it matters (see `impl_swar_steps`).
 
             // standard opening for SWAR algorithm with first step and edge case handling
             let div_original = div;
@@ -427,19 +432,7 @@ macro_rules! impl_binary_long {
 
             // central loop
             div = div.wrapping_sub(1);
-            let mut i = shl;
-            loop {
-                if i == 0 {
-                    break
-                }
-                i -= 1;
-                // shift left 1 and subtract
-                duo = duo.wrapping_shl(1).wrapping_sub(div);
-                // create mask
-                let mask = (duo as $iX).wrapping_shr($n - 1) as $uX;
-                // restore
-                duo = duo.wrapping_add(div & mask);
-            }
+            duo = $swar_steps(duo, div, shl as u32);
             // unpack
             return ((duo & mask) | quo, duo >> shl);
 
@@ -615,5 +608,72 @@ macro_rules! impl_binary_long {
             }
             (quo, rem)
         }
-    }
+    };
+    // the default, which uses the Rust central loop that is always defined inside of the unsigned
+    // function
+    (
+        $unsigned_name:ident, // name of the unsigned division function
+        $signed_name:ident, // name of the signed division function
+        $zero_div_fn:ident, // function called when division by zero is attempted
+        $normalization_shift:ident, // function for finding the normalization shift
+        $n:tt, // the number of bits in a $iX or $uX
+        $uX:ident, // unsigned integer type for the inputs and outputs of `$unsigned_name`
+        $iX:ident, // signed integer type for the inputs and outputs of `$signed_name`
+        $($unsigned_attr:meta),*; // attributes for the unsigned function
+        $($signed_attr:meta),* // attributes for the signed function
+    ) => {
+        $crate::impl_binary_long!(
+            $unsigned_name,
+            $signed_name,
+            $zero_div_fn,
+            $normalization_shift,
+            swar_steps = rust_swar_steps,
+            $n,
+            $uX,
+            $iX,
+            $($unsigned_attr),*;
+            $($signed_attr),*
+        );
+    };
+}
+
+/// Creates a function for the central loop of the mask based restoring SWAR algorithm of
+/// `impl_binary_long`, which performs `steps` division steps on `duo` with the adjusted `div`.
+/// On almost all architectures, each step compiles down to 5 assembly instructions or less, but
+/// this is up to LLVM. Hand written assembly with the same signature can be passed to
+/// `impl_binary_long` with `swar_steps = ` to guarantee it.
+#[macro_export]
+macro_rules! impl_swar_steps {
+    (
+        $name:ident, // name of the function
+        $n:tt, // the number of bits in a $iX or $uX
+        $uX:ident, // unsigned integer type for the inputs and outputs of `$name`
+        $iX:ident, // signed integer type with the same bit width as `$uX`
+        $($attr:meta),* // attributes for the function
+    ) => {
+        /// Performs `steps` steps of the central loop of the SWAR binary long division algorithm on
+        /// `duo`, where `div` is the normalized divisor minus one (so that each step also shifts a
+        /// quotient bit into `duo`), and returns the new `duo`.
+        $(
+            #[$attr]
+        )*
+        pub fn $name(duo: $uX, div: $uX, steps: u32) -> $uX {
+            let mut duo = duo;
+            let mut i = steps;
+            loop {
+                if i == 0 {
+                    break
+                }
+                i -= 1;
+                $crate::instrument::step($crate::instrument::Loop::BinaryLong);
+                // shift left 1 and subtract
+                duo = duo.wrapping_shl(1).wrapping_sub(div);
+                // create mask
+                let mask = (duo as $iX).wrapping_shr($n - 1) as $uX;
+                // restore
+                duo = duo.wrapping_add(div & mask);
+            }
+            duo
+        }
+    };
 }
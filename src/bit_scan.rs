@@ -0,0 +1,94 @@
+/// Creates `const` software bit scanning functions that only use shifts, comparisons, and
+/// additions, for architectures without `leading_zeros` or `trailing_zeros` instructions where
+/// LLVM's expansion would otherwise be used. They are built on the software bisection of
+/// `impl_normalization_shift`.
+#[macro_export]
+macro_rules! impl_bit_scan {
+    (
+        $leading_zeros:ident, // name of the leading zeros function
+        $trailing_zeros:ident, // name of the trailing zeros function
+        $leading_ones:ident, // name of the leading ones function
+        $trailing_ones:ident, // name of the trailing ones function
+        $n:tt, // the number of bits in a $uX
+        $uX:ident, // unsigned integer type for the inputs
+    ) => {
+        /// Returns the number of leading zeros in `x`, like `$uX::leading_zeros`.
+        pub const fn $leading_zeros(x: $uX) -> u32 {
+            const NO_LZ: bool = false;
+            // the signed type is not used by the bisection
+            $crate::impl_normalization_shift!(const normalization_shift, NO_LZ, $n, $uX, $uX,);
+            if x == 0 {
+                return $n;
+            }
+            // normalizing 1 to `x` shifts it to the msb of `x`
+            $n - 1 - (normalization_shift(x, 1, true) as u32)
+        }
+
+        /// Returns the number of trailing zeros in `x`, like `$uX::trailing_zeros`.
+        pub const fn $trailing_zeros(x: $uX) -> u32 {
+            if x == 0 {
+                return $n;
+            }
+            // `x & x.wrapping_neg()` isolates the least significant set bit
+            $n - 1 - $leading_zeros(x & x.wrapping_neg())
+        }
+
+        /// Returns the number of leading ones in `x`, like `$uX::leading_ones`.
+        pub const fn $leading_ones(x: $uX) -> u32 {
+            $leading_zeros(!x)
+        }
+
+        /// Returns the number of trailing ones in `x`, like `$uX::trailing_ones`.
+        pub const fn $trailing_ones(x: $uX) -> u32 {
+            $trailing_zeros(!x)
+        }
+    };
+}
+
+/// Creates `const` software power of two utilities on top of a leading zeros function from
+/// `impl_bit_scan`.
+#[macro_export]
+macro_rules! impl_bit_util {
+    (
+        $is_power_of_two:ident, // name of the power of two check
+        $next_power_of_two:ident, // name of the next power of two function
+        $checked_next_power_of_two:ident, // name of the checked next power of two function
+        $bit_width:ident, // name of the bit width function
+        $leading_zeros:ident, // function for the leading zeros of a $uX
+        $n:tt, // the number of bits in a $uX
+        $uX:ident, // unsigned integer type for the inputs
+    ) => {
+        /// Returns `true` if `x` is a power of two, like `$uX::is_power_of_two`.
+        pub const fn $is_power_of_two(x: $uX) -> bool {
+            (x != 0) & ((x & x.wrapping_sub(1)) == 0)
+        }
+
+        /// Returns the number of bits needed to represent `x`, which is 0 for `x == 0`.
+        pub const fn $bit_width(x: $uX) -> u32 {
+            $n - $leading_zeros(x)
+        }
+
+        /// Returns the smallest power of two greater than or equal to `x`, or `None` if it does
+        /// not fit in a `$uX`.
+        pub const fn $checked_next_power_of_two(x: $uX) -> Option<$uX> {
+            if x <= 1 {
+                return Some(1);
+            }
+            let shift = $bit_width(x - 1);
+            if shift == $n {
+                None
+            } else {
+                Some(1 << shift)
+            }
+        }
+
+        /// Returns the smallest power of two greater than or equal to `x`. If it does not fit in a
+        /// `$uX`, this returns 0 like `$uX::next_power_of_two` does with overflow checks disabled.
+        pub const fn $next_power_of_two(x: $uX) -> $uX {
+            match $checked_next_power_of_two(x) {
+                Some(pow) => pow,
+                None => 0,
+            }
+        }
+    };
+}
@@ -0,0 +1,43 @@
+//! The conversions used by the algorithm macros for the `$uD` and `$iD` types. `as` casts and
+//! integer literals only work with the primitive integers, so the macros use `cast!` for them
+//! instead, which allows instantiating the macros with integer types that are not primitives (such
+//! as `u256` and `i256`).
+
+/// Converts from `T` with the semantics of `as` casts between integers (truncating, zero extending
+/// unsigned values, and sign extending signed values)
+pub trait CastFrom<T> {
+    fn cast_from(x: T) -> Self;
+}
+
+macro_rules! impl_cast_from {
+    ([$($from:ident)*] $to:ident) => {
+        $(
+            impl CastFrom<$from> for $to {
+                #[inline(always)]
+                fn cast_from(x: $from) -> Self {
+                    x as $to
+                }
+            }
+        )*
+    };
+    ($from:tt $($to:ident)*) => {
+        $(
+            impl_cast_from!($from $to);
+        )*
+    };
+}
+
+impl_cast_from!(
+    [u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize]
+    u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize
+);
+
+/// Converts `$x` to the integer type `$T` like `$x as $T` would, but also for integer types that
+/// are not primitives
+#[doc(hidden)]
+#[macro_export]
+macro_rules! cast {
+    ($x:expr, $T:ty) => {
+        <$T as $crate::cast::CastFrom<_>>::cast_from($x)
+    };
+}
@@ -29,26 +29,67 @@ macro_rules! impl_delegate {
             // multiplications that we assume to not be fast enough relative to the divisions to
             // outweigh setup times.
 
+            // Short division of a `$uD` by a `$uH`, using `$uX` by `$uX` division. This works for
+            // any `duo`.
+            #[inline(always)]
+            fn short_division(duo: $uD, div: $uH) -> ($uD, $uD) {
+                let duo_hi = $crate::cast!(duo >> ($n_h * 2), $uX);
+                let div_0 = $crate::cast!(div, $uX);
+                let (quo_hi, rem_3) = $half_division(duo_hi, div_0);
+
+                let duo_mid =
+                    $crate::cast!($crate::cast!(duo >> $n_h, $uH), $uX)
+                    | (rem_3 << $n_h);
+                let (quo_1, rem_2) = $half_division(duo_mid, div_0);
+
+                let duo_lo =
+                    $crate::cast!($crate::cast!(duo, $uH), $uX)
+                    | (rem_2 << $n_h);
+                let (quo_0, rem_1) = $half_division(duo_lo, div_0);
+
+                (
+                    $crate::cast!(quo_0, $uD)
+                    | ($crate::cast!(quo_1, $uD) << $n_h)
+                    | ($crate::cast!(quo_hi, $uD) << ($n_h * 2)),
+                    $crate::cast!(rem_1, $uD)
+                )
+            }
+
             // the number of bits in a $uX
             let n = $n_h * 2;
 
-            let duo_lo = duo as $uX;
-            let duo_hi = (duo >> n) as $uX;
-            let div_lo = div as $uX;
-            let div_hi = (div >> n) as $uX;
+            let duo_lo = $crate::cast!(duo, $uX);
+            let duo_hi = $crate::cast!(duo >> n, $uX);
+            let div_lo = $crate::cast!(div, $uX);
+            let div_hi = $crate::cast!(div >> n, $uX);
+            let zero = $crate::cast!(0u8, $uX);
 
-            match (div_lo == 0, div_hi == 0, duo_hi == 0) {
-                (true, true, _) => {
-                    $zero_div_fn()
-                }
+            if $crate::hint::unlikely((div_lo | div_hi) == zero) {
+                $zero_div_fn()
+            }
+
+            // The branches of the tuning profiles (see `$crate::hint`) are moved in front of the
+            // `match`, which does not depend on the ranges that they exclude. `div != 0`, so
+            // `div_hi == 0` implies `div_lo != 0`.
+            if $crate::hint::SMALL_OPERANDS && ((duo_hi | div_hi) == zero) {
+                let tmp = $half_division(duo_lo, div_lo);
+                return ($crate::cast!(tmp.0, $uD), $crate::cast!(tmp.1, $uD))
+            }
+            if $crate::hint::QUOTIENT_HEAVY && (div_hi == zero) && ((div_lo >> $n_h) == zero) {
+                return short_division(duo, $crate::cast!(div_lo, $uH))
+            }
+
+            match (div_lo == zero, div_hi == zero, duo_hi == zero) {
+                // handled by the zero check above
+                (true, true, _) => $zero_div_fn(),
                 (_, false, true) => {
                     // `duo` < `div`
-                    return (0, duo)
+                    return ($crate::cast!(0u8, $uD), duo)
                 }
                 (false, true, true) => {
                     // delegate to smaller division
                     let tmp = $half_division(duo_lo, div_lo);
-                    return (tmp.0 as $uD, tmp.1 as $uD)
+                    return ($crate::cast!(tmp.0, $uD), $crate::cast!(tmp.1, $uD))
                 }
                 (false, true, false) => {
                     if duo_hi < div_lo {
@@ -83,20 +124,24 @@ macro_rules! impl_delegate {
                         // for this task, since `pow` and `quo` can be `$uX` and the delegation
                         // check is simple.
                         let mut div: $uD = div << shl;
-                        let mut pow_lo: $uX = 1 << shl;
-                        let mut quo_lo: $uX = 0;
+                        let mut pow_lo: $uX = $crate::cast!(1u8, $uX) << shl;
+                        let mut quo_lo: $uX = zero;
                         let mut duo = duo;
                         loop {
+                            $crate::instrument::step($crate::instrument::Loop::Delegate);
                             let sub = duo.wrapping_sub(div);
-                            if 0 <= (sub as $iD) {
+                            if !$crate::cast!(sub, $iD).is_negative() {
                                 duo = sub;
                                 quo_lo |= pow_lo;
-                                let duo_hi = (duo >> n) as $uX;
-                                if duo_hi == 0 {
+                                let duo_hi = $crate::cast!(duo >> n, $uX);
+                                if duo_hi == zero {
                                     // Delegate to get the rest of the quotient. Note that the
                                     // `div_lo` here is the original unshifted `div`.
-                                    let tmp = $half_division(duo as $uX, div_lo);
-                                    return ((quo_lo | tmp.0) as $uD, tmp.1 as $uD)
+                                    let tmp = $half_division($crate::cast!(duo, $uX), div_lo);
+                                    return (
+                                        $crate::cast!(quo_lo | tmp.0, $uD),
+                                        $crate::cast!(tmp.1, $uD)
+                                    )
                                 }
                             }
                             div >>= 1;
@@ -104,64 +149,50 @@ macro_rules! impl_delegate {
                         }
                     } else if duo_hi == div_lo {
                         // `quo_hi == 1`. This branch is cheap and helps with edge cases.
-                        let tmp = $half_division(duo as $uX, div as $uX);
-                        return ((1 << n) | (tmp.0 as $uD), tmp.1 as $uD)
+                        let tmp = $half_division($crate::cast!(duo, $uX), $crate::cast!(div, $uX));
+                        return (
+                            ($crate::cast!(1u8, $uD) << n) | $crate::cast!(tmp.0, $uD),
+                            $crate::cast!(tmp.1, $uD)
+                        )
                     } else {
                         // `div_lo < duo_hi`
                         // `rem_hi == 0`
-                        if (div_lo >> $n_h) == 0 {
-                            // Short division of $uD by a $uH, using $uX by $uX division
-                            let div_0 = div_lo as $uH as $uX;
-                            let (quo_hi, rem_3) = $half_division(duo_hi, div_0);
-
-                            let duo_mid =
-                                ((duo >> $n_h) as $uH as $uX)
-                                | (rem_3 << $n_h);
-                            let (quo_1, rem_2) = $half_division(duo_mid, div_0);
-
-                            let duo_lo =
-                                (duo as $uH as $uX)
-                                | (rem_2 << $n_h);
-                            let (quo_0, rem_1) = $half_division(duo_lo, div_0);
-
-                            return (
-                                (quo_0 as $uD)
-                                | ((quo_1 as $uD) << $n_h)
-                                | ((quo_hi as $uD) << n),
-                                rem_1 as $uD
-                            )
+                        if (div_lo >> $n_h) == zero {
+                            return short_division(duo, $crate::cast!(div_lo, $uH))
                         }
 
                         // This is basically a short division composed of a half division for the hi
                         // part, specialized 3 variable binary long division in the middle, and
                         // another half division for the lo part.
-                        let duo_lo = duo as $uX;
+                        let duo_lo = $crate::cast!(duo, $uX);
                         let tmp = $half_division(duo_hi, div_lo);
                         let quo_hi = tmp.0;
-                        let mut duo = (duo_lo as $uD) | ((tmp.1 as $uD) << n);
+                        let mut duo = $crate::cast!(duo_lo, $uD) | ($crate::cast!(tmp.1, $uD) << n);
                         // This check is required to avoid breaking the long division below.
                         if duo < div {
-                            return ((quo_hi as $uD) << n, duo);
+                            return ($crate::cast!(quo_hi, $uD) << n, duo);
                         }
 
                         // The half division handled all shift alignments down to `n`, so this
                         // division can continue with a shift of `n - 1`.
                         let mut div: $uD = div << (n - 1);
-                        let mut pow_lo: $uX = 1 << (n - 1);
-                        let mut quo_lo: $uX = 0;
+                        let mut pow_lo: $uX = $crate::cast!(1u8, $uX) << (n - 1);
+                        let mut quo_lo: $uX = zero;
                         loop {
+                            $crate::instrument::step($crate::instrument::Loop::Delegate);
                             let sub = duo.wrapping_sub(div);
-                            if 0 <= (sub as $iD) {
+                            if !$crate::cast!(sub, $iD).is_negative() {
                                 duo = sub;
                                 quo_lo |= pow_lo;
-                                let duo_hi = (duo >> n) as $uX;
-                                if duo_hi == 0 {
+                                let duo_hi = $crate::cast!(duo >> n, $uX);
+                                if duo_hi == zero {
                                     // Delegate to get the rest of the quotient. Note that the
                                     // `div_lo` here is the original unshifted `div`.
-                                    let tmp = $half_division(duo as $uX, div_lo);
+                                    let tmp = $half_division($crate::cast!(duo, $uX), div_lo);
                                     return (
-                                        (tmp.0) as $uD | (quo_lo as $uD) | ((quo_hi as $uD) << n),
-                                        tmp.1 as $uD
+                                        $crate::cast!(tmp.0 | quo_lo, $uD)
+                                            | ($crate::cast!(quo_hi, $uD) << n),
+                                        $crate::cast!(tmp.1, $uD)
                                     );
                                 }
                             }
@@ -173,21 +204,22 @@ macro_rules! impl_delegate {
                 (_, false, false) => {
                     // Full $uD by $uD binary long division. `quo_hi` will always be 0.
                     if duo < div {
-                        return (0, duo);
+                        return ($crate::cast!(0u8, $uD), duo);
                     }
                     let div_original = div;
                     let shl = $half_normalization_shift(duo_hi, div_hi, false);
                     let mut duo = duo;
                     let mut div: $uD = div << shl;
-                    let mut pow_lo: $uX = 1 << shl;
-                    let mut quo_lo: $uX = 0;
+                    let mut pow_lo: $uX = $crate::cast!(1u8, $uX) << shl;
+                    let mut quo_lo: $uX = zero;
                     loop {
+                        $crate::instrument::step($crate::instrument::Loop::Delegate);
                         let sub = duo.wrapping_sub(div);
-                        if 0 <= (sub as $iD) {
+                        if !$crate::cast!(sub, $iD).is_negative() {
                             duo = sub;
                             quo_lo |= pow_lo;
                             if duo < div_original {
-                                return (quo_lo as $uD, duo)
+                                return ($crate::cast!(quo_lo, $uD), duo)
                             }
                         }
                         div >>= 1;
@@ -203,8 +235,8 @@ macro_rules! impl_delegate {
             #[$signed_attr]
         )*
         pub fn $signed_name(duo: $iD, div: $iD) -> ($iD, $iD) {
-            let duo_neg = duo < 0;
-            let div_neg = div < 0;
+            let duo_neg = duo.is_negative();
+            let div_neg = div.is_negative();
             let mut duo = duo;
             let mut div = div;
             if duo_neg {
@@ -213,9 +245,9 @@ macro_rules! impl_delegate {
             if div_neg {
                 div = div.wrapping_neg();
             }
-            let t = $unsigned_name(duo as $uD, div as $uD);
-            let mut quo = t.0 as $iD;
-            let mut rem = t.1 as $iD;
+            let t = $unsigned_name($crate::cast!(duo, $uD), $crate::cast!(div, $uD));
+            let mut quo = $crate::cast!(t.0, $iD);
+            let mut rem = $crate::cast!(t.1, $iD);
             if duo_neg {
                 rem = rem.wrapping_neg();
             }
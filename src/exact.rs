@@ -0,0 +1,80 @@
+/// Returns the inverse of the odd `$odd` modulo `2^$n`, for the `$n` bit unsigned integer type `$uX`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! odd_inverse {
+    ($odd:expr, $n:expr, $uX:ident) => {{
+        let odd: $uX = $odd;
+        // Newton's iteration doubles the number of correct bits of the inverse each time, starting
+        // from the 5 correct bits of `(3 * odd) ^ 2`. The first iterations use `u64`
+        // multiplications, since an inverse modulo `2^64` is also one modulo any smaller power of
+        // two.
+        let odd_lo = odd as u64;
+        let mut inv_lo = 3u64.wrapping_mul(odd_lo) ^ 2;
+        let mut i = 0;
+        while i < 4 {
+            inv_lo = inv_lo.wrapping_mul(2u64.wrapping_sub(odd_lo.wrapping_mul(inv_lo)));
+            i += 1;
+        }
+        let mut inv = inv_lo as $uX;
+        let mut bits = 64;
+        while bits < $n {
+            inv = inv.wrapping_mul((2 as $uX).wrapping_sub(odd.wrapping_mul(inv)));
+            bits *= 2;
+        }
+        inv
+    }};
+}
+
+/// Creates a function for division that is known to be exact, which multiplies by the inverse of
+/// the odd part of the divisor modulo `2^$n` instead of dividing (as in "Division by Invariant
+/// Integers using Multiplication" by Granlund and Montgomery). This is for callers that know the
+/// divisibility in advance, such as polynomial arithmetic and the cofactors of known factors. The
+/// same inverse also tests divisibility without a division, which is created as a second function.
+#[macro_export]
+macro_rules! impl_exact_div {
+    (
+        $name:ident, // name of the exact division function
+        $is_multiple_of:ident, // name of the divisibility test function
+        $zero_div_fn:ident, // `const` function called when division by zero is attempted
+        $n:expr, // the number of bits in a $uX
+        $uX:ident, // unsigned integer type for the inputs and outputs
+    ) => {
+        /// Returns `duo / div` for a `duo` that is a multiple of `div`. If `duo` is not a multiple
+        /// of `div`, the result is meaningless (but this is still safe), and debug builds panic.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        #[track_caller]
+        pub const fn $name(duo: $uX, div: $uX) -> $uX {
+            if div == 0 {
+                $zero_div_fn()
+            }
+            debug_assert!(duo % div == 0, "the division is not exact");
+            // The powers of two of `div` are divided out by a shift, which is exact because `duo`
+            // has at least as many trailing zeros. The odd part has an inverse modulo `2^$n`.
+            let tz = div.trailing_zeros();
+            let odd = div >> tz;
+            let inv = $crate::odd_inverse!(odd, $n, $uX);
+            (duo >> tz).wrapping_mul(inv)
+        }
+
+        /// Returns if `duo` is a multiple of `div`, like `uX::is_multiple_of`. Only 0 is a
+        /// multiple of 0.
+        pub const fn $is_multiple_of(duo: $uX, div: $uX) -> bool {
+            if div == 0 {
+                return duo == 0;
+            }
+            // From "Hacker's Delight" (Henry S. Warren): with the inverse of the odd part of
+            // `div`, the multiples of `div` are exactly the `duo` where `duo * inv` rotated right
+            // by the trailing zeros of `div` is at most `$uX::MAX / div`. Instead of dividing to
+            // get the bound, the rotated value `x` is compared by checking if `x * div` overflows.
+            let tz = div.trailing_zeros();
+            let inv = $crate::odd_inverse!(div >> tz, $n, $uX);
+            duo.wrapping_mul(inv)
+                .rotate_right(tz)
+                .checked_mul(div)
+                .is_some()
+        }
+    };
+}
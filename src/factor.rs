@@ -0,0 +1,140 @@
+/// Creates functions for finding small prime factors by trial division. Divisibility by the odd
+/// primes below 64 is checked with precomputed modular inverses instead of divisions, and larger
+/// candidates come from a wheel that skips multiples of 2, 3, and 5.
+#[macro_export]
+macro_rules! impl_factor {
+    (
+        $smallest_factor:ident, // name of the smallest prime factor function
+        $factor_small_primes:ident, // name of the small prime factorization function
+        $div_rem:ident, // function for division of a $uX by a $uX
+        $uX:ident, // unsigned integer type for the inputs and factors
+    ) => {
+        /// Returns the smallest prime factor of `x`, or `x` itself if `x < 2`. This uses trial
+        /// division, so it is slow for large `x` without small factors.
+        pub fn $smallest_factor(x: $uX) -> $uX {
+            if x < 2 {
+                return x;
+            }
+            let mut factors = [(0, 0)];
+            $factor_small_primes(x, $uX::MAX, &mut factors);
+            factors[0].0
+        }
+
+        /// Divides the prime factors at or below `bound` out of `x` in increasing order, and
+        /// writes them with their multiplicities to `factors`. Returns the number of factors
+        /// written and the remaining cofactor. If `factors` fills up, this returns early and the
+        /// cofactor may still have factors at or below `bound`. `x` can have at most 15 distinct
+        /// prime factors for a `u64` and 25 for a `u128`. If `x == 0`, nothing is written and the
+        /// cofactor is 0.
+        pub fn $factor_small_primes(
+            x: $uX,
+            bound: $uX,
+            factors: &mut [($uX, u32)],
+        ) -> (usize, $uX) {
+            /// The odd primes below 64, with their inverses modulo `2^n` and `$uX::MAX / p`. `x` is
+            /// divisible by `p` if and only if `x * inverse <= $uX::MAX / p`, in which case
+            /// `x * inverse` is also the exact quotient.
+            const SMALL_PRIMES: [($uX, $uX, $uX); 17] = {
+                let primes: [$uX; 17] = [
+                    3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61,
+                ];
+                let mut table = [(0, 0, 0); 17];
+                let mut i = 0;
+                while i < 17 {
+                    let p = primes[i];
+                    // Newton's iteration doubles the number of correct bits of the inverse each
+                    // time, starting from the 3 correct bits of `p` itself
+                    let mut inv = p;
+                    let mut j = 0;
+                    while j < 6 {
+                        inv = inv.wrapping_mul((2 as $uX).wrapping_sub(p.wrapping_mul(inv)));
+                        j += 1;
+                    }
+                    table[i] = (p, inv, $uX::MAX / p);
+                    i += 1;
+                }
+                table
+            };
+            // the gaps between numbers coprime to 30, starting from 67 (which is 7 modulo 30)
+            const WHEEL: [$uX; 8] = [4, 2, 4, 2, 4, 6, 2, 6];
+
+            if x == 0 {
+                return (0, 0);
+            }
+            let mut x = x;
+            let mut len = 0;
+            if factors.is_empty() {
+                return (len, x);
+            }
+            let tz = x.trailing_zeros();
+            if tz != 0 && bound >= 2 {
+                x >>= tz;
+                factors[len] = (2, tz);
+                len += 1;
+                if len == factors.len() {
+                    return (len, x);
+                }
+            }
+            let mut i = 0;
+            while i < SMALL_PRIMES.len() {
+                let (p, inv, lim) = SMALL_PRIMES[i];
+                if p > bound {
+                    return (len, x);
+                }
+                let mut multiplicity = 0;
+                loop {
+                    let quo = x.wrapping_mul(inv);
+                    if quo > lim {
+                        break;
+                    }
+                    x = quo;
+                    multiplicity += 1;
+                }
+                if multiplicity != 0 {
+                    factors[len] = (p, multiplicity);
+                    len += 1;
+                    if len == factors.len() {
+                        return (len, x);
+                    }
+                }
+                i += 1;
+            }
+            let mut d: $uX = 67;
+            let mut i = 0;
+            loop {
+                match d.checked_mul(d) {
+                    Some(sqr) if sqr <= x => (),
+                    _ => {
+                        // there is no factor at or below `sqrt(x)`, so `x` is 1 or a prime
+                        if x != 1 && x <= bound {
+                            factors[len] = (x, 1);
+                            return (len + 1, 1);
+                        }
+                        return (len, x);
+                    }
+                }
+                if d > bound {
+                    return (len, x);
+                }
+                let mut multiplicity = 0;
+                loop {
+                    let (quo, rem) = $div_rem(x, d);
+                    if rem != 0 {
+                        break;
+                    }
+                    x = quo;
+                    multiplicity += 1;
+                }
+                if multiplicity != 0 {
+                    factors[len] = (d, multiplicity);
+                    len += 1;
+                    if len == factors.len() {
+                        return (len, x);
+                    }
+                }
+                d += WHEEL[i];
+                i = (i + 1) & 7;
+            }
+        }
+    };
+}
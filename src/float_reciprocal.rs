@@ -0,0 +1,115 @@
+/// Creates unsigned and signed `u64` division functions and a `u128` by `u64` division step that
+/// seed the quotient from an `f64` reciprocal of the divisor, and then correct it with integer
+/// multiplications. This is designed for targets with fast floating point units but slow or absent
+/// integer division. The types are fixed, because the correction bounds depend on the 53 bit
+/// mantissa of `f64`.
+#[macro_export]
+macro_rules! impl_float_reciprocal {
+    (
+        $unsigned_name:ident, // name of the unsigned division function
+        $signed_name:ident, // name of the signed division function
+        $step_name:ident, // name of the `u128` by `u64` division step function
+        $zero_div_fn:ident, // function called when division by zero is attempted
+        $($unsigned_attr:meta),*; // attributes for the unsigned function
+        $($signed_attr:meta),* // attributes for the signed function
+    ) => {
+        /// Computes the quotient and remainder of `duo` divided by `div` and returns them as a
+        /// tuple.
+        $(
+            #[$unsigned_attr]
+        )*
+        pub fn $unsigned_name(duo: u64, div: u64) -> (u64, u64) {
+            if $crate::hint::unlikely(div == 0) {
+                $zero_div_fn()
+            }
+            if duo < div {
+                return (0, duo)
+            }
+            // Converting `duo` and `div` and the multiplication each have a relative error of at
+            // most `2^-53`, so the estimate `quo` has a relative error below `2^-51` and an
+            // absolute error of at most a few `2^13`. The `as u64` saturates if `duo` rounds up to
+            // `2^64`.
+            let recip = 1.0 / (div as f64);
+            let quo = ((duo as f64) * recip) as u64;
+            // `duo - (quo * div)` is less than `2^78` in magnitude, so it fits in an `i128` even
+            // though the intermediate multiplication may wrap.
+            let rem = (duo as i128).wrapping_sub((quo as i128).wrapping_mul(div as i128));
+            $crate::float_reciprocal_fixup!(quo, rem, div, recip)
+        }
+
+        /// Computes the quotient and remainder of `duo` divided by `div` and returns them as a
+        /// tuple.
+        $(
+            #[$signed_attr]
+        )*
+        pub fn $signed_name(duo: i64, div: i64) -> (i64, i64) {
+            let duo_neg = duo < 0;
+            let div_neg = div < 0;
+            let mut duo = duo;
+            let mut div = div;
+            if duo_neg {
+                duo = duo.wrapping_neg();
+            }
+            if div_neg {
+                div = div.wrapping_neg();
+            }
+            let t = $unsigned_name(duo as u64, div as u64);
+            let mut quo = t.0 as i64;
+            let mut rem = t.1 as i64;
+            if duo_neg {
+                rem = rem.wrapping_neg();
+            }
+            if duo_neg != div_neg {
+                quo = quo.wrapping_neg();
+            }
+            (quo, rem)
+        }
+
+        $crate::unchecked_fn! {
+            /// Computes the quotient and remainder of `duo` divided by `div` and returns them as a
+            /// tuple. This is a drop in replacement for the `$asymmetric_division` function of
+            /// `impl_asymmetric`.
+            ///
+            /// # Safety
+            ///
+            /// The quotient must fit in a `u64` and `div` must not be zero. This does not cause
+            /// undefined behavior otherwise, but the results are meaningless. The function is
+            /// `unsafe` to match hardware division instructions with the same preconditions.
+            pub fn $step_name(duo: u128, div: u64) -> (u64, u64) {
+                debug_assert!(((duo >> 64) as u64) < div);
+                // The same bounds as for `$unsigned_name` apply relative to the quotient, and
+                // `quo * div <= duo`, so the remainder of the estimate is less than `2^78` in
+                // magnitude.
+                let recip = 1.0 / (div as f64);
+                let quo = ((duo as f64) * recip) as u64;
+                let rem = (duo as i128).wrapping_sub((quo as i128).wrapping_mul(div as i128));
+                $crate::float_reciprocal_fixup!(quo, rem, div, recip)
+            }
+        }
+    };
+}
+
+/// The correction shared by the functions of `impl_float_reciprocal`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! float_reciprocal_fixup {
+    ($quo:ident, $rem:ident, $div:ident, $recip:ident) => {{
+        // One more estimate from the remainder brings the error down to at most 2, which is fixed
+        // by the final integer steps.
+        let corr = (($rem as f64) * $recip) as i128;
+        let mut quo = ($quo as i128) + corr;
+        let mut rem = $rem - (corr * ($div as i128));
+        loop {
+            if rem < 0 {
+                quo -= 1;
+                rem += $div as i128;
+            } else if rem >= ($div as i128) {
+                quo += 1;
+                rem -= $div as i128;
+            } else {
+                break;
+            }
+        }
+        (quo as u64, rem as u64)
+    }};
+}
@@ -0,0 +1,71 @@
+/// Creates functions for the remainders modulo `2^k - 1` and `2^k + 1` by folding, which only use
+/// shifts, masks, additions, and subtractions. These moduli show up in checksums and in number
+/// theoretic transforms, where a general division would be wasted.
+#[macro_export]
+macro_rules! impl_fold {
+    (
+        $rem_pow2m1:ident, // name of the remainder modulo `2^k - 1` function
+        $rem_pow2p1:ident, // name of the remainder modulo `2^k + 1` function
+        $n:tt, // the number of bits in a $uX
+        $uX:ident, // unsigned integer type for the inputs
+    ) => {
+        /// Computes `x % (2^k - 1)` by folding. Because `2^k` is congruent to 1, the bits of `x`
+        /// above `k` can be added to the lower `k` bits until the sum fits.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `k == 0` (where the modulus would be zero) or if `k > $n`.
+        #[track_caller]
+        pub fn $rem_pow2m1(x: $uX, k: u32) -> $uX {
+            assert!((k != 0) && (k <= $n), "`k` is out of range");
+            let m = <$uX>::MAX >> ($n - k);
+            let mut x = x;
+            // `(x & m) + (x >> k)` is less than `2^k + 2^(n - k)`, which cannot overflow, and is
+            // less than `x` when `x > m`
+            while x > m {
+                x = (x & m) + (x >> k);
+            }
+            if x == m {
+                0
+            } else {
+                x
+            }
+        }
+
+        /// Computes `x % (2^k + 1)` by folding. Because `2^k` is congruent to -1, the bits of `x`
+        /// above `k` can be subtracted from the lower `k` bits, tracking the sign of the
+        /// difference, until it fits.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `k == 0` or if `k >= $n` (where the modulus would not fit in a `$uX`).
+        #[track_caller]
+        pub fn $rem_pow2p1(x: $uX, k: u32) -> $uX {
+            assert!((k != 0) && (k < $n), "`k` is out of range");
+            let mask = <$uX>::MAX >> ($n - k);
+            let mut x = x;
+            // if `true`, the remainder is congruent to `-x` instead of `x`
+            let mut neg = false;
+            loop {
+                let hi = x >> k;
+                if hi == 0 {
+                    break;
+                }
+                let lo = x & mask;
+                // both of these are less than `x`
+                if lo >= hi {
+                    x = lo - hi;
+                } else {
+                    x = hi - lo;
+                    neg = !neg;
+                }
+            }
+            // `x < 2^k`, so `x` is already reduced
+            if neg && (x != 0) {
+                (mask + 2) - x
+            } else {
+                x
+            }
+        }
+    };
+}
@@ -0,0 +1,77 @@
+/// Creates a function for finding the greatest common divisor of two unsigned integers using the
+/// Euclidean algorithm on top of a division function from this crate.
+#[macro_export]
+macro_rules! impl_gcd {
+    (
+        $name:ident, // name of the greatest common divisor function
+        $div_rem:ident, // function for division of a $uX by a $uX
+        $uX:ident, // unsigned integer type for the inputs and output of `$name`
+        $($attr:meta),* // attributes for the function
+    ) => {
+        /// Returns the greatest common divisor of `lhs` and `rhs`. `gcd(0, 0)` is defined as 0,
+        /// and `gcd(x, 0) == gcd(0, x) == x`.
+        $(
+            #[$attr]
+        )*
+        pub fn $name(lhs: $uX, rhs: $uX) -> $uX {
+            // Binary GCD would avoid divisions entirely, but the Euclidean algorithm needs far
+            // fewer iterations when the division function is fast, and it exercises the algorithms
+            // of this crate.
+            let mut lhs = lhs;
+            let mut rhs = rhs;
+            loop {
+                if rhs == 0 {
+                    return lhs
+                }
+                let rem = $div_rem(lhs, rhs).1;
+                lhs = rhs;
+                rhs = rem;
+            }
+        }
+    }
+}
+
+/// Creates a function for the continued fraction expansion of `num / den`, which is the same loop
+/// as the Euclidean algorithm except that the quotients are kept.
+#[macro_export]
+macro_rules! impl_continued_fraction {
+    (
+        $name:ident, // name of the continued fraction function
+        $zero_div_fn:ident, // function called when division by zero is attempted
+        $div_rem:ident, // function for division of a $uX by a $uX
+        $uX:ident, // unsigned integer type for the inputs and partial quotients
+        $($attr:meta),* // attributes for the function
+    ) => {
+        /// Writes the partial quotients `[a0; a1, a2, ...]` of the continued fraction of
+        /// `num / den` to `quotients`, and returns how many were written. The expansion of a
+        /// rational number is finite, and its last partial quotient is greater than 1 unless the
+        /// expansion is just `[0]` (when `num == 0`) or `[1]` (when `num == den`). If `quotients`
+        /// fills up first, the expansion is truncated and `quotients.len()` is returned.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `den == 0`.
+        $(
+            #[$attr]
+        )*
+        #[track_caller]
+        pub fn $name(num: $uX, den: $uX, quotients: &mut [$uX]) -> usize {
+            if den == 0 {
+                $zero_div_fn()
+            }
+            let mut num = num;
+            let mut den = den;
+            let mut len = 0;
+            loop {
+                if den == 0 || len == quotients.len() {
+                    return len
+                }
+                let (quo, rem) = $div_rem(num, den);
+                quotients[len] = quo;
+                len += 1;
+                num = den;
+                den = rem;
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Branch layout hints used by the division macros through `$crate::hint`. Functions used as a
+//! `$zero_div_fn` should also be marked `#[cold]`, since division by zero is the rarest path of
+//! all.
+
+/// Hints that `b` is usually `true`. This uses `core::intrinsics::likely` when the nightly only
+/// `branch-hints` feature is enabled, and is a no-op otherwise.
+#[inline(always)]
+pub const fn likely(b: bool) -> bool {
+    #[cfg(feature = "branch-hints")]
+    {
+        core::intrinsics::likely(b)
+    }
+    #[cfg(not(feature = "branch-hints"))]
+    {
+        b
+    }
+}
+
+/// Hints that `b` is usually `false`. This uses `core::intrinsics::unlikely` when the nightly only
+/// `branch-hints` feature is enabled, and is a no-op otherwise.
+#[inline(always)]
+pub const fn unlikely(b: bool) -> bool {
+    #[cfg(feature = "branch-hints")]
+    {
+        core::intrinsics::unlikely(b)
+    }
+    #[cfg(not(feature = "branch-hints"))]
+    {
+        b
+    }
+}
+
+/// If the `profile-quotient-heavy` feature is enabled, the early exit branches of the algorithms
+/// are reordered so that the branches for large quotients (small divisors) are checked first. By
+/// default, the branches for small quotients are checked first, which suits remainder-heavy
+/// workloads such as modular reduction where the dividend is usually not much larger than the
+/// divisor.
+pub const QUOTIENT_HEAVY: bool = cfg!(feature = "profile-quotient-heavy");
+
+/// If the `profile-small-operands` feature is enabled, the algorithms first check if both operands
+/// fit in the half sized integer and divide them with the half division, for workloads where wide
+/// integers usually hold small values. This is checked before the branches of
+/// `QUOTIENT_HEAVY` if both profiles are enabled.
+pub const SMALL_OPERANDS: bool = cfg!(feature = "profile-small-operands");
@@ -1,23 +1,177 @@
 // TODO: when `unsafe_block_in_unsafe_fn` is stabilized, remove this
 #![allow(unused_unsafe)]
 
-fn zero_div_fn() -> ! {
+mod reciprocal;
+pub use reciprocal::u128_by_u64_div_rem_reciprocal;
+
+// Only the widths and algorithms enabled by features are implemented (see `Cargo.toml`), so not
+// every helper is used by every combination of features.
+
+#[allow(dead_code)]
+#[cold]
+#[track_caller]
+const fn zero_div_fn() -> ! {
     panic!("attempt to divide by zero")
 }
 
+#[allow(dead_code)]
 fn u16_by_u16_div_rem(duo: u16, div: u16) -> (u16, u16) {
     (duo / div, duo % div)
 }
 
+#[cfg(not(target_pointer_width = "16"))]
+#[allow(dead_code)]
 fn u32_by_u32_div_rem(duo: u32, div: u32) -> (u32, u32) {
     (duo / div, duo % div)
 }
 
-#[cfg(any(not(feature = "asm"), not(target_arch = "x86")))]
-unsafe fn u64_by_u32_div_rem(duo: u64, div: u32) -> (u32, u32) {
-    let duo_hi = (duo >> 32) as u32;
-    debug_assert!(duo_hi < div);
-    ((duo / (div as u64)) as u32, (duo % (div as u64)) as u32)
+// 16 bit targets (AVR and MSP430) have at most a 16 bit division, and the `/` operator on `u32` and
+// `u64` would call the generic shift-subtract routines of `compiler-builtins`. Instead, the half
+// divisions used by the algorithms of the larger widths are `_delegate` built on the `u16`
+// division, and the `u64` division is `_delegate` on top of that.
+#[cfg(target_pointer_width = "16")]
+mod half_div_16 {
+    use super::*;
+
+    impl_delegate!(
+        u32_by_u32_div_rem,
+        i32_by_i32_div_rem,
+        zero_div_fn,
+        u16_normalization_shift,
+        u16_by_u16_div_rem,
+        8,
+        u8,
+        u16,
+        u32,
+        i32,
+        allow(dead_code),
+        inline;
+        allow(dead_code)
+    );
+    impl_delegate!(
+        u64_by_u64_div_rem,
+        i64_by_i64_div_rem,
+        zero_div_fn,
+        u32_normalization_shift,
+        u32_by_u32_div_rem,
+        16,
+        u16,
+        u32,
+        u64,
+        i64,
+        allow(dead_code),
+        inline(never);
+        allow(dead_code)
+    );
+}
+#[cfg(target_pointer_width = "16")]
+#[allow(unused_imports)]
+use half_div_16::{u32_by_u32_div_rem, u64_by_u64_div_rem};
+
+/// `RECIPROCALS[d] == u16::MAX / d` for `0 < d <= 256`, which the `_table` functions use instead
+/// of a division loop
+#[cfg(feature = "table")]
+#[allow(dead_code)]
+const RECIPROCALS: [u16; 257] = {
+    let mut table = [0u16; 257];
+    let mut d = 1;
+    while d < 257 {
+        table[d] = ((u16::MAX as u32) / (d as u32)) as u16;
+        d += 1;
+    }
+    table
+};
+
+/// Divides `duo` by `div` with `RECIPROCALS` and returns a tuple of the quotient and the
+/// remainder, where `0 < div <= 256`
+#[cfg(feature = "table")]
+#[allow(dead_code)]
+#[inline]
+fn u16_by_table_div_rem(duo: u16, div: u16) -> (u16, u16) {
+    // `RECIPROCALS[div] >= (2^16 - div) / div`, so `duo * RECIPROCALS[div] / 2^16` is more than
+    // `duo / div - duo / 2^16`, and the estimated quotient is at most one too small.
+    let mut quo = (((duo as u32) * (RECIPROCALS[div as usize] as u32)) >> 16) as u16;
+    let mut rem = duo - (quo * div);
+    if rem >= div {
+        quo += 1;
+        rem -= div;
+    }
+    (quo, rem)
+}
+
+#[cfg(any(
+    not(all(feature = "asm", not(feature = "no-unsafe"))),
+    not(target_arch = "avr")
+))]
+crate::unchecked_fn! {
+    #[allow(dead_code)]
+    fn u16_by_u8_div_rem(duo: u16, div: u8) -> (u8, u8) {
+        let duo_hi = (duo >> 8) as u8;
+        debug_assert!(duo_hi < div);
+        ((duo / (div as u16)) as u8, (duo % (div as u16)) as u8)
+    }
+}
+
+/// Divides `duo` by `div` and returns a tuple of the quotient and the remainder. AVR has no
+/// division instruction, so this is a restoring division loop that only has to run for the 8 bits
+/// of the quotient.
+///
+/// # Safety
+///
+/// The quotient has to fit in a `u8` (`(duo >> 8) < div`), otherwise the results are wrong.
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+#[allow(dead_code)]
+unsafe fn u16_by_u8_div_rem(duo: u16, div: u8) -> (u8, u8) {
+    debug_assert!(((duo >> 8) as u8) < div);
+    let quo: u8;
+    let rem: u8;
+    unsafe {
+        // The bits of the lower half of `duo` are shifted from `quo` into `rem`, which starts as
+        // the upper half, and the bits of the quotient are shifted into the bottom of `quo`. A
+        // carry out of `rem` means that it is definitely larger than `div`.
+        core::arch::asm!(
+            "ldi {cnt}, 8",
+            "1:",
+            "lsl {quo}",
+            "rol {rem}",
+            "brcs 2f",
+            "cp {rem}, {div}",
+            "brcs 3f",
+            "2:",
+            "sub {rem}, {div}",
+            "inc {quo}",
+            "3:",
+            "dec {cnt}",
+            "brne 1b",
+            div = in(reg) div,
+            quo = inout(reg) duo as u8 => quo,
+            rem = inout(reg) (duo >> 8) as u8 => rem,
+            cnt = out(reg_upper) _,
+            options(pure, nomem, nostack)
+        );
+    }
+    (quo, rem)
+}
+
+#[cfg(any(
+    not(all(feature = "asm", not(feature = "no-unsafe"))),
+    not(target_arch = "x86")
+))]
+crate::unchecked_fn! {
+    /// Divides `duo` by `div` and returns a tuple of the quotient and the remainder, without
+    /// checking that the quotient fits in a `u32`. This is `unsafe` because some targets use a
+    /// hardware division instruction for it, and is left out with the `no-unsafe` feature.
+    ///
+    /// # Safety
+    ///
+    /// The quotient has to fit in a `u32` (`(duo >> 32) < div`, which also implies `div != 0`),
+    /// otherwise the results are wrong or a hardware exception occurs.
+    #[allow(dead_code)]
+    pub fn u64_by_u32_div_rem_unchecked(duo: u64, div: u32) -> (u32, u32) {
+        let duo_hi = (duo >> 32) as u32;
+        debug_assert!(duo_hi < div);
+        ((duo / (div as u64)) as u32, (duo % (div as u64)) as u32)
+    }
 }
 
 /// Divides `duo` by `div` and returns a tuple of the quotient and the remainder.
@@ -26,8 +180,9 @@ unsafe fn u64_by_u32_div_rem(duo: u64, div: u32) -> (u32, u32) {
 ///
 /// If the quotient does not fit in a `u32`, a floating point exception occurs.
 /// If `div == 0`, then a division by zero exception occurs.
-#[cfg(all(feature = "asm", target_arch = "x86"))]
-unsafe fn u64_by_u32_div_rem(duo: u64, div: u32) -> (u32, u32) {
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "x86"))]
+#[allow(dead_code)]
+pub unsafe fn u64_by_u32_div_rem_unchecked(duo: u64, div: u32) -> (u32, u32) {
     let duo_lo = duo as u32;
     let duo_hi = (duo >> 32) as u32;
     debug_assert!(duo_hi < div);
@@ -47,15 +202,79 @@ unsafe fn u64_by_u32_div_rem(duo: u64, div: u32) -> (u32, u32) {
     (quo, rem)
 }
 
+/// Divides `duo` by `div` and returns a tuple of the quotient and the remainder, or `None` if the
+/// quotient does not fit in a `u32` (including when `div == 0`). This is the `u64` by `u32`
+/// division step of many algorithms, which uses a hardware division instruction where there is one.
+pub fn u64_by_u32_div_rem(duo: u64, div: u32) -> Option<(u32, u32)> {
+    if ((duo >> 32) as u32) < div {
+        Some(crate::unchecked_call!(u64_by_u32_div_rem_unchecked(
+            duo, div
+        )))
+    } else {
+        None
+    }
+}
+
+#[cfg(not(target_pointer_width = "16"))]
+#[allow(dead_code)]
 fn u64_by_u64_div_rem(duo: u64, div: u64) -> (u64, u64) {
     (duo / div, duo % div)
 }
 
-#[cfg(any(not(feature = "asm"), not(target_arch = "x86_64")))]
-unsafe fn u128_by_u64_div_rem(duo: u128, div: u64) -> (u64, u64) {
-    let duo_hi = (duo >> 64) as u64;
-    debug_assert!(duo_hi < div);
-    ((duo / (div as u128)) as u64, (duo % (div as u128)) as u64)
+#[allow(dead_code)]
+const fn u128_by_u128_div_rem(duo: u128, div: u128) -> (u128, u128) {
+    (duo / div, duo % div)
+}
+
+/// Returns the full product of `lhs` and `rhs` as a tuple of the lower and upper halves
+#[allow(dead_code)]
+const fn u128_widening_mul(lhs: u128, rhs: u128) -> (u128, u128) {
+    let lhs_0 = lhs as u64 as u128;
+    let lhs_1 = lhs >> 64;
+    let rhs_0 = rhs as u64 as u128;
+    let rhs_1 = rhs >> 64;
+    let mul_00 = lhs_0 * rhs_0;
+    let mul_01 = lhs_0 * rhs_1;
+    let mul_10 = lhs_1 * rhs_0;
+    let mul_11 = lhs_1 * rhs_1;
+    // the sum of three values less than `2^64` cannot overflow
+    let mid = (mul_00 >> 64) + (mul_01 as u64 as u128) + (mul_10 as u64 as u128);
+    let lo = (mul_00 as u64 as u128) | (mid << 64);
+    let hi = mul_11 + (mul_01 >> 64) + (mul_10 >> 64) + (mid >> 64);
+    (lo, hi)
+}
+
+#[cfg(any(
+    not(all(feature = "asm", not(feature = "no-unsafe"))),
+    not(any(
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "powerpc64",
+        target_arch = "s390x"
+    ))
+))]
+crate::unchecked_fn! {
+    /// Divides `duo` by `div` and returns a tuple of the quotient and the remainder, without
+    /// checking that the quotient fits in a `u64`. This is `unsafe` because some targets use a
+    /// hardware division instruction for it, and is left out with the `no-unsafe` feature.
+    ///
+    /// # Safety
+    ///
+    /// The quotient has to fit in a `u64` (`(duo >> 64) < div`, which also implies `div != 0`),
+    /// otherwise the results are wrong or a hardware exception occurs.
+    #[allow(dead_code)]
+    pub fn u128_by_u64_div_rem_unchecked(duo: u128, div: u64) -> (u64, u64) {
+        let duo_hi = (duo >> 64) as u64;
+        debug_assert!(duo_hi < div);
+        // The `/` operator would call `__udivti3`, which does not know that the quotient fits in
+        // a `u64`, so the reciprocal based division is used instead. `duo < div * 2^64`, so no
+        // set bits of `duo` are shifted out by the normalization.
+        let shl = div.leading_zeros();
+        let div = div << shl;
+        let (quo, rem) =
+            reciprocal::u128_by_u64_div_rem_recip(duo << shl, div, reciprocal::u64_reciprocal(div));
+        (quo, rem >> shl)
+    }
 }
 
 /// Divides `duo` by `div` and returns a tuple of the quotient and the remainder.
@@ -64,8 +283,9 @@ unsafe fn u128_by_u64_div_rem(duo: u128, div: u64) -> (u64, u64) {
 ///
 /// If the quotient does not fit in a `u64`, a floating point exception occurs.
 /// If `div == 0`, then a division by zero exception occurs.
-#[cfg(all(feature = "asm", target_arch = "x86_64"))]
-unsafe fn u128_by_u64_div_rem(duo: u128, div: u64) -> (u64, u64) {
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "x86_64"))]
+#[allow(dead_code)]
+pub unsafe fn u128_by_u64_div_rem_unchecked(duo: u128, div: u64) -> (u64, u64) {
     let duo_lo = duo as u64;
     let duo_hi = (duo >> 64) as u64;
     debug_assert!(duo_hi < div);
@@ -85,11 +305,275 @@ unsafe fn u128_by_u64_div_rem(duo: u128, div: u64) -> (u64, u64) {
     (quo, rem)
 }
 
+/// Divides `duo` by `div` and returns a tuple of the quotient and the remainder.
+///
+/// # Safety
+///
+/// If the quotient does not fit in a `u64`, a fixed point divide exception occurs.
+/// If `div == 0`, then a fixed point divide exception occurs.
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "s390x"))]
+#[allow(dead_code)]
+pub unsafe fn u128_by_u64_div_rem_unchecked(duo: u128, div: u64) -> (u64, u64) {
+    let duo_lo = duo as u64;
+    let duo_hi = (duo >> 64) as u64;
+    debug_assert!(duo_hi < div);
+    let quo: u64;
+    let rem: u64;
+    unsafe {
+        // divides the even/odd register pair r0:r1 (`duo` is split into two 64 bit parts to do
+        // this) by `div`. The remainder is stored in r0 and the quotient in r1.
+        core::arch::asm!(
+            "dlgr %r0, {0}",
+            in(reg) div,
+            inlateout("r0") duo_hi => rem,
+            inlateout("r1") duo_lo => quo,
+            options(pure, nomem, nostack)
+        );
+    }
+    (quo, rem)
+}
+
+/// Divides `duo` by `div` and returns a tuple of the quotient and the remainder.
+///
+/// # Safety
+///
+/// The quotient has to fit in a `u64` (`(duo >> 64) < div`, which also implies `div != 0`),
+/// otherwise the results are wrong.
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    any(target_arch = "aarch64", target_arch = "powerpc64")
+))]
+#[allow(dead_code)]
+pub unsafe fn u128_by_u64_div_rem_unchecked(duo: u128, div: u64) -> (u64, u64) {
+    debug_assert!(((duo >> 64) as u64) < div);
+    // AArch64 and PowerPC64 have no 128 by 64 bit division, but `umulh` and `mulhdu` are fast on
+    // them, so this is the reciprocal based division of `reciprocal` with the estimate calculated
+    // by a high multiplication and an add with carry. `duo < div * 2^64`, so no set bits of `duo`
+    // are shifted out by the normalization.
+    let shl = div.leading_zeros();
+    let div = div << shl;
+    let recip = reciprocal::u64_reciprocal(div);
+    let duo = duo << shl;
+    let duo_lo = duo as u64;
+    let duo_hi = (duo >> 64) as u64;
+    let tmp_lo: u64;
+    let tmp_hi: u64;
+    unsafe {
+        // `(tmp_hi, tmp_lo) = recip * duo_hi + duo`, which cannot overflow because `recip < 2^64`
+        // and `duo_hi < 2^64 - 1`
+        #[cfg(target_arch = "aarch64")]
+        core::arch::asm!(
+            "mul {lo}, {recip}, {duo_hi}",
+            "umulh {hi}, {recip}, {duo_hi}",
+            "adds {lo}, {lo}, {duo_lo}",
+            "adc {hi}, {hi}, {duo_hi}",
+            recip = in(reg) recip,
+            duo_hi = in(reg) duo_hi,
+            duo_lo = in(reg) duo_lo,
+            lo = out(reg) tmp_lo,
+            hi = out(reg) tmp_hi,
+            options(pure, nomem, nostack)
+        );
+        // the carry of `addc` is in the `CA` bit of `xer`
+        #[cfg(target_arch = "powerpc64")]
+        core::arch::asm!(
+            "mulld {lo}, {recip}, {duo_hi}",
+            "mulhdu {hi}, {recip}, {duo_hi}",
+            "addc {lo}, {lo}, {duo_lo}",
+            "adde {hi}, {hi}, {duo_hi}",
+            recip = in(reg) recip,
+            duo_hi = in(reg) duo_hi,
+            duo_lo = in(reg) duo_lo,
+            lo = out(reg) tmp_lo,
+            hi = out(reg) tmp_hi,
+            out("xer") _,
+            options(pure, nomem, nostack)
+        );
+    }
+    // the same corrections as `reciprocal::u128_by_u64_div_rem_recip`
+    let mut quo = tmp_hi.wrapping_add(1);
+    let mut rem = duo_lo.wrapping_sub(quo.wrapping_mul(div));
+    if rem > tmp_lo {
+        quo = quo.wrapping_sub(1);
+        rem = rem.wrapping_add(div);
+    }
+    if rem >= div {
+        quo += 1;
+        rem -= div;
+    }
+    (quo, rem >> shl)
+}
+
+/// Divides `duo` by `div` and returns a tuple of the quotient and the remainder, or `None` if the
+/// quotient does not fit in a `u64` (including when `div == 0`). This is the `u128` by `u64`
+/// division step of many algorithms, which uses a hardware division instruction where there is one.
+pub fn u128_by_u64_div_rem(duo: u128, div: u64) -> Option<(u64, u64)> {
+    if ((duo >> 64) as u64) < div {
+        Some(crate::unchecked_call!(u128_by_u64_div_rem_unchecked(
+            duo, div
+        )))
+    } else {
+        None
+    }
+}
+
+// These use the hardware division instructions directly to provide a test oracle that is
+// independent of LLVM's own lowering of `/` and `%`. Unlike the asm functions above, they are safe
+// and cover the whole input range.
+#[allow(unused_macros)]
+macro_rules! impl_hardware_oracle {
+    (
+        $unsigned_name:ident,
+        $signed_name:ident,
+        $uX:ident,
+        $iX:ident,
+        // the `x86_64` register modifier and the `aarch64` register modifier for `$uX`
+        $x86_mod:tt,
+        $arm_mod:tt
+    ) => {
+        /// Computes the quotient and remainder of `duo` divided by `div` with a hardware division
+        /// instruction and returns them as a tuple.
+        #[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "x86_64"))]
+        #[track_caller]
+        pub fn $unsigned_name(duo: $uX, div: $uX) -> ($uX, $uX) {
+            if div == 0 {
+                zero_div_fn()
+            }
+            let quo: $uX;
+            let rem: $uX;
+            unsafe {
+                // the high part of the dividend in rdx is 0, so the quotient always fits
+                core::arch::asm!(
+                    concat!("div {0", $x86_mod, "}"),
+                    in(reg) div,
+                    inlateout("rax") duo => quo,
+                    inlateout("rdx") 0 as $uX => rem,
+                    options(pure, nomem, nostack)
+                );
+            }
+            (quo, rem)
+        }
+
+        /// Computes the quotient and remainder of `duo` divided by `div` with a hardware division
+        /// instruction and returns them as a tuple.
+        #[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "x86_64"))]
+        #[track_caller]
+        pub fn $signed_name(duo: $iX, div: $iX) -> ($iX, $iX) {
+            if div == 0 {
+                zero_div_fn()
+            }
+            if div == -1 {
+                // `idiv` raises an exception for `MIN / -1` instead of wrapping
+                return (duo.wrapping_neg(), 0)
+            }
+            let quo: $iX;
+            let rem: $iX;
+            unsafe {
+                // the high part of the dividend in rdx is the sign extension of `duo`
+                core::arch::asm!(
+                    concat!("idiv {0", $x86_mod, "}"),
+                    in(reg) div,
+                    inlateout("rax") duo => quo,
+                    inlateout("rdx") duo >> ($iX::MAX.count_ones()) => rem,
+                    options(pure, nomem, nostack)
+                );
+            }
+            (quo, rem)
+        }
+
+        /// Computes the quotient and remainder of `duo` divided by `div` with a hardware division
+        /// instruction and returns them as a tuple.
+        #[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "aarch64"))]
+        #[track_caller]
+        pub fn $unsigned_name(duo: $uX, div: $uX) -> ($uX, $uX) {
+            if div == 0 {
+                zero_div_fn()
+            }
+            let quo: $uX;
+            let rem: $uX;
+            unsafe {
+                core::arch::asm!(
+                    concat!("udiv {0", $arm_mod, "}, {2", $arm_mod, "}, {3", $arm_mod, "}"),
+                    concat!(
+                        "msub {1", $arm_mod, "}, {0", $arm_mod, "}, {3", $arm_mod, "}, {2",
+                        $arm_mod, "}"
+                    ),
+                    out(reg) quo,
+                    out(reg) rem,
+                    in(reg) duo,
+                    in(reg) div,
+                    options(pure, nomem, nostack)
+                );
+            }
+            (quo, rem)
+        }
+
+        /// Computes the quotient and remainder of `duo` divided by `div` with a hardware division
+        /// instruction and returns them as a tuple.
+        #[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "aarch64"))]
+        #[track_caller]
+        pub fn $signed_name(duo: $iX, div: $iX) -> ($iX, $iX) {
+            if div == 0 {
+                zero_div_fn()
+            }
+            let quo: $iX;
+            let rem: $iX;
+            unsafe {
+                // `sdiv` wraps for `MIN / -1`
+                core::arch::asm!(
+                    concat!("sdiv {0", $arm_mod, "}, {2", $arm_mod, "}, {3", $arm_mod, "}"),
+                    concat!(
+                        "msub {1", $arm_mod, "}, {0", $arm_mod, "}, {3", $arm_mod, "}, {2",
+                        $arm_mod, "}"
+                    ),
+                    out(reg) quo,
+                    out(reg) rem,
+                    in(reg) duo,
+                    in(reg) div,
+                    options(pure, nomem, nostack)
+                );
+            }
+            (quo, rem)
+        }
+    };
+}
+
+// `defmt::Format` for the types created by `impl_ratio`, `impl_invariant_rem`, and
+// `impl_precomputed_divisor`. This is done here instead of in the exported macros, because a
+// `cfg(feature = "defmt")` in an exported macro would be evaluated against the features of the
+// crate invoking it.
+#[allow(unused_macros)]
+macro_rules! impl_defmt_ratio {
+    ($name:ident) => {
+        #[cfg(feature = "defmt")]
+        impl defmt::Format for $name {
+            fn format(&self, f: defmt::Formatter) {
+                defmt::write!(f, "{}/{}", self.num, self.den)
+            }
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_defmt_invariant_rem {
+    ($name:ident) => {
+        #[cfg(feature = "defmt")]
+        impl defmt::Format for $name {
+            fn format(&self, f: defmt::Formatter) {
+                defmt::write!(f, "{}(div: {})", stringify!($name), self.divisor())
+            }
+        }
+    };
+}
+
 // The `B` extension on RISC-V determines if a CLZ assembly instruction exists
 #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[allow(dead_code)]
 const USE_LZ: bool = cfg!(target_feature = "b");
 
 #[cfg(target_arch = "arm")]
+#[allow(dead_code)]
 const USE_LZ: bool = if cfg!(target_feature = "thumb-mode") {
     // ARM thumb targets have CLZ instructions if the instruction set of ARMv6T2 is supported. This
     // is needed to successfully differentiate between targets like `thumbv8.base` and
@@ -102,14 +586,71 @@ const USE_LZ: bool = if cfg!(target_feature = "thumb-mode") {
     cfg!(target_feature = "v5te")
 };
 
+// The 16 bit targets (AVR and MSP430) have no CLZ instructions, and LLVM's expansion of
+// `leading_zeros` is a long loop of single bit shifts on them
+#[cfg(target_pointer_width = "16")]
+#[allow(dead_code)]
+const USE_LZ: bool = false;
+
+// PowerPC has `cntlzw`, and PowerPC64 also has `cntlzd`
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+#[allow(dead_code)]
+const USE_LZ: bool = true;
+
 // All other targets Rust supports have CLZ instructions
-#[cfg(not(any(target_arch = "arm", target_arch = "riscv32", target_arch = "riscv64")))]
+#[cfg(not(any(
+    target_arch = "arm",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "riscv32",
+    target_arch = "riscv64",
+    target_pointer_width = "16"
+)))]
+#[allow(dead_code)]
 const USE_LZ: bool = true;
 
-impl_normalization_shift!(u8_normalization_shift, USE_LZ, 8, u8, i8,);
-impl_normalization_shift!(u16_normalization_shift, USE_LZ, 16, u16, i16,);
-impl_normalization_shift!(u32_normalization_shift, USE_LZ, 32, u32, i32,);
-impl_normalization_shift!(u64_normalization_shift, USE_LZ, 64, u64, i64,);
+impl_normalization_shift!(u8_normalization_shift, USE_LZ, 8, u8, i8, allow(dead_code));
+impl_normalization_shift!(
+    u16_normalization_shift,
+    USE_LZ,
+    16,
+    u16,
+    i16,
+    allow(dead_code)
+);
+impl_normalization_shift!(
+    u32_normalization_shift,
+    USE_LZ,
+    32,
+    u32,
+    i32,
+    allow(dead_code)
+);
+impl_normalization_shift!(
+    u64_normalization_shift,
+    USE_LZ,
+    64,
+    u64,
+    i64,
+    allow(dead_code)
+);
+impl_normalization_shift!(
+    u128_normalization_shift,
+    USE_LZ,
+    128,
+    u128,
+    i128,
+    allow(dead_code)
+);
+#[cfg(feature = "width-512")]
+impl_normalization_shift!(
+    u256_normalization_shift,
+    USE_LZ,
+    256,
+    u256,
+    i256,
+    allow(dead_code)
+);
 
 // Note: one reason for the macros having a `$half_division:ident` instead of directly calling the
 // `/` and `%` builtin operators is that allows using different algorithms for the half
@@ -124,7 +665,10 @@ impl_normalization_shift!(u64_normalization_shift, USE_LZ, 64, u64, i64,);
 
 // Note: The overhead of the existing binary long division algorithm setup is high enough that
 // faster algorithms for 8 bit and 16 bit divisions probably exist. However, the smallest division
-// in `compiler-builtins` is 32 bits, so these cases are only left in for testing purposes.
+// in `compiler-builtins` is 32 bits, so these cases are mostly left in for testing purposes. The
+// exception is AVR, the main consumer of 8 and 16 bit software division, which gets the hand
+// written `_avr` functions with the `asm` feature. The `_table` functions replace the loop with a
+// multiplication by a reciprocal from a small table, for targets with fast multipliers.
 
 // Inlining is only done on the signed function in order to encourage optimal branching if LLVM
 // knows that one or both inputs cannot be negative. `inline(never)` is applied to the unsigned
@@ -132,178 +676,173 @@ impl_normalization_shift!(u64_normalization_shift, USE_LZ, 64, u64, i64,);
 // 4 times into the 4 branches of the signed function implementations. Inlining the unsigned
 // division functions results in huge code bloat.
 
-// 8 bit
-impl_binary_long!(
-    u8_div_rem_binary_long,
-    i8_div_rem_binary_long,
-    zero_div_fn,
-    u8_normalization_shift,
-    8,
-    u8,
-    i8,
-    inline(never);
-    inline
-);
+#[cfg(feature = "bignum")]
+pub mod bignum;
+#[cfg(feature = "bignum")]
+mod uint;
+#[cfg(feature = "bignum")]
+pub use uint::{Uint, U256, U512};
 
-// 16 bit
-impl_binary_long!(
-    u16_div_rem_binary_long,
-    i16_div_rem_binary_long,
-    zero_div_fn,
-    u16_normalization_shift,
-    16,
-    u16,
-    i16,
-    inline(never);
-    inline
-);
+#[cfg(all(feature = "portable-simd", feature = "width-32"))]
+mod simd;
+#[cfg(all(feature = "portable-simd", feature = "width-32"))]
+pub use simd::*;
 
-// 32 bit
-impl_binary_long!(
-    u32_div_rem_binary_long,
-    i32_div_rem_binary_long,
-    zero_div_fn,
-    u32_normalization_shift,
-    32,
-    u32,
-    i32,
-    inline(never);
-    inline
-);
-impl_delegate!(
-    u32_div_rem_delegate,
-    i32_div_rem_delegate,
-    zero_div_fn,
-    u16_normalization_shift,
-    u16_by_u16_div_rem,
-    8,
-    u8,
-    u16,
-    u32,
-    i32,
-    inline(never);
-    inline
-);
+#[cfg(all(feature = "width-128", feature = "trifecta"))]
+mod digits;
+#[cfg(all(feature = "width-128", feature = "trifecta"))]
+pub use digits::U128Digits;
 
-// 64 bit
-impl_binary_long!(
-    u64_div_rem_binary_long,
-    i64_div_rem_binary_long,
-    zero_div_fn,
-    u64_normalization_shift,
-    64,
-    u64,
-    i64,
-    inline(never);
-    inline
-);
-impl_delegate!(
-    u64_div_rem_delegate,
-    i64_div_rem_delegate,
-    zero_div_fn,
-    u32_normalization_shift,
-    u32_by_u32_div_rem,
-    16,
-    u16,
-    u32,
-    u64,
-    i64,
-    inline(never);
-    inline
-);
-impl_trifecta!(
-    u64_div_rem_trifecta,
-    i64_div_rem_trifecta,
-    zero_div_fn,
-    u32_by_u32_div_rem,
-    16,
-    u16,
-    u32,
-    u64,
-    i64,
-    inline(never);
-    inline
-);
-impl_asymmetric!(
-    u64_div_rem_asymmetric,
-    i64_div_rem_asymmetric,
-    zero_div_fn,
-    u32_by_u32_div_rem,
-    u64_by_u32_div_rem,
-    16,
-    u16,
-    u32,
-    u64,
-    i64,
-    inline(never);
-    inline
-);
+#[cfg(any(feature = "width-32", feature = "width-64", feature = "width-128"))]
+mod nonzero;
+#[cfg(any(feature = "width-32", feature = "width-64", feature = "width-128"))]
+pub use nonzero::*;
 
-// 128 bit
-impl_delegate!(
-    u128_div_rem_delegate,
-    i128_div_rem_delegate,
-    zero_div_fn,
-    u64_normalization_shift,
-    u64_by_u64_div_rem,
-    32,
-    u32,
-    u64,
-    u128,
-    i128,
-    inline(never);
-    inline
-);
-impl_trifecta!(
-    u128_div_rem_trifecta,
-    i128_div_rem_trifecta,
-    zero_div_fn,
-    u64_by_u64_div_rem,
-    32,
-    u32,
-    u64,
-    u128,
-    i128,
-    inline(never);
-    inline
-);
-impl_asymmetric!(
-    u128_div_rem_asymmetric,
-    i128_div_rem_asymmetric,
-    zero_div_fn,
-    u64_by_u64_div_rem,
-    u128_by_u64_div_rem,
-    32,
-    u32,
-    u64,
-    u128,
-    i128,
-    inline(never);
-    inline
-);
+#[cfg(feature = "width-64")]
+mod duration;
+#[cfg(feature = "width-64")]
+pub use duration::*;
 
-// Demonstrate inlining to eliminate unused instructions for quotient-only computation
-mod inliner {
-    use super::*;
+#[cfg(feature = "width-64")]
+mod radix;
+#[cfg(feature = "width-64")]
+pub use radix::*;
 
-    impl_asymmetric!(
-        u128_div_rem_asymmetric_inline,
-        _unused,
-        zero_div_fn,
-        u64_by_u64_div_rem,
-        u128_by_u64_div_rem,
-        32,
-        u32,
-        u64,
-        u128,
-        i128,
-        inline(always);
-    );
+mod bit_scan;
+pub use bit_scan::*;
 
-    /// Returns the quotient of `duo` divided by `div`
-    pub fn u128_div_asymmetric(duo: u128, div: u128) -> u128 {
-        u128_div_rem_asymmetric_inline(duo, div).0
-    }
-}
+#[cfg(all(
+    feature = "binary-long",
+    any(feature = "width-32", feature = "width-64")
+))]
+mod swar;
+#[cfg(all(
+    feature = "binary-long",
+    any(feature = "width-32", feature = "width-64")
+))]
+pub use swar::*;
+
+mod traits;
+pub use traits::*;
+
+#[cfg(feature = "self-check")]
+mod self_check;
+#[cfg(feature = "self-check")]
+pub use self_check::*;
+
+#[cfg(all(feature = "fixed", feature = "width-128", feature = "trifecta"))]
+mod fixed;
+#[cfg(all(feature = "fixed", feature = "width-128", feature = "trifecta"))]
+pub use self::fixed::WideDiv;
+
+#[cfg(feature = "ethnum")]
+mod ethnum;
+
+#[cfg(any(feature = "width-64", feature = "width-128"))]
+pub mod barrett;
+
+#[cfg(any(feature = "width-64", feature = "width-128"))]
+pub mod montgomery;
+
+#[cfg(all(
+    feature = "export-aeabi",
+    feature = "width-64",
+    feature = "delegate",
+    target_arch = "arm"
+))]
+pub mod aeabi;
+
+#[cfg(all(
+    feature = "width-32",
+    feature = "width-64",
+    feature = "width-128",
+    feature = "binary-long",
+    feature = "delegate",
+    feature = "trifecta",
+    feature = "asymmetric"
+))]
+pub mod target_defaults;
+
+#[cfg(all(
+    feature = "width-32",
+    feature = "width-64",
+    feature = "width-128",
+    feature = "binary-long",
+    feature = "delegate",
+    feature = "trifecta",
+    feature = "asymmetric"
+))]
+mod bits;
+#[cfg(all(
+    feature = "width-32",
+    feature = "width-64",
+    feature = "width-128",
+    feature = "binary-long",
+    feature = "delegate",
+    feature = "trifecta",
+    feature = "asymmetric"
+))]
+pub use bits::div_rem_bits;
+
+#[cfg(all(
+    feature = "std",
+    feature = "width-32",
+    feature = "width-64",
+    feature = "width-128",
+    feature = "binary-long",
+    feature = "delegate",
+    feature = "trifecta",
+    feature = "asymmetric"
+))]
+pub mod tuner;
+
+// A width can end up empty if none of the algorithms it uses are enabled
+
+#[cfg(feature = "width-8")]
+mod width_8;
+#[cfg(feature = "width-8")]
+#[allow(unused_imports)]
+pub use width_8::*;
+
+#[cfg(feature = "width-16")]
+mod width_16;
+#[cfg(feature = "width-16")]
+#[allow(unused_imports)]
+pub use width_16::*;
+
+#[cfg(feature = "width-32")]
+mod width_32;
+#[cfg(feature = "width-32")]
+#[allow(unused_imports)]
+pub use width_32::*;
+
+#[cfg(feature = "width-64")]
+mod width_64;
+#[cfg(feature = "width-64")]
+#[allow(unused_imports)]
+pub use width_64::*;
+
+#[cfg(feature = "width-128")]
+mod width_128;
+#[cfg(feature = "width-128")]
+#[allow(unused_imports)]
+pub use width_128::*;
+
+#[cfg(feature = "width-256")]
+mod wide_int;
+#[cfg(feature = "width-256")]
+pub use wide_int::{i256, u256};
+#[cfg(feature = "width-512")]
+pub use wide_int::{i512, u512};
+
+#[cfg(feature = "width-256")]
+mod width_256;
+#[cfg(feature = "width-256")]
+#[allow(unused_imports)]
+pub use width_256::*;
 
-pub use inliner::u128_div_asymmetric;
+#[cfg(feature = "width-512")]
+mod width_512;
+#[cfg(feature = "width-512")]
+#[allow(unused_imports)]
+pub use width_512::*;
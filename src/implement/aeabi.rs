@@ -0,0 +1,76 @@
+//! The ARM EABI 64 bit division entry points, so that bare metal ARM projects can satisfy the
+//! references that the compiler emits for `u64` and `i64` division with the algorithms of this
+//! crate. These are exported with `#[no_mangle]`, and override the weak definitions of
+//! `compiler_builtins`.
+//!
+//! `__aeabi_uldivmod` and `__aeabi_ldivmod` take the dividend in `r0:r1` and the divisor in
+//! `r2:r3`, and return the quotient in `r0:r1` and the remainder in `r2:r3`. The remainder return
+//! cannot be expressed with `extern "C"`, so these are naked functions that call a helper with the
+//! `__udivmoddi4` signature (a pointer to the remainder on the stack) and then load the remainder
+//! into `r2:r3`.
+//!
+//! Division by zero panics like the rest of this crate, which aborts because the panic cannot
+//! unwind out of an `extern "C"` function, instead of calling `__aeabi_ldiv0`.
+
+use super::{i64_div_rem_delegate, u64_div_rem_delegate};
+
+extern "C" fn u64_div_rem_aeabi_helper(duo: u64, div: u64, rem: &mut u64) -> u64 {
+    let (quo, tmp) = u64_div_rem_delegate(duo, div);
+    *rem = tmp;
+    quo
+}
+
+extern "C" fn i64_div_rem_aeabi_helper(duo: i64, div: i64, rem: &mut i64) -> i64 {
+    let (quo, tmp) = i64_div_rem_delegate(duo, div);
+    *rem = tmp;
+    quo
+}
+
+/// Computes the quotient and remainder of the `u64` in `r0:r1` divided by the `u64` in `r2:r3`,
+/// and returns the quotient in `r0:r1` and the remainder in `r2:r3`.
+///
+/// # Safety
+///
+/// This has a special calling convention and should only be called by compiler generated code.
+#[unsafe(naked)]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn __aeabi_uldivmod() {
+    core::arch::naked_asm!(
+        // `r4` is saved to keep the stack 8 byte aligned
+        "push {{r4, lr}}",
+        // 8 bytes for the remainder pointer argument and 8 bytes for the remainder
+        "sub sp, sp, #16",
+        "add r4, sp, #8",
+        "str r4, [sp]",
+        "bl {helper}",
+        "ldr r2, [sp, #8]",
+        "ldr r3, [sp, #12]",
+        "add sp, sp, #16",
+        "pop {{r4, pc}}",
+        helper = sym u64_div_rem_aeabi_helper,
+    );
+}
+
+/// Computes the quotient and remainder of the `i64` in `r0:r1` divided by the `i64` in `r2:r3`,
+/// and returns the quotient in `r0:r1` and the remainder in `r2:r3`. Like the other signed
+/// functions of this crate, `i64::MIN / -1` wraps around.
+///
+/// # Safety
+///
+/// This has a special calling convention and should only be called by compiler generated code.
+#[unsafe(naked)]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn __aeabi_ldivmod() {
+    core::arch::naked_asm!(
+        "push {{r4, lr}}",
+        "sub sp, sp, #16",
+        "add r4, sp, #8",
+        "str r4, [sp]",
+        "bl {helper}",
+        "ldr r2, [sp, #8]",
+        "ldr r3, [sp, #12]",
+        "add sp, sp, #16",
+        "pop {{r4, pc}}",
+        helper = sym i64_div_rem_aeabi_helper,
+    );
+}
@@ -0,0 +1,147 @@
+//! Barrett reduction by a fixed modulus. The modulus is normalized (shifted so that its most
+//! significant bit is set) and `mu`, its reciprocal `floor((2^(2*n) - 1) / m) - 2^n`, is
+//! precomputed, so that a double width value is reduced with one high multiplication and at most
+//! two corrections per word, as in "Improved Division by Invariant Integers" (Möller, Granlund).
+//! This is the building block of modular arithmetic with a modulus that only changes rarely.
+
+#[allow(unused_imports)]
+use super::*;
+
+macro_rules! impl_barrett {
+    (
+        $name:ident, // name of the Barrett reduction struct
+        $reciprocal:path, // `const` function computing `mu` of a normalized modulus
+        $widening_mul:path, // `const` full product of two `$uX`s as a tuple of halves, lower first
+        $n:expr, // the number of bits in a $uX
+        $uX:ident // unsigned integer type for the modulus and the reduced values
+    ) => {
+        /// A fixed modulus with its precomputed Barrett reciprocal
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name {
+            // the normalized modulus
+            m: $uX,
+            shift: u32,
+            mu: $uX,
+        }
+
+        impl $name {
+            /// Precomputes the reciprocal of the modulus `m`. This involves a division, so it is
+            /// only worth it if many values are reduced.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `m == 0`.
+            #[track_caller]
+            pub const fn new(m: $uX) -> Self {
+                if m == 0 {
+                    zero_div_fn()
+                }
+                let shift = m.leading_zeros();
+                let m = m << shift;
+                Self {
+                    m,
+                    shift,
+                    mu: $reciprocal(m),
+                }
+            }
+
+            /// Returns the modulus
+            pub const fn modulus(self) -> $uX {
+                self.m >> self.shift
+            }
+
+            /// Returns the remainder of `(hi * 2^$n + lo) / self.m`, where `hi < self.m`
+            const fn reduce_step(self, hi: $uX, lo: $uX) -> $uX {
+                // The quotient estimate is the high half of `(mu + 2^$n) * hi + (hi, lo)`, which is
+                // at most 1 too small or 1 too large after the first correction. The remainder is
+                // calculated modulo `2^$n` since it is known to be close to `m`.
+                let (mul_lo, mul_hi) = $widening_mul(self.mu, hi);
+                let (quo_lo, carry) = mul_lo.overflowing_add(lo);
+                let quo = mul_hi
+                    .wrapping_add(hi)
+                    .wrapping_add(1)
+                    .wrapping_add(carry as $uX);
+                let mut rem = lo.wrapping_sub(quo.wrapping_mul(self.m));
+                if rem > quo_lo {
+                    rem = rem.wrapping_add(self.m);
+                }
+                if rem >= self.m {
+                    rem -= self.m;
+                }
+                rem
+            }
+
+            /// Returns `(hi * 2^$n + lo) % self.modulus()`
+            const fn reduce(self, lo: $uX, hi: $uX) -> $uX {
+                // `(hi, lo)` is shifted like `m` into three words, where the most significant word
+                // is less than `2^shift` and therefore less than the normalized `m`
+                let (top, hi, lo) = if self.shift == 0 {
+                    (0, hi, lo)
+                } else {
+                    (
+                        hi >> ($n - self.shift),
+                        (hi << self.shift) | (lo >> ($n - self.shift)),
+                        lo << self.shift,
+                    )
+                };
+                let rem = self.reduce_step(top, hi);
+                self.reduce_step(rem, lo) >> self.shift
+            }
+
+            /// Returns `(a * b) % self.modulus()`. This is fastest if `a` is already reduced.
+            pub const fn mul_mod(self, a: $uX, b: $uX) -> $uX {
+                if a < self.modulus() {
+                    // `(a << shift) * b < m * 2^$n`, so the shifted product is reduced in one step
+                    let (lo, hi) = $widening_mul(a << self.shift, b);
+                    self.reduce_step(hi, lo) >> self.shift
+                } else {
+                    let (lo, hi) = $widening_mul(a, b);
+                    self.reduce(lo, hi)
+                }
+            }
+        }
+    };
+}
+
+// `floor((2^128 - 1) / m) - 2^64` is the reciprocal that the `u128` by `u64` division of this crate
+// uses on targets without a 128 by 64 bit division instruction
+#[cfg(feature = "width-64")]
+impl_barrett!(
+    Barrett64,
+    super::reciprocal::u64_reciprocal,
+    super::width_64::u64_widening_mul,
+    64,
+    u64
+);
+
+#[cfg(feature = "width-64")]
+impl Barrett64 {
+    /// Returns `x % self.modulus()`
+    pub const fn reduce_u128(self, x: u128) -> u64 {
+        self.reduce(x as u64, (x >> 64) as u64)
+    }
+}
+
+/// Returns `floor((2^256 - 1) / m) - 2^128` for a normalized `m`
+#[cfg(feature = "width-128")]
+const fn u128_reciprocal(m: u128) -> u128 {
+    // `2^256 - 1 == (2^128 + !m) * 2^128 + (2^128 - 1)`, so the reciprocal is the quotient of
+    // `!m * 2^128 + (2^128 - 1)`. The division of `!m * 2^128` is done first, and then the
+    // quotient of its remainder plus `2^128 - 1` is 1 or 2 because `m` is normalized.
+    let (quo, rem) = super::width_128::u128_shifted_div_rem(!m, m);
+    // `rem + (2^128 - 1) - m`, compared with `m`
+    let (tmp, carry) = rem.overflowing_add(!m);
+    let extra = if carry || (tmp >= m) { 2 } else { 1 };
+    quo.wrapping_add(extra)
+}
+
+#[cfg(feature = "width-128")]
+impl_barrett!(Barrett128, u128_reciprocal, u128_widening_mul, 128, u128);
+
+#[cfg(all(feature = "width-128", feature = "width-256"))]
+impl Barrett128 {
+    /// Returns `x % self.modulus()`
+    pub const fn reduce_u256(self, x: u256) -> u128 {
+        self.reduce(x.lo, x.hi)
+    }
+}
@@ -0,0 +1,533 @@
+//! Division algorithms for integers stored as slices of `u64` limbs (or `u32` limbs for the
+//! `big-digit` entry points on targets that are not 64 bit) in little endian order (the least
+//! significant limb is at index 0), along with the shift, comparison, and subtraction helpers that
+//! they are built from. The helpers work in place on the slices, without copying them.
+
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+mod burnikel_ziegler;
+#[cfg(feature = "alloc")]
+pub use burnikel_ziegler::div_rem_burnikel_ziegler;
+
+// Implements the limb slice algorithms for the limb type `$Limb`
+macro_rules! impl_limbs {
+    ($Limb:ident) => {
+        const BITS: usize = $Limb::BITS as usize;
+
+        /// Compares `lhs` and `rhs`, which can have different lengths
+        pub fn cmp(lhs: &[$Limb], rhs: &[$Limb]) -> Ordering {
+            // any extra limbs have to be zero for the shorter slice to be equal or larger
+            let mut i = lhs.len();
+            while i > rhs.len() {
+                i -= 1;
+                if lhs[i] != 0 {
+                    return Ordering::Greater;
+                }
+            }
+            let mut j = rhs.len();
+            while j > lhs.len() {
+                j -= 1;
+                if rhs[j] != 0 {
+                    return Ordering::Less;
+                }
+            }
+            loop {
+                if i == 0 {
+                    return Ordering::Equal;
+                }
+                i -= 1;
+                if lhs[i] != rhs[i] {
+                    return lhs[i].cmp(&rhs[i]);
+                }
+            }
+        }
+
+        /// Shifts `x` left by one bit, shifting in `bit` at the least significant end. Returns the
+        /// bit shifted out of the most significant end.
+        pub fn shl1(x: &mut [$Limb], bit: $Limb) -> $Limb {
+            let mut carry = bit;
+            let mut i = 0;
+            while i < x.len() {
+                let tmp = x[i];
+                x[i] = (tmp << 1) | carry;
+                carry = tmp >> (BITS - 1);
+                i += 1;
+            }
+            carry
+        }
+
+        /// Adds `rhs` to `lhs` in place, where `rhs` is no longer than `lhs` (any limbs past the
+        /// length of `lhs` are ignored). Returns the carry.
+        pub fn add_assign(lhs: &mut [$Limb], rhs: &[$Limb]) -> bool {
+            let mut carry = false;
+            let mut i = 0;
+            while i < lhs.len() {
+                let rhs_limb = if i < rhs.len() { rhs[i] } else { 0 };
+                let (tmp, o0) = lhs[i].overflowing_add(rhs_limb);
+                let (tmp, o1) = tmp.overflowing_add(carry as $Limb);
+                lhs[i] = tmp;
+                carry = o0 || o1;
+                i += 1;
+            }
+            carry
+        }
+
+        /// Subtracts `rhs` from `lhs` in place, where `rhs` is no longer than `lhs` (any limbs
+        /// past the length of `lhs` are ignored). Returns the borrow.
+        pub fn sub_assign(lhs: &mut [$Limb], rhs: &[$Limb]) -> bool {
+            let mut borrow = false;
+            let mut i = 0;
+            while i < lhs.len() {
+                let rhs_limb = if i < rhs.len() { rhs[i] } else { 0 };
+                let (tmp, o0) = lhs[i].overflowing_sub(rhs_limb);
+                let (tmp, o1) = tmp.overflowing_sub(borrow as $Limb);
+                lhs[i] = tmp;
+                borrow = o0 || o1;
+                i += 1;
+            }
+            borrow
+        }
+
+        /// Subtracts `rhs` from `lhs` in place if `lhs >= rhs`, where `rhs` can be longer than
+        /// `lhs` if its extra limbs are zero. Returns `true` if the subtraction was done.
+        pub fn sub_assign_if_ge(lhs: &mut [$Limb], rhs: &[$Limb]) -> bool {
+            if cmp(lhs, rhs) == Ordering::Less {
+                return false;
+            }
+            sub_assign(lhs, rhs);
+            true
+        }
+
+        /// Shifts `x` left by `shift` bits in place, which is how divisors are normalized. Returns
+        /// the bits shifted out of the most significant end, in the least significant bits of the
+        /// returned limb.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `shift` is not less than the number of bits in a limb.
+        pub fn shl(x: &mut [$Limb], shift: u32) -> $Limb {
+            assert!((shift as usize) < BITS);
+            if shift == 0 {
+                return 0;
+            }
+            let mut carry = 0;
+            let mut i = 0;
+            while i < x.len() {
+                let tmp = x[i];
+                x[i] = (tmp << shift) | carry;
+                carry = tmp >> ((BITS as u32) - shift);
+                i += 1;
+            }
+            carry
+        }
+
+        /// Shifts `x` right by `shift` bits in place, which is how remainders are denormalized.
+        /// Returns the bits shifted out of the least significant end, in the most significant bits
+        /// of the returned limb.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `shift` is not less than the number of bits in a limb.
+        pub fn shr(x: &mut [$Limb], shift: u32) -> $Limb {
+            assert!((shift as usize) < BITS);
+            if shift == 0 {
+                return 0;
+            }
+            let mut carry = 0;
+            let mut i = x.len();
+            while i > 0 {
+                i -= 1;
+                let tmp = x[i];
+                x[i] = (tmp >> shift) | carry;
+                carry = tmp << ((BITS as u32) - shift);
+            }
+            carry
+        }
+
+        /// Returns the number of significant bits in `x`
+        pub fn bit_len(x: &[$Limb]) -> usize {
+            let mut i = x.len();
+            loop {
+                if i == 0 {
+                    return 0;
+                }
+                i -= 1;
+                if x[i] != 0 {
+                    return (i * BITS) + (BITS - (x[i].leading_zeros() as usize));
+                }
+            }
+        }
+
+        /// Computes the quotient and remainder of `duo` divided by `div` with binary long
+        /// division, and writes them to `quo` and `rem`. This has no requirements on the
+        /// normalization or lengths of `duo` and `div`, so it is a simple fallback for the faster
+        /// algorithms.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div` is zero, if `quo` is shorter than `duo`, or if `rem` is shorter than
+        /// `div`.
+        #[track_caller]
+        // only `div_rem_big_digits` uses this, for the `u32` limbs of targets that are not 64 bit
+        #[allow(dead_code)]
+        pub(crate) fn div_rem_binary_long(
+            duo: &[$Limb],
+            div: &[$Limb],
+            quo: &mut [$Limb],
+            rem: &mut [$Limb],
+        ) {
+            if bit_len(div) == 0 {
+                crate::implement::zero_div_fn()
+            }
+            assert!(quo.len() >= duo.len());
+            assert!(rem.len() >= div.len());
+            let mut i = 0;
+            while i < quo.len() {
+                quo[i] = 0;
+                i += 1;
+            }
+            let mut i = 0;
+            while i < rem.len() {
+                rem[i] = 0;
+                i += 1;
+            }
+            // Starting from the most significant set bit of `duo` skips the leading zero steps
+            let mut bit = bit_len(duo);
+            loop {
+                if bit == 0 {
+                    return;
+                }
+                bit -= 1;
+                let carry = shl1(rem, (duo[bit / BITS] >> (bit % BITS)) & 1);
+                // If a bit was shifted out of `rem`, it is definitely larger than `div`, and the
+                // wrapping subtraction below produces the correct result.
+                if (carry != 0) || (cmp(rem, div) != Ordering::Less) {
+                    sub_assign(rem, div);
+                    quo[bit / BITS] |= 1 << (bit % BITS);
+                }
+            }
+        }
+    };
+}
+
+impl_limbs!(u64);
+
+// only the division is used by `div_rem_big_digits`
+#[cfg(all(feature = "big-digit", not(target_pointer_width = "64")))]
+#[allow(dead_code)]
+mod u32_limbs {
+    use super::*;
+
+    impl_limbs!(u32);
+}
+
+/// The type of the limbs of `num_bigint::BigUint` (its `BigDigit`), which is `u64` on 64 bit
+/// targets and `u32` on other targets
+#[cfg(all(feature = "big-digit", target_pointer_width = "64"))]
+pub type BigDigit = u64;
+/// The type of the limbs of `num_bigint::BigUint` (its `BigDigit`), which is `u64` on 64 bit
+/// targets and `u32` on other targets
+#[cfg(all(feature = "big-digit", not(target_pointer_width = "64")))]
+pub type BigDigit = u32;
+
+/// Computes the quotient and remainder of `duo` divided by `div`, which are in the little endian
+/// `BigDigit` layout of `num_bigint::BigUint` (e.g. from `BigUint::to_u64_digits` on 64 bit
+/// targets), and writes them to `quo` and `rem`. Neither `duo` nor `div` need to be normalized or
+/// stripped of leading zero limbs, so this can serve as the core loop of `BigUint` division. On 64
+/// bit targets, this uses `div_rem_limbs` on a copy of `duo` that is extended by a zero limb.
+///
+/// # Panics
+///
+/// Panics if `div` is zero, if `quo` is shorter than `duo`, or if `rem` is shorter than `div`.
+#[cfg(feature = "big-digit")]
+#[track_caller]
+pub fn div_rem_big_digits(
+    duo: &[BigDigit],
+    div: &[BigDigit],
+    quo: &mut [BigDigit],
+    rem: &mut [BigDigit],
+) {
+    #[cfg(target_pointer_width = "64")]
+    {
+        // the number of significant limbs in `duo` and `div`
+        let m = bit_len(duo).div_ceil(64);
+        let n = bit_len(div).div_ceil(64);
+        if n == 0 {
+            super::zero_div_fn()
+        }
+        assert!(quo.len() >= duo.len());
+        assert!(rem.len() >= div.len());
+        quo.fill(0);
+        rem.fill(0);
+        if m < n {
+            // `duo < div`
+            rem[..m].copy_from_slice(&duo[..m]);
+            return;
+        }
+        // the zero limb on top makes the quotient fit in `m + 1 - n` limbs
+        let mut tmp = alloc::vec::Vec::with_capacity(m + 1);
+        tmp.extend_from_slice(&duo[..m]);
+        tmp.push(0);
+        div_rem_limbs(&mut tmp, div);
+        rem[..n].copy_from_slice(&tmp[..n]);
+        quo[..(m + 1 - n)].copy_from_slice(&tmp[n..]);
+    }
+    #[cfg(not(target_pointer_width = "64"))]
+    u32_limbs::div_rem_binary_long(duo, div, quo, rem);
+}
+
+/// Divides `duo` by the single limb `div` with short division, passing each limb of the quotient
+/// to `emit` as soon as it is produced (starting from the most significant limb, including any
+/// leading zero limbs), and returns the remainder. This uses a fixed amount of memory regardless of
+/// the length of `duo`, so huge values can be converted or printed progressively without
+/// materializing the quotient.
+///
+/// # Panics
+///
+/// Panics if `div` is zero.
+#[track_caller]
+pub fn div_rem_limb_streaming<F: FnMut(u64)>(duo: &[u64], div: u64, mut emit: F) -> u64 {
+    if div == 0 {
+        super::zero_div_fn()
+    }
+    let mut rem: u64 = 0;
+    let mut i = duo.len();
+    while i > 0 {
+        i -= 1;
+        // `rem < div`, so each quotient limb fits in a `u64`
+        let (quo, tmp) = crate::unchecked_call!(super::u128_by_u64_div_rem_unchecked(
+            ((rem as u128) << 64) | (duo[i] as u128),
+            div
+        ));
+        rem = tmp;
+        emit(quo);
+    }
+    rem
+}
+
+/// Divides `limbs` by the single limb `div` in place with short division, starting from the most
+/// significant limb, and returns the remainder. This is the hot loop of printing and base
+/// conversion, which repeatedly divide by the largest power of the radix that fits in a limb.
+///
+/// # Panics
+///
+/// Panics if `div` is zero.
+#[track_caller]
+pub fn div_rem_limbs_by_u64(limbs: &mut [u64], div: u64) -> u64 {
+    if div == 0 {
+        super::zero_div_fn()
+    }
+    let mut rem: u64 = 0;
+    let mut i = limbs.len();
+    while i > 0 {
+        i -= 1;
+        // `rem < div`, so each quotient limb fits in a `u64`
+        let (quo, tmp) = crate::unchecked_call!(super::u128_by_u64_div_rem_unchecked(
+            ((rem as u128) << 64) | (limbs[i] as u128),
+            div
+        ));
+        limbs[i] = quo;
+        rem = tmp;
+    }
+    rem
+}
+
+/// Divides `duo` by `div` in place with Knuth's Algorithm D (from "The Art of Computer
+/// Programming" volume 2, section 4.3.1), using the 128 by 64 bit division of this crate to
+/// estimate each limb of the quotient. Returns the number `n` of significant limbs in `div` (its
+/// length without leading zero limbs). Afterwards, the lowest `n` limbs of `duo` are the remainder
+/// and the remaining `duo.len() - n` limbs are the quotient. No memory is allocated and `div` is
+/// not modified, so this can serve as the core loop of the division of a bignum library.
+///
+/// The quotient has to fit in `duo.len() - n` limbs, which means that the most significant `n`
+/// limbs of `duo` have to be less than `div`. This is always the case when the most significant
+/// limb of `duo` is zero, so the dividend can be extended by a zero limb to divide it by anything.
+///
+/// # Panics
+///
+/// Panics if `div` is zero or if the quotient does not fit (including when `duo` has less than `n`
+/// limbs).
+#[track_caller]
+pub fn div_rem_limbs(duo: &mut [u64], div: &[u64]) -> usize {
+    let n = bit_len(div).div_ceil(64);
+    if n == 0 {
+        super::zero_div_fn()
+    }
+    // the most significant `n` limbs of `duo` have to be less than `div`
+    let fits = if duo.len() < n {
+        false
+    } else {
+        let mut i = n;
+        loop {
+            if i == 0 {
+                break false;
+            }
+            i -= 1;
+            let duo_limb = duo[duo.len() - n + i];
+            if duo_limb != div[i] {
+                break duo_limb < div[i];
+            }
+        }
+    };
+    if !fits {
+        panic!("quotient does not fit in `duo.len() - n` limbs")
+    }
+    // the number of limbs in the quotient
+    let m = duo.len() - n;
+    if m == 0 {
+        // `duo < div`, so `duo` is already the remainder
+        return n;
+    }
+
+    if n == 1 {
+        // short division, with each quotient limb stored in the limb above the one it came from
+        let div = div[0];
+        let mut rem = duo[m];
+        let mut i = m;
+        while i > 0 {
+            i -= 1;
+            // `rem < div`, so each quotient limb fits in a `u64`
+            let (quo, tmp) = crate::unchecked_call!(super::u128_by_u64_div_rem_unchecked(
+                ((rem as u128) << 64) | (duo[i] as u128),
+                div
+            ));
+            duo[i + 1] = quo;
+            rem = tmp;
+        }
+        duo[0] = rem;
+        return n;
+    }
+
+    // `div` is normalized on the fly so that it does not have to be copied. Because the most
+    // significant `n` limbs of `duo` are less than `div`, normalizing `duo` in place cannot shift
+    // out any set bits.
+    let shift = div[n - 1].leading_zeros();
+    let div_limb = |i: usize| -> u64 {
+        if (shift == 0) || (i == 0) {
+            div[i] << shift
+        } else {
+            (div[i] << shift) | (div[i - 1] >> (64 - shift))
+        }
+    };
+    let carry = shl(duo, shift);
+    debug_assert_eq!(carry, 0);
+    let div_1 = div_limb(n - 1);
+    let div_0 = div_limb(n - 2);
+
+    // Each step divides the `n + 1` limbs of `duo` starting at `j` by `div`. The quotient limb is
+    // less than `2^64` because the upper `n` of those limbs are the remainder of the last step (or
+    // the part of `duo` that was checked to be less than `div`), and afterwards the remainder fits
+    // in the lower `n` limbs, so the quotient limb can be stored in the limb that was freed.
+    let mut j = m;
+    while j > 0 {
+        j -= 1;
+        let duo_2 = duo[j + n];
+        let duo_1 = duo[j + n - 1];
+        let duo_0 = duo[j + n - 2];
+        // Estimates the quotient limb from the upper two limbs of the window and the upper limb of
+        // `div`, which is at most 2 too large because `div` is normalized. `duo_2 <= div_1`
+        // because the upper limbs of the window are less than `div`.
+        let (mut quo, mut rem_hat) = if duo_2 < div_1 {
+            let (quo, rem) = crate::unchecked_call!(super::u128_by_u64_div_rem_unchecked(
+                ((duo_2 as u128) << 64) | (duo_1 as u128),
+                div_1
+            ));
+            (quo, rem as u128)
+        } else {
+            // the estimate would be `2^64`, which does not fit
+            (u64::MAX, (duo_1 as u128) + (div_1 as u128))
+        };
+        // corrects the estimate with the second limb of `div`, after which it is at most 1 too
+        // large
+        while ((rem_hat >> 64) == 0)
+            && ((quo as u128) * (div_0 as u128) > ((rem_hat << 64) | (duo_0 as u128)))
+        {
+            quo -= 1;
+            rem_hat += div_1 as u128;
+        }
+
+        // subtracts `quo * div` from the window
+        let mut mul_carry: u64 = 0;
+        let mut borrow = false;
+        let mut i = 0;
+        while i < n {
+            let tmp = (quo as u128) * (div_limb(i) as u128) + (mul_carry as u128);
+            mul_carry = (tmp >> 64) as u64;
+            let (dif, o0) = duo[j + i].overflowing_sub(tmp as u64);
+            let (dif, o1) = dif.overflowing_sub(borrow as u64);
+            duo[j + i] = dif;
+            borrow = o0 || o1;
+            i += 1;
+        }
+        let (dif, o0) = duo[j + n].overflowing_sub(mul_carry);
+        let (dif, o1) = dif.overflowing_sub(borrow as u64);
+        duo[j + n] = dif;
+        if o0 || o1 {
+            // The estimate was 1 too large, which is rare (about `2 / 2^64` of the time), so `div`
+            // is added back
+            quo -= 1;
+            let mut carry = false;
+            let mut i = 0;
+            while i < n {
+                let (sum, o0) = duo[j + i].overflowing_add(div_limb(i));
+                let (sum, o1) = sum.overflowing_add(carry as u64);
+                duo[j + i] = sum;
+                carry = o0 || o1;
+                i += 1;
+            }
+            // the carry out of the window cancels the borrow
+        }
+        duo[j + n] = quo;
+    }
+
+    // denormalizes the remainder
+    if shift != 0 {
+        let mut carry = 0;
+        let mut i = n;
+        while i > 0 {
+            i -= 1;
+            let tmp = duo[i];
+            duo[i] = (tmp >> shift) | carry;
+            carry = tmp << (64 - shift);
+        }
+    }
+    n
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` with binary long division like
+/// `div_rem_binary_long`, but instead of writing the quotient to a slice, each limb of it is passed
+/// to `emit` as soon as it is complete (starting from the most significant limb, including any
+/// leading zero limbs). The remainder is written to `rem`.
+///
+/// # Panics
+///
+/// Panics if `div` is zero or if `rem` is shorter than `div`.
+#[track_caller]
+pub fn div_rem_streaming<F: FnMut(u64)>(duo: &[u64], div: &[u64], rem: &mut [u64], mut emit: F) {
+    if bit_len(div) == 0 {
+        super::zero_div_fn()
+    }
+    assert!(rem.len() >= div.len());
+    let mut i = 0;
+    while i < rem.len() {
+        rem[i] = 0;
+        i += 1;
+    }
+    let mut i = duo.len();
+    while i > 0 {
+        i -= 1;
+        let mut quo: u64 = 0;
+        let mut bit = 64;
+        while bit > 0 {
+            bit -= 1;
+            let carry = shl1(rem, (duo[i] >> bit) & 1);
+            // the same restoring step as in `div_rem_binary_long`
+            if (carry != 0) || (cmp(rem, div) != Ordering::Less) {
+                sub_assign(rem, div);
+                quo |= 1 << bit;
+            }
+        }
+        emit(quo);
+    }
+}
@@ -0,0 +1,238 @@
+//! Recursive division from "Fast Recursive Division" by Christoph Burnikel and Joachim Ziegler.
+//! A `2n` by `n` limb division is split into two `3n/2` by `n` limb divisions, which estimate their
+//! quotients with a `n` by `n/2` limb division of the upper limbs, and the recursion bottoms out in
+//! `div_rem_limbs` once the divisor is short enough. Together with Karatsuba multiplication for the
+//! corrections, this beats the quadratic schoolbook division for divisors of more than a few
+//! thousand bits.
+
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use super::{add_assign, bit_len, cmp, div_rem_limbs, shl, shr, sub_assign};
+
+/// Divisors with at most this many limbs are divided by `div_rem_limbs` instead of recursing
+const DIV_THRESHOLD: usize = 32;
+
+/// Factors with less than this many limbs are multiplied with schoolbook multiplication instead of
+/// Karatsuba multiplication
+const MUL_THRESHOLD: usize = 24;
+
+/// Sets all of the limbs of `x` to zero
+fn zero(x: &mut [u64]) {
+    let mut i = 0;
+    while i < x.len() {
+        x[i] = 0;
+        i += 1;
+    }
+}
+
+/// Writes the `x.len() + y.len()` limb product of `x` and `y` to `res`
+fn mul_schoolbook(res: &mut [u64], x: &[u64], y: &[u64]) {
+    zero(res);
+    let mut i = 0;
+    while i < x.len() {
+        let mut carry: u64 = 0;
+        let mut j = 0;
+        while j < y.len() {
+            // `(2^64 - 1)^2 + 2*(2^64 - 1) == 2^128 - 1`, so this cannot overflow
+            let tmp = (x[i] as u128) * (y[j] as u128) + (res[i + j] as u128) + (carry as u128);
+            res[i + j] = tmp as u64;
+            carry = (tmp >> 64) as u64;
+            j += 1;
+        }
+        res[i + y.len()] = carry;
+        i += 1;
+    }
+}
+
+/// Writes `|x - y|` to `res` and returns if `x < y`, where all three have the same length
+fn abs_sub(res: &mut [u64], x: &[u64], y: &[u64]) -> bool {
+    let neg = cmp(x, y) == Ordering::Less;
+    let (big, small) = if neg { (y, x) } else { (x, y) };
+    res.copy_from_slice(big);
+    sub_assign(res, small);
+    neg
+}
+
+/// Writes the `2 * x.len()` limb product of `x` and `y` to `res`, where `x` and `y` have the same
+/// length
+fn mul(res: &mut [u64], x: &[u64], y: &[u64]) {
+    let l = x.len();
+    if (l < MUL_THRESHOLD) || ((l % 2) == 1) {
+        mul_schoolbook(res, x, y);
+        return;
+    }
+    let h = l / 2;
+    let (x0, x1) = x.split_at(h);
+    let (y0, y1) = y.split_at(h);
+    let (z0, z2) = res.split_at_mut(l);
+    mul(z0, x0, y0);
+    mul(z2, x1, y1);
+
+    // Subtractive Karatsuba, `x0*y1 + x1*y0 == z0 + z2 - (x1 - x0)*(y1 - y0)`, which avoids the
+    // extra carry limbs of the sums in the additive variant
+    let mut mid = vec![0u64; l + 1];
+    mid.split_at_mut(l).0.copy_from_slice(z0);
+    add_assign(&mut mid, z2);
+    let mut dx = vec![0u64; h];
+    let mut dy = vec![0u64; h];
+    let neg = abs_sub(&mut dx, x1, x0) != abs_sub(&mut dy, y1, y0);
+    let mut prod = vec![0u64; l];
+    mul(&mut prod, &dx, &dy);
+    if neg {
+        add_assign(&mut mid, &prod);
+    } else {
+        sub_assign(&mut mid, &prod);
+    }
+    // the middle product is less than `2^(64*l)`, so this cannot carry out of `res`
+    add_assign(res.split_at_mut(h).1, &mid);
+}
+
+/// Divides the `2n` limbs of `a` by the `n` limbs of the normalized `b`, where the upper `n` limbs
+/// of `a` are less than `b`. The quotient is written to `q`, the remainder is left in the lower `n`
+/// limbs of `a`, and the upper `n` limbs of `a` are set to zero. `scratch` needs `n` limbs.
+fn div_2n_by_n(a: &mut [u64], b: &[u64], q: &mut [u64], scratch: &mut [u64]) {
+    let n = b.len();
+    if (n <= DIV_THRESHOLD) || ((n % 2) == 1) {
+        div_rem_limbs(a, b);
+        let (_, a_hi) = a.split_at_mut(n);
+        q.copy_from_slice(a_hi);
+        zero(a_hi);
+        return;
+    }
+    let h = n / 2;
+    let (q_lo, q_hi) = q.split_at_mut(h);
+    // divides the upper `3h` limbs, whose remainder becomes the upper `2h` limbs of the lower `3h`
+    div_3h_by_2h(a.split_at_mut(h).1.split_at_mut(3 * h).0, b, q_hi, scratch);
+    div_3h_by_2h(a.split_at_mut(3 * h).0, b, q_lo, scratch);
+}
+
+/// Divides the `3h` limbs of `a` by the `2h` limbs of the normalized `b`, where the upper `2h` limbs
+/// of `a` are less than `b`. The quotient is written to `q`, the remainder is left in the lower
+/// `2h` limbs of `a`, and the upper `h` limbs of `a` are set to zero. `scratch` needs `2h` limbs.
+fn div_3h_by_2h(a: &mut [u64], b: &[u64], q: &mut [u64], scratch: &mut [u64]) {
+    let h = q.len();
+    let (b0, b1) = b.split_at(h);
+    {
+        // Estimates the quotient by dividing the upper `2h` limbs of `a` by the upper `h` limbs of
+        // `b`, leaving the remainder of that division in the upper `2h` limbs of `a`. Like the
+        // estimates of `div_rem_limbs`, this is at most 2 too large because `b` is normalized.
+        let (_, a12) = a.split_at_mut(h);
+        if cmp(a12.split_at(h).1, b1) == Ordering::Less {
+            div_2n_by_n(a12, b1, q, scratch);
+        } else {
+            // The upper `h` limbs of `a` equal `b1`, for which the estimate would be `2^(64*h)`
+            // and does not fit. `2^(64*h) - 1` is used instead, with the remainder
+            // `a12 - (2^(64*h) - 1)*b1 == a12 - b1*2^(64*h) + b1`.
+            let mut i = 0;
+            while i < h {
+                q[i] = u64::MAX;
+                i += 1;
+            }
+            sub_assign(a12.split_at_mut(h).1, b1);
+            add_assign(a12, b1);
+        }
+    }
+    // subtracts the rest of `q * b`, adding back `b` while the remainder is negative
+    let (d, _) = scratch.split_at_mut(2 * h);
+    mul(d, q, b0);
+    let mut negative = sub_assign(a, d);
+    while negative {
+        sub_assign(q, &[1]);
+        negative = !add_assign(a, b);
+    }
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` with Burnikel-Ziegler recursive
+/// division, and writes them to `quo` and `rem`. Neither `duo` nor `div` need to be normalized or
+/// stripped of leading zero limbs. Short divisors are divided directly by `div_rem_limbs`, so this
+/// is only faster than it for divisors of more than a few thousand bits, but it can be used for any
+/// operands. Scratch space proportional to the length of `duo` is allocated.
+///
+/// # Panics
+///
+/// Panics if `div` is zero, if `quo` is shorter than `duo`, or if `rem` is shorter than `div`.
+#[track_caller]
+pub fn div_rem_burnikel_ziegler(duo: &[u64], div: &[u64], quo: &mut [u64], rem: &mut [u64]) {
+    let n0 = bit_len(div).div_ceil(64);
+    if n0 == 0 {
+        crate::implement::zero_div_fn()
+    }
+    assert!(quo.len() >= duo.len());
+    assert!(rem.len() >= div.len());
+    zero(quo);
+    zero(rem);
+    let duo_bits = bit_len(duo);
+    let duo_len = duo_bits.div_ceil(64);
+    let (duo, _) = duo.split_at(duo_len);
+    let (div, _) = div.split_at(n0);
+    if duo_len < n0 {
+        rem.split_at_mut(duo_len).0.copy_from_slice(duo);
+        return;
+    }
+
+    if n0 <= DIV_THRESHOLD {
+        // the extra zero limb makes the quotient fit
+        let mut tmp = Vec::with_capacity(duo_len + 1);
+        tmp.extend_from_slice(duo);
+        tmp.push(0);
+        div_rem_limbs(&mut tmp, div);
+        let (r, q) = tmp.split_at(n0);
+        rem.split_at_mut(n0).0.copy_from_slice(r);
+        quo.split_at_mut(q.len()).0.copy_from_slice(q);
+        return;
+    }
+
+    // The divisor is padded with zero limbs at the least significant end to `n = m*2^k` limbs with
+    // `m <= DIV_THRESHOLD`, so that it can be halved `k` times in the recursion, and normalized.
+    let mut m = n0;
+    let mut k = 0;
+    while m > DIV_THRESHOLD {
+        m = m.div_ceil(2);
+        k += 1;
+    }
+    let n = m << k;
+    let limb_shift = n - n0;
+    let shift = div[n0 - 1].leading_zeros();
+    let mut b = vec![0u64; n];
+    b.split_at_mut(limb_shift).1.copy_from_slice(div);
+    shl(&mut b, shift);
+
+    // `duo` is shifted the same way into `t` blocks of `n` limbs, with the most significant bit
+    // left clear so that the most significant block is less than `b`
+    let a_bits = duo_bits + (limb_shift * 64) + (shift as usize);
+    let t = (a_bits + 1).div_ceil(n * 64).max(2);
+    let mut a = vec![0u64; t * n];
+    a.split_at_mut(limb_shift)
+        .1
+        .split_at_mut(duo_len)
+        .0
+        .copy_from_slice(duo);
+    shl(&mut a, shift);
+
+    // Divides each window of two blocks from the most significant end, where the upper block is
+    // the remainder of the previous window. The quotient is unaffected by the normalization.
+    let mut q = vec![0u64; (t - 1) * n];
+    let mut scratch = vec![0u64; n];
+    let mut i = t - 1;
+    while i > 0 {
+        i -= 1;
+        let window = a.split_at_mut(i * n).1.split_at_mut(2 * n).0;
+        let q_i = q.split_at_mut(i * n).1.split_at_mut(n).0;
+        div_2n_by_n(window, &b, q_i, &mut scratch);
+    }
+
+    // denormalizes the remainder, whose lower `limb_shift` limbs are zero
+    let (r, _) = a.split_at_mut(n);
+    shr(r, shift);
+    rem.split_at_mut(n0)
+        .0
+        .copy_from_slice(r.split_at(limb_shift).1);
+    // the quotient is no larger than `duo`, so any limbs past `quo` are zero
+    let len = if q.len() < quo.len() {
+        q.len()
+    } else {
+        quo.len()
+    };
+    quo.split_at_mut(len).0.copy_from_slice(q.split_at(len).0);
+}
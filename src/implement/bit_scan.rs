@@ -0,0 +1,161 @@
+#[cfg(feature = "width-8")]
+impl_bit_scan!(
+    u8_leading_zeros,
+    u8_trailing_zeros,
+    u8_leading_ones,
+    u8_trailing_ones,
+    8,
+    u8,
+);
+#[cfg(feature = "width-16")]
+impl_bit_scan!(
+    u16_leading_zeros,
+    u16_trailing_zeros,
+    u16_leading_ones,
+    u16_trailing_ones,
+    16,
+    u16,
+);
+#[cfg(feature = "width-32")]
+impl_bit_scan!(
+    u32_leading_zeros,
+    u32_trailing_zeros,
+    u32_leading_ones,
+    u32_trailing_ones,
+    32,
+    u32,
+);
+#[cfg(feature = "width-64")]
+impl_bit_scan!(
+    u64_leading_zeros,
+    u64_trailing_zeros,
+    u64_leading_ones,
+    u64_trailing_ones,
+    64,
+    u64,
+);
+#[cfg(feature = "width-128")]
+impl_bit_scan!(
+    u128_leading_zeros,
+    u128_trailing_zeros,
+    u128_leading_ones,
+    u128_trailing_ones,
+    128,
+    u128,
+);
+
+#[cfg(target_pointer_width = "16")]
+impl_bit_scan!(
+    usize_leading_zeros,
+    usize_trailing_zeros,
+    usize_leading_ones,
+    usize_trailing_ones,
+    16,
+    usize,
+);
+#[cfg(target_pointer_width = "32")]
+impl_bit_scan!(
+    usize_leading_zeros,
+    usize_trailing_zeros,
+    usize_leading_ones,
+    usize_trailing_ones,
+    32,
+    usize,
+);
+#[cfg(target_pointer_width = "64")]
+impl_bit_scan!(
+    usize_leading_zeros,
+    usize_trailing_zeros,
+    usize_leading_ones,
+    usize_trailing_ones,
+    64,
+    usize,
+);
+
+#[cfg(feature = "width-8")]
+impl_bit_util!(
+    u8_is_power_of_two,
+    u8_next_power_of_two,
+    u8_checked_next_power_of_two,
+    u8_bit_width,
+    u8_leading_zeros,
+    8,
+    u8,
+);
+
+#[cfg(feature = "width-16")]
+impl_bit_util!(
+    u16_is_power_of_two,
+    u16_next_power_of_two,
+    u16_checked_next_power_of_two,
+    u16_bit_width,
+    u16_leading_zeros,
+    16,
+    u16,
+);
+
+#[cfg(feature = "width-32")]
+impl_bit_util!(
+    u32_is_power_of_two,
+    u32_next_power_of_two,
+    u32_checked_next_power_of_two,
+    u32_bit_width,
+    u32_leading_zeros,
+    32,
+    u32,
+);
+
+#[cfg(feature = "width-64")]
+impl_bit_util!(
+    u64_is_power_of_two,
+    u64_next_power_of_two,
+    u64_checked_next_power_of_two,
+    u64_bit_width,
+    u64_leading_zeros,
+    64,
+    u64,
+);
+
+#[cfg(feature = "width-128")]
+impl_bit_util!(
+    u128_is_power_of_two,
+    u128_next_power_of_two,
+    u128_checked_next_power_of_two,
+    u128_bit_width,
+    u128_leading_zeros,
+    128,
+    u128,
+);
+
+#[cfg(target_pointer_width = "16")]
+impl_bit_util!(
+    usize_is_power_of_two,
+    usize_next_power_of_two,
+    usize_checked_next_power_of_two,
+    usize_bit_width,
+    usize_leading_zeros,
+    16,
+    usize,
+);
+
+#[cfg(target_pointer_width = "32")]
+impl_bit_util!(
+    usize_is_power_of_two,
+    usize_next_power_of_two,
+    usize_checked_next_power_of_two,
+    usize_bit_width,
+    usize_leading_zeros,
+    32,
+    usize,
+);
+
+#[cfg(target_pointer_width = "64")]
+impl_bit_util!(
+    usize_is_power_of_two,
+    usize_next_power_of_two,
+    usize_checked_next_power_of_two,
+    usize_bit_width,
+    usize_leading_zeros,
+    64,
+    usize,
+);
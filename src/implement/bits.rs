@@ -0,0 +1,54 @@
+//! Division of integers with widths that are not powers of two (such as the 48 and 96 bit fields
+//! of DSP and network protocol code), which are stored in a `u128` and divided with the
+//! recommended function of the smallest primitive width that they fit in, or with at most two
+//! `u128` by `u64` division steps for widths of up to 96 bits.
+
+use super::target_defaults::{u128_div_rem, u32_div_rem, u64_div_rem};
+
+/// Computes the quotient and remainder of the lower `BITS` bits of `duo` divided by the lower
+/// `BITS` bits of `div`, and returns them as a tuple. The upper bits of both are ignored. Because
+/// `BITS` is a constant, the width of the division is chosen at compile time, so that for example
+/// `div_rem_bits::<48>` uses `target_defaults::u64_div_rem` and does not pay for a 128 bit
+/// division. For `BITS` in `65..=96`, a divisor that fits in a `u64` takes at most two steps of
+/// `u128_by_u64_div_rem` (a single instruction on x86_64 and s390x with the `asm` feature)
+/// instead of a full `u128` division. `BITS` has to be in `1..=128`, which is checked at compile
+/// time.
+///
+/// # Panics
+///
+/// Panics if the lower `BITS` bits of `div` are zero.
+#[track_caller]
+pub fn div_rem_bits<const BITS: u32>(duo: u128, div: u128) -> (u128, u128) {
+    const { assert!(0 < BITS && BITS <= 128) };
+    let mask = u128::MAX >> (128 - BITS);
+    let duo = duo & mask;
+    let div = div & mask;
+    if BITS <= 32 {
+        let (quo, rem) = u32_div_rem(duo as u32, div as u32);
+        (quo as u128, rem as u128)
+    } else if BITS <= 64 {
+        let (quo, rem) = u64_div_rem(duo as u64, div as u64);
+        (quo as u128, rem as u128)
+    } else if BITS <= 96 && div != 0 && (div >> 64) == 0 {
+        let div = div as u64;
+        let duo_hi = (duo >> 64) as u64;
+        if duo_hi < div {
+            let (quo, rem) = crate::unchecked_call!(super::u128_by_u64_div_rem_unchecked(duo, div));
+            (quo as u128, rem as u128)
+        } else {
+            // `duo_hi` has at most 32 bits, so `div < 2^32` here and the upper part of the
+            // quotient is a `u64` division. `rem_hi < div` makes the second step fit in a `u64`.
+            let (quo_hi, rem_hi) = u64_div_rem(duo_hi, div);
+            let (quo_lo, rem) = crate::unchecked_call!(super::u128_by_u64_div_rem_unchecked(
+                ((rem_hi as u128) << 64) | (duo as u64 as u128),
+                div
+            ));
+            (((quo_hi as u128) << 64) | (quo_lo as u128), rem as u128)
+        }
+    } else {
+        // zero divisors also end up here to panic at the caller. The `u128` algorithms already
+        // branch on the sizes of the operands, so the zeroed upper bits lead them to their cheaper
+        // cases
+        u128_div_rem(duo, div)
+    }
+}
@@ -0,0 +1,100 @@
+//! Lazy decimal digits of a `u128`
+
+use super::*;
+
+/// `10^i` for every digit position `i` of a chunk
+const POW10: [u64; 19] = [
+    1,
+    10,
+    100,
+    1_000,
+    10_000,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+    1_000_000_000,
+    10_000_000_000,
+    100_000_000_000,
+    1_000_000_000_000,
+    10_000_000_000_000,
+    100_000_000_000_000,
+    1_000_000_000_000_000,
+    10_000_000_000_000_000,
+    100_000_000_000_000_000,
+    1_000_000_000_000_000_000,
+];
+
+/// The largest power of ten that fits in a `u64`
+const CHUNK: u128 = 10_000_000_000_000_000_000;
+
+/// An iterator over the decimal digits (as values from 0 to 9) of a `u128`. Iterating forwards
+/// yields the most significant digit first, and iterating backwards with `next_back` or `rev`
+/// yields the least significant digit first. No digit buffer is needed: the `u128` is split into
+/// three chunks of 19 digits with two divisions up front, and each digit is extracted from its
+/// chunk when it is yielded.
+#[derive(Debug, Clone)]
+pub struct U128Digits {
+    chunks: [u64; 3],
+    // digits are indexed from the least significant digit, and the digits in `back..front` have
+    // not been yielded yet
+    back: u32,
+    front: u32,
+}
+
+impl U128Digits {
+    /// Creates an iterator over the decimal digits of `x`. Zero has the single digit 0.
+    pub fn new(x: u128) -> Self {
+        let (hi, lo) = u128_div_rem_trifecta(x, CHUNK);
+        let (hi, mid) = u128_div_rem_trifecta(hi, CHUNK);
+        let chunks = [lo as u64, mid as u64, hi as u64];
+        // count the digits starting from the most significant nonzero chunk
+        let mut i = 2;
+        while i > 0 && chunks[i] == 0 {
+            i -= 1;
+        }
+        let mut len = 1;
+        while len < 19 && chunks[i] >= POW10[len] {
+            len += 1;
+        }
+        Self {
+            chunks,
+            back: 0,
+            front: (i as u32) * 19 + (len as u32),
+        }
+    }
+
+    fn digit(&self, i: u32) -> u8 {
+        let chunk = self.chunks[(i / 19) as usize];
+        ((chunk / POW10[(i % 19) as usize]) % 10) as u8
+    }
+}
+
+impl Iterator for U128Digits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.back == self.front {
+            return None;
+        }
+        self.front -= 1;
+        Some(self.digit(self.front))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.front - self.back) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for U128Digits {
+    fn next_back(&mut self) -> Option<u8> {
+        if self.back == self.front {
+            return None;
+        }
+        self.back += 1;
+        Some(self.digit(self.back - 1))
+    }
+}
+
+impl ExactSizeIterator for U128Digits {}
@@ -0,0 +1,50 @@
+//! Breaking durations down into days, hours, minutes, seconds, and nanoseconds with chains of
+//! divisions by constants
+
+use super::*;
+
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+const SECS_PER_DAY: u64 = 86_400;
+
+// The magic numbers for every divisor are computed at compile time
+const NANOS_PER_SEC_MAGIC: U64MagicTable<1> = U64MagicTable::new(NANOS_PER_SEC);
+const SECS_PER_DAY_MAGIC: U64MagicTable<1> = U64MagicTable::new(SECS_PER_DAY);
+const SECS_PER_HOUR_MAGIC: U64MagicTable<1> = U64MagicTable::new(3600);
+const SECS_PER_MIN_MAGIC: U64MagicTable<1> = U64MagicTable::new(60);
+
+/// Divides a `u128` by a divisor below `2^32` as a short division of 32 bit digits. Every step
+/// divides a `u64` whose upper half is the previous remainder, so the quotients of the steps fit in
+/// 32 bits and the magic numbers of `table` are all that is needed.
+fn u128_div_rem_magic(duo: u128, div: u64, table: &U64MagicTable<1>) -> (u128, u64) {
+    let mut quo = 0;
+    let mut rem = 0;
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        let tmp = (rem << 32) | (((duo >> (i * 32)) as u32) as u64);
+        let digit = table.div_by_table(tmp, div);
+        rem = tmp - (digit * div);
+        quo |= (digit as u128) << (i * 32);
+    }
+    (quo, rem)
+}
+
+/// Breaks `secs` seconds down into `(days, hours, mins, secs)`
+pub fn u64_breakdown_secs(secs: u64) -> (u64, u8, u8, u8) {
+    let days = SECS_PER_DAY_MAGIC.div_by_table(secs, SECS_PER_DAY);
+    let secs = secs - (days * SECS_PER_DAY);
+    let hours = SECS_PER_HOUR_MAGIC.div_by_table(secs, 3600);
+    let secs = secs - (hours * 3600);
+    let mins = SECS_PER_MIN_MAGIC.div_by_table(secs, 60);
+    let secs = secs - (mins * 60);
+    (days, hours as u8, mins as u8, secs as u8)
+}
+
+/// Breaks `nanos` nanoseconds down into `(days, hours, mins, secs, nanos)`. The number of days
+/// can exceed a `u64` for the largest inputs.
+pub fn u128_breakdown_nanos(nanos: u128) -> (u128, u8, u8, u8, u32) {
+    let (secs, nanos) = u128_div_rem_magic(nanos, NANOS_PER_SEC, &NANOS_PER_SEC_MAGIC);
+    let (days, secs) = u128_div_rem_magic(secs, SECS_PER_DAY, &SECS_PER_DAY_MAGIC);
+    let (_, hours, mins, secs) = u64_breakdown_secs(secs);
+    (days, hours, mins, secs, nanos as u32)
+}
@@ -0,0 +1,58 @@
+//! Interoperability with the 256 bit integers of the `ethnum` crate. Note that `U256` and `I256`
+//! have inherent `div_rem` methods that take precedence over `DivRem::div_rem` in method call
+//! syntax, so the trait method has to be called as `DivRem::div_rem(duo, div)`.
+
+use ::ethnum::{I256, U256};
+
+use super::{DivRem, Uint};
+
+impl From<U256> for Uint<4> {
+    fn from(x: U256) -> Self {
+        let (hi, lo) = x.into_words();
+        Uint::from_limbs([lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64])
+    }
+}
+
+impl From<Uint<4>> for U256 {
+    fn from(x: Uint<4>) -> Self {
+        let limbs = x.limbs;
+        U256::from_words(
+            ((limbs[3] as u128) << 64) | (limbs[2] as u128),
+            ((limbs[1] as u128) << 64) | (limbs[0] as u128),
+        )
+    }
+}
+
+impl DivRem for U256 {
+    type Output = (U256, U256);
+
+    /// Divides with the limb algorithms of `bignum` through `Uint<4>`
+    #[track_caller]
+    fn div_rem(self, rhs: U256) -> (U256, U256) {
+        let (quo, rem) = Uint::<4>::from(self).div_rem(Uint::<4>::from(rhs));
+        (quo.into(), rem.into())
+    }
+}
+
+impl DivRem for I256 {
+    type Output = (I256, I256);
+
+    /// Divides with the limb algorithms of `bignum` through `Uint<4>`. Like the primitive
+    /// integers, `I256::MIN.div_rem(-1)` wraps around to `(I256::MIN, 0)`.
+    #[track_caller]
+    fn div_rem(self, rhs: I256) -> (I256, I256) {
+        let (quo, rem) = DivRem::div_rem(self.unsigned_abs(), rhs.unsigned_abs());
+        // the quotient is negative if the signs differ, and the remainder has the sign of `self`
+        let quo = if self.is_negative() != rhs.is_negative() {
+            quo.as_i256().wrapping_neg()
+        } else {
+            quo.as_i256()
+        };
+        let rem = if self.is_negative() {
+            rem.as_i256().wrapping_neg()
+        } else {
+            rem.as_i256()
+        };
+        (quo, rem)
+    }
+}
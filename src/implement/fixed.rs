@@ -0,0 +1,137 @@
+//! Widening division of the fixed point types of the `fixed` crate, where the dividend is shifted
+//! left by the number of fractional bits into an integer twice as wide before dividing
+
+use ::fixed::{
+    types::extra::{LeEqU128, LeEqU64},
+    FixedI128, FixedI64, FixedU128, FixedU64,
+};
+
+use super::*;
+
+/// Division of fixed point numbers that computes the exact truncated quotient using an
+/// intermediate twice as wide as the fixed point numbers (e.g. 256 bits for `I64F64 / I64F64`).
+pub trait WideDiv: Sized {
+    /// Returns `self / rhs` rounded towards zero, or `None` if `rhs` is zero or the quotient
+    /// overflows.
+    fn checked_wide_div(self, rhs: Self) -> Option<Self>;
+
+    /// Returns `self / rhs` rounded towards zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or if the quotient overflows.
+    #[track_caller]
+    fn wide_div(self, rhs: Self) -> Self {
+        match self.checked_wide_div(rhs) {
+            Some(quo) => quo,
+            None => panic!("attempt to divide by zero or with overflow"),
+        }
+    }
+}
+
+/// Computes `(duo << shl) / div`, returning `None` if the quotient does not fit in a `u64`
+fn u64_shl_div(duo: u64, shl: u32, div: u64) -> Option<u64> {
+    // `shl <= 64`, so this cannot overflow
+    let duo = (duo as u128) << shl;
+    let (quo, _) = u128_div_rem_trifecta(duo, div as u128);
+    if (quo >> 64) != 0 {
+        return None;
+    }
+    Some(quo as u64)
+}
+
+/// Computes `(duo << shl) / div`, returning `None` if the quotient does not fit in a `u128`. The
+/// 256 bit dividend is divided with two steps of 256 by 128 bit long division with 64 bit digits
+/// (as in "Hacker's Delight" by Henry S. Warren).
+fn u128_shl_div(duo: u128, shl: u32, div: u128) -> Option<u128> {
+    // the 256 bit dividend
+    let (duo_hi, duo_lo) = match shl {
+        0 => (0, duo),
+        128 => (duo, 0),
+        _ => (duo >> (128 - shl), duo << shl),
+    };
+    if duo_hi >= div {
+        return None;
+    }
+    if duo_hi == 0 {
+        return Some(u128_div_rem_trifecta(duo_lo, div).0);
+    }
+    // Normalize so that the msb of `div` is set, which bounds the error of the estimated digits.
+    // `duo_hi < div`, so no bits are shifted out of `duo_hi`.
+    let norm = div.leading_zeros();
+    let div = div << norm;
+    let (duo_hi, duo_lo) = if norm == 0 {
+        (duo_hi, duo_lo)
+    } else {
+        ((duo_hi << norm) | (duo_lo >> (128 - norm)), duo_lo << norm)
+    };
+    let div_1 = div >> 64;
+    let div_0 = div as u64 as u128;
+    let duo_1 = duo_lo >> 64;
+    let duo_0 = duo_lo as u64 as u128;
+
+    // Estimates the next 64 bit digit of the quotient of `(rem << 64) | duo_digit` divided by
+    // `div`, which is at most 2 too large, and corrects it
+    let digit = |rem: u128, duo_digit: u128| -> (u128, u128) {
+        let (mut quo, mut rem_hat) = u128_div_rem_trifecta(rem, div_1);
+        while (quo >> 64) != 0 || (quo * div_0) > ((rem_hat << 64) | duo_digit) {
+            quo -= 1;
+            rem_hat += div_1;
+            if (rem_hat >> 64) != 0 {
+                break;
+            }
+        }
+        // the true remainder is less than `div`
+        let rem = ((rem << 64) | duo_digit).wrapping_sub(quo.wrapping_mul(div));
+        (quo, rem)
+    };
+    let (quo_1, rem) = digit(duo_hi, duo_1);
+    let (quo_0, _) = digit(rem, duo_0);
+    Some((quo_1 << 64) | quo_0)
+}
+
+macro_rules! impl_wide_div_unsigned {
+    ($Fixed:ident, $LeEqU:ident, $shl_div:ident) => {
+        impl<Frac: $LeEqU> WideDiv for $Fixed<Frac> {
+            fn checked_wide_div(self, rhs: Self) -> Option<Self> {
+                if rhs.to_bits() == 0 {
+                    return None;
+                }
+                let quo = $shl_div(self.to_bits(), Self::FRAC_NBITS, rhs.to_bits())?;
+                Some(Self::from_bits(quo))
+            }
+        }
+    };
+}
+
+macro_rules! impl_wide_div_signed {
+    ($Fixed:ident, $LeEqU:ident, $shl_div:ident, $iX:ident) => {
+        impl<Frac: $LeEqU> WideDiv for $Fixed<Frac> {
+            fn checked_wide_div(self, rhs: Self) -> Option<Self> {
+                let duo = self.to_bits();
+                let div = rhs.to_bits();
+                if div == 0 {
+                    return None;
+                }
+                let quo = $shl_div(duo.unsigned_abs(), Self::FRAC_NBITS, div.unsigned_abs())?;
+                if (duo < 0) != (div < 0) {
+                    // `$iX::MIN` is allowed for negative quotients
+                    if quo > ($iX::MIN as $iX).unsigned_abs() {
+                        return None;
+                    }
+                    Some(Self::from_bits((quo as $iX).wrapping_neg()))
+                } else {
+                    if quo > ($iX::MAX as $iX).unsigned_abs() {
+                        return None;
+                    }
+                    Some(Self::from_bits(quo as $iX))
+                }
+            }
+        }
+    };
+}
+
+impl_wide_div_unsigned!(FixedU64, LeEqU64, u64_shl_div);
+impl_wide_div_unsigned!(FixedU128, LeEqU128, u128_shl_div);
+impl_wide_div_signed!(FixedI64, LeEqU64, u64_shl_div, i64);
+impl_wide_div_signed!(FixedI128, LeEqU128, u128_shl_div, i128);
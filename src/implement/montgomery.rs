@@ -0,0 +1,156 @@
+//! Montgomery multiplication by a fixed odd modulus `m`. Values are converted into Montgomery form
+//! `x * R % m` with `R = 2^n`, in which products are reduced by `mont_reduce` (REDC from "Modular
+//! Multiplication Without Trial Division" by Peter L. Montgomery) with only multiplications and
+//! shifts by `n`. This is faster than `barrett` for long chains of modular multiplications, such as
+//! modular exponentiation, where the conversions into and out of Montgomery form are amortized.
+
+#[allow(unused_imports)]
+use super::*;
+
+macro_rules! impl_montgomery {
+    (
+        $name:ident, // name of the Montgomery struct
+        $r_mod:path, // `const` function computing `R % m` of an odd modulus
+        $widening_mul:path, // `const` full product of two `$uX`s as a tuple of halves, lower first
+        $n:expr, // the number of bits in a $uX
+        $uX:ident // unsigned integer type for the modulus and the values
+    ) => {
+        /// A fixed odd modulus with the precomputed constants of Montgomery multiplication
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name {
+            m: $uX,
+            // `-1 / m` modulo `R`
+            m_neg_inv: $uX,
+            // `R^2 % m`
+            r2: $uX,
+        }
+
+        impl $name {
+            /// Returns `lhs * mul + add` as a tuple of the lower and upper halves, which cannot
+            /// overflow
+            const fn carrying_mul_add(lhs: $uX, mul: $uX, add: $uX) -> ($uX, $uX) {
+                let (lo, hi) = $widening_mul(lhs, mul);
+                let (lo, carry) = lo.overflowing_add(add);
+                (lo, hi + (carry as $uX))
+            }
+
+            /// Precomputes the constants for the odd modulus `m`
+            ///
+            /// # Panics
+            ///
+            /// Panics if `m` is even.
+            #[track_caller]
+            pub const fn new(m: $uX) -> Self {
+                if m % 2 == 0 {
+                    panic!("the modulus of Montgomery multiplication has to be odd")
+                }
+                let m_neg_inv = $crate::odd_inverse!(m, $n, $uX).wrapping_neg();
+                // `R % m`, doubled modulo `m` another `$n` times to get `R^2 % m`
+                let mut r2 = $r_mod(m);
+                let mut i = 0;
+                while i < $n {
+                    let (tmp, carry) = r2.overflowing_add(r2);
+                    r2 = if carry || (tmp >= m) {
+                        tmp.wrapping_sub(m)
+                    } else {
+                        tmp
+                    };
+                    i += 1;
+                }
+                Self { m, m_neg_inv, r2 }
+            }
+
+            /// Returns the modulus
+            pub const fn modulus(self) -> $uX {
+                self.m
+            }
+
+            /// Returns `(hi * 2^$n + lo) / R % m`, where `hi < m`
+            const fn redc(self, lo: $uX, hi: $uX) -> $uX {
+                // `u * m + (hi, lo)` is a multiple of `R`, so its lower half is 0 and only the
+                // carry out of it is kept
+                let u = lo.wrapping_mul(self.m_neg_inv);
+                let (_, carry) = Self::carrying_mul_add(u, self.m, lo);
+                // the sum is less than `2 * m`, but can overflow a `$uX`
+                let (rem, overflow) = hi.overflowing_add(carry);
+                if overflow || (rem >= self.m) {
+                    rem.wrapping_sub(self.m)
+                } else {
+                    rem
+                }
+            }
+
+            /// Converts `x` into Montgomery form, returning `x * R % m`
+            pub const fn to_mont(self, x: $uX) -> $uX {
+                self.mont_mul(x, self.r2)
+            }
+
+            /// Converts `x` out of Montgomery form, returning `x / R % m`
+            pub const fn from_mont(self, x: $uX) -> $uX {
+                self.redc(x, 0)
+            }
+
+            /// Returns `a * b / R % m`, which is the Montgomery form of the product of the values
+            /// if `a` and `b` are in Montgomery form. One of `a` and `b` has to be less than `m`.
+            pub const fn mont_mul(self, a: $uX, b: $uX) -> $uX {
+                debug_assert!((a < self.m) || (b < self.m));
+                let (lo, hi) = $widening_mul(a, b);
+                self.redc(lo, hi)
+            }
+        }
+    };
+}
+
+/// Returns `2^64 % m` for an odd `m`
+#[cfg(feature = "width-64")]
+const fn u64_r_mod(m: u64) -> u64 {
+    if m == 1 {
+        return 0;
+    }
+    // With `m` normalized for the reciprocal division, `2^64` is shifted by the same amount. The
+    // upper half of the shifted dividend is less than the normalized `m` because `m > 1`.
+    let shl = m.leading_zeros();
+    let div = m << shl;
+    let recip = super::reciprocal::u64_reciprocal(div);
+    let (_, rem) = super::reciprocal::u128_by_u64_div_rem_recip(1 << (64 + shl), div, recip);
+    rem >> shl
+}
+
+#[cfg(feature = "width-64")]
+impl_montgomery!(
+    Montgomery64,
+    u64_r_mod,
+    super::width_64::u64_widening_mul,
+    64,
+    u64
+);
+
+#[cfg(feature = "width-64")]
+impl Montgomery64 {
+    /// Returns `x / R % m`, where `x < m * R`
+    pub const fn mont_reduce(self, x: u128) -> u64 {
+        debug_assert!((x >> 64) < (self.m as u128));
+        self.redc(x as u64, (x >> 64) as u64)
+    }
+}
+
+/// Returns `2^128 % m` for an odd `m`
+#[cfg(feature = "width-128")]
+const fn u128_r_mod(m: u128) -> u128 {
+    if m == 1 {
+        return 0;
+    }
+    super::width_128::u128_shifted_div_rem(1, m).1
+}
+
+#[cfg(feature = "width-128")]
+impl_montgomery!(Montgomery128, u128_r_mod, u128_widening_mul, 128, u128);
+
+#[cfg(all(feature = "width-128", feature = "width-256"))]
+impl Montgomery128 {
+    /// Returns `x / R % m`, where `x < m * R`
+    pub const fn mont_reduce(self, x: u256) -> u128 {
+        debug_assert!(x.hi < self.m);
+        self.redc(x.lo, x.hi)
+    }
+}
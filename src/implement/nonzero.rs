@@ -0,0 +1,117 @@
+//! Division functions that take `NonZero` divisors and cannot panic. Every panic in the algorithms
+//! comes from division by zero (or from debug assertions), so these use copies of the algorithms
+//! instantiated with half divisions by `NonZero` integers and a division by zero function that is
+//! unreachable. With the `no-panic` feature, `#[no_panic]` verifies this when building in release
+//! mode. With the `no-unsafe` feature, the unreachable paths are checked `unreachable!()` panics
+//! instead of `unreachable_unchecked`, which the optimizer can usually still remove.
+
+#[cfg(feature = "width-128")]
+use core::num::{NonZeroI128, NonZeroU128};
+#[cfg(feature = "width-32")]
+use core::num::{NonZeroI32, NonZeroU32};
+#[cfg(feature = "width-64")]
+use core::num::{NonZeroI64, NonZeroU64};
+
+mod imp {
+    #[allow(unused_imports)]
+    use core::num::{NonZeroU16, NonZeroU32, NonZeroU64};
+
+    /// The wrappers only pass nonzero divisors to the algorithms, which never divide by zero
+    /// themselves.
+    #[cfg(not(feature = "no-unsafe"))]
+    #[allow(dead_code)]
+    #[inline(always)]
+    fn unreachable_div_fn() -> ! {
+        unsafe { core::hint::unreachable_unchecked() }
+    }
+
+    #[cfg(feature = "no-unsafe")]
+    #[allow(dead_code)]
+    #[inline(always)]
+    fn unreachable_div_fn() -> ! {
+        unreachable!()
+    }
+
+    macro_rules! impl_half_division_nonzero {
+        ($name:ident, $uX:ident, $nonzero:ident) => {
+            // The algorithms only call these with nonzero divisors
+            #[allow(dead_code)]
+            fn $name(duo: $uX, div: $uX) -> ($uX, $uX) {
+                #[cfg(not(feature = "no-unsafe"))]
+                let div = unsafe { $nonzero::new_unchecked(div) };
+                #[cfg(feature = "no-unsafe")]
+                let div = match $nonzero::new(div) {
+                    Some(div) => div,
+                    None => unreachable_div_fn(),
+                };
+                (duo / div, duo % div)
+            }
+        };
+    }
+
+    impl_half_division_nonzero!(u16_by_u16_div_rem_nonzero, u16, NonZeroU16);
+    impl_half_division_nonzero!(u32_by_u32_div_rem_nonzero, u32, NonZeroU32);
+    impl_half_division_nonzero!(u64_by_u64_div_rem_nonzero, u64, NonZeroU64);
+
+    #[cfg(feature = "width-32")]
+    impl_trifecta!(
+        u32_div_rem_nonzero,
+        i32_div_rem_nonzero,
+        unreachable_div_fn,
+        u16_by_u16_div_rem_nonzero,
+        8,
+        u8,
+        u16,
+        u32,
+        i32,
+        inline(never);
+        inline
+    );
+    #[cfg(feature = "width-64")]
+    impl_trifecta!(
+        u64_div_rem_nonzero,
+        i64_div_rem_nonzero,
+        unreachable_div_fn,
+        u32_by_u32_div_rem_nonzero,
+        16,
+        u16,
+        u32,
+        u64,
+        i64,
+        inline(never);
+        inline
+    );
+    #[cfg(feature = "width-128")]
+    impl_trifecta!(
+        u128_div_rem_nonzero,
+        i128_div_rem_nonzero,
+        unreachable_div_fn,
+        u64_by_u64_div_rem_nonzero,
+        32,
+        u32,
+        u64,
+        u128,
+        i128,
+        inline(never);
+        inline
+    );
+}
+
+macro_rules! impl_nonzero {
+    ($width:literal, $name:ident, $ty:ident, $nonzero:ident) => {
+        /// Computes the quotient and remainder of `duo` divided by `div` and returns them as a
+        /// tuple. This cannot panic.
+        #[cfg(feature = $width)]
+        #[cfg_attr(feature = "no-panic", no_panic::no_panic)]
+        pub fn $name(duo: $ty, div: $nonzero) -> ($ty, $ty) {
+            imp::$name(duo, div.get())
+        }
+    };
+}
+
+impl_nonzero!("width-32", u32_div_rem_nonzero, u32, NonZeroU32);
+impl_nonzero!("width-32", i32_div_rem_nonzero, i32, NonZeroI32);
+impl_nonzero!("width-64", u64_div_rem_nonzero, u64, NonZeroU64);
+impl_nonzero!("width-64", i64_div_rem_nonzero, i64, NonZeroI64);
+impl_nonzero!("width-128", u128_div_rem_nonzero, u128, NonZeroU128);
+impl_nonzero!("width-128", i128_div_rem_nonzero, i128, NonZeroI128);
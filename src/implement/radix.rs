@@ -0,0 +1,82 @@
+//! Mixed radix decomposition, which generalizes breaking down quantities into units (e.g.
+//! seconds into minutes, hours, and days with the bases `[60, 60, 24]`)
+
+use super::*;
+
+/// Decomposes `x` into digits of the mixed radix given by `bases`, from the least significant
+/// digit to the most significant. `out[i]` is set to the digit for `bases[i]`, and the last
+/// element of `out` is set to what is left after dividing by all of the bases. For example,
+/// decomposing `100_000` seconds with the bases `[60, 60, 24]` results in `[40, 46, 3, 1]`, or 1
+/// day, 3 hours, 46 minutes, and 40 seconds.
+///
+/// # Panics
+///
+/// Panics if any of the `bases` are zero, or if `out.len() != bases.len() + 1`.
+#[track_caller]
+pub fn u64_decompose(x: u64, bases: &[u64], out: &mut [u64]) {
+    assert!(out.len() == bases.len() + 1);
+    let mut x = x;
+    let mut i = 0;
+    while i < bases.len() {
+        if bases[i] == 0 {
+            zero_div_fn()
+        }
+        let (quo, rem) = u64_by_u64_div_rem(x, bases[i]);
+        out[i] = rem;
+        x = quo;
+        i += 1;
+    }
+    out[bases.len()] = x;
+}
+
+/// A mixed radix with the magic numbers for all of its bases precomputed, for when the same list
+/// of bases is used for many decompositions. This can be built at compile time, such as
+/// `const DHMS: U64MixedRadix<3> = U64MixedRadix::new([60, 60, 24]);`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct U64MixedRadix<const LEN: usize> {
+    bases: [u64; LEN],
+    magic: [U64MagicTable<1>; LEN],
+}
+
+impl<const LEN: usize> U64MixedRadix<LEN> {
+    /// Computes the magic numbers for `bases`, which are ordered from the least significant digit
+    /// to the most significant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `bases` are zero.
+    pub const fn new(bases: [u64; LEN]) -> Self {
+        let mut magic = [U64MagicTable::new(1); LEN];
+        let mut i = 0;
+        while i < LEN {
+            magic[i] = U64MagicTable::new(bases[i]);
+            i += 1;
+        }
+        Self { bases, magic }
+    }
+
+    /// Returns the bases of the radix
+    pub const fn bases(&self) -> [u64; LEN] {
+        self.bases
+    }
+
+    /// The same as `u64_decompose` with the bases of `self`, except that no division instructions
+    /// are used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out.len() != LEN + 1`.
+    #[track_caller]
+    pub fn decompose(&self, x: u64, out: &mut [u64]) {
+        assert!(out.len() == LEN + 1);
+        let mut x = x;
+        let mut i = 0;
+        while i < LEN {
+            let quo = self.magic[i].div_by_table(x, self.bases[i]);
+            out[i] = x - (quo * self.bases[i]);
+            x = quo;
+            i += 1;
+        }
+        out[LEN] = x;
+    }
+}
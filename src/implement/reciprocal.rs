@@ -0,0 +1,76 @@
+//! The reciprocal based 2 by 1 word division of "Improved Division by Invariant Integers" (Niels
+//! Möller, Torbjörn Granlund). The reciprocal of a normalized `u64` divisor is refined from a
+//! table lookup with Newton iterations, so that a `u128` by `u64` division needs only
+//! multiplications. This is the `u128_by_u64_div_rem_unchecked` of targets without a 128 by 64 bit
+//! division instruction (such as AArch64 and RISC-V), where the `/` operator calls the generic
+//! `__udivti3` of `compiler-builtins`.
+
+/// `floor((2^19 - 3 * 2^8) / d)` for `d` from 256 to 511, which are the 11 bit approximations of
+/// the reciprocals of the 9 most significant bits of normalized divisors
+const RECIPROCAL_SEEDS: [u16; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = (0x7fd00 / (i + 256)) as u16;
+        i += 1;
+    }
+    table
+};
+
+/// Returns `floor((2^128 - 1) / div) - 2^64` for a normalized `div` (the most significant bit is
+/// set)
+pub(crate) const fn u64_reciprocal(div: u64) -> u64 {
+    debug_assert!((div >> 63) != 0);
+    // The steps of `reciprocal_word` from the paper, where each `v` has about twice as many correct
+    // bits as the previous one. `v1` has 22 bits, `v2` has 35 bits, and `v3` is at most 1 too
+    // small, which `v4` corrects.
+    let v0 = RECIPROCAL_SEEDS[((div >> 55) - 256) as usize] as u64;
+    let d40 = (div >> 24) + 1;
+    let v1 = (v0 << 11) - (((v0 * v0) * d40) >> 40) - 1;
+    let v2 = (v1 << 13) + (v1.wrapping_mul((1u64 << 60).wrapping_sub(v1 * d40)) >> 47);
+    // `ceil(div / 2)`, and the error `2^96 - v2 * div` (modulo `2^64`) calculated from it
+    let d0 = div & 1;
+    let d63 = (div >> 1) + d0;
+    let e = ((v2 >> 1) & d0.wrapping_neg()).wrapping_sub(v2.wrapping_mul(d63));
+    let v3 = ((((v2 as u128) * (e as u128)) >> 65) as u64).wrapping_add(v2 << 31);
+    let tmp = (v3 as u128) * (div as u128) + (div as u128);
+    v3.wrapping_sub((tmp >> 64) as u64).wrapping_sub(div)
+}
+
+/// Divides `duo` by the normalized `div` with `recip = u64_reciprocal(div)`, and returns a tuple of
+/// the quotient and the remainder. `(duo >> 64) < div` is required for the quotient to fit.
+pub(crate) const fn u128_by_u64_div_rem_recip(duo: u128, div: u64, recip: u64) -> (u64, u64) {
+    let duo_hi = (duo >> 64) as u64;
+    let duo_lo = duo as u64;
+    debug_assert!(duo_hi < div);
+    // The quotient estimate is the high half of `(recip + 2^64) * duo_hi + duo` plus 1, which
+    // is at most 1 too large or 1 too small after the first correction. The remainder is
+    // calculated modulo `2^64` since it is known to be close to `div`.
+    let tmp = ((recip as u128) * (duo_hi as u128)).wrapping_add(duo);
+    let mut quo = ((tmp >> 64) as u64).wrapping_add(1);
+    let mut rem = duo_lo.wrapping_sub(quo.wrapping_mul(div));
+    if rem > (tmp as u64) {
+        quo = quo.wrapping_sub(1);
+        rem = rem.wrapping_add(div);
+    }
+    if rem >= div {
+        quo += 1;
+        rem -= div;
+    }
+    (quo, rem)
+}
+
+/// Divides `duo` by `div` and returns a tuple of the quotient and the remainder, or `None` if the
+/// quotient does not fit in a `u64` (including when `div == 0`). This is the same as
+/// `u128_by_u64_div_rem`, except that it always uses the reciprocal based division of targets
+/// without a 128 by 64 bit division instruction, so that it can be compared on any target.
+pub fn u128_by_u64_div_rem_reciprocal(duo: u128, div: u64) -> Option<(u64, u64)> {
+    if ((duo >> 64) as u64) >= div {
+        return None;
+    }
+    // `duo < div * 2^64`, so no set bits of `duo` are shifted out by the normalization
+    let shl = div.leading_zeros();
+    let div = div << shl;
+    let (quo, rem) = u128_by_u64_div_rem_recip(duo << shl, div, u64_reciprocal(div));
+    Some((quo, rem >> shl))
+}
@@ -0,0 +1,50 @@
+//! Wrappers of the `DivRem` functions that check every result against the `/` and `%` operators in
+//! debug builds, so that production workloads can be run as a continuous differential test while
+//! qualifying this crate. In release builds (without `debug_assertions`), they are the same as
+//! `DivRem::div_rem`.
+
+use super::DivRem;
+
+macro_rules! impl_self_check {
+    ($($name:ident, $iX:ident;)*) => {
+        $(
+            /// Computes the quotient and remainder of `duo` divided by `div` with
+            /// `DivRem::div_rem`, and returns them as a tuple. With `debug_assertions`, this also
+            /// computes them with the `/` and `%` operators and asserts that they are equal. Like
+            /// `DivRem`, the signed functions wrap around for `iX::MIN / -1`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `div == 0`, or if the results differ with `debug_assertions`.
+            #[track_caller]
+            pub fn $name(duo: $iX, div: $iX) -> ($iX, $iX) {
+                let res = duo.div_rem(div);
+                if cfg!(debug_assertions) {
+                    let expected = (duo.wrapping_div(div), duo.wrapping_rem(div));
+                    assert_eq!(
+                        res,
+                        expected,
+                        "`{}` returned a wrong result for `{} / {}`",
+                        stringify!($name),
+                        duo,
+                        div
+                    );
+                }
+                res
+            }
+        )*
+    };
+}
+
+impl_self_check!(
+    u8_div_rem_self_checked, u8;
+    i8_div_rem_self_checked, i8;
+    u16_div_rem_self_checked, u16;
+    i16_div_rem_self_checked, i16;
+    u32_div_rem_self_checked, u32;
+    i32_div_rem_self_checked, i32;
+    u64_div_rem_self_checked, u64;
+    i64_div_rem_self_checked, i64;
+    u128_div_rem_self_checked, u128;
+    i128_div_rem_self_checked, i128;
+);
@@ -0,0 +1,36 @@
+//! Batch division by an invariant divisor with `core::simd`, so that any target with SIMD gets a
+//! vectorized path without intrinsics for each instruction set.
+
+use core::simd::{num::SimdUint, u32x8, u64x8};
+
+use super::*;
+
+/// Divides every element of `duo` by `div` in place. This uses the magic numbers of
+/// `U32MagicTable`, with 8 lanes at a time widened to `u64` for the multiplication.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[track_caller]
+pub fn u32_div_invariant_simd(duo: &mut [u32], div: u32) {
+    if div == 0 {
+        zero_div_fn()
+    }
+    let table = U32MagicTable::<1>::new(div);
+    let (mul, shift) = match table.get(div) {
+        Some(magic) => magic,
+        None => unreachable!(),
+    };
+    let mul = u64x8::splat(mul as u64);
+    let shift = u64x8::splat(shift as u64);
+    let mut chunks = duo.chunks_exact_mut(8);
+    for chunk in &mut chunks {
+        let x: u64x8 = u32x8::from_slice(chunk).cast();
+        // the same calculation as `U32MagicTable::div_by_table`
+        let quo = (((x * mul) >> 32) + x) >> shift;
+        quo.cast::<u32>().copy_to_slice(chunk);
+    }
+    for x in chunks.into_remainder() {
+        *x = table.div_by_table(*x, div);
+    }
+}
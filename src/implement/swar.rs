@@ -0,0 +1,312 @@
+//! The central loops of the SWAR algorithm of `impl_binary_long` for `u32_div_rem_binary_long` and
+//! `u64_div_rem_binary_long`. With the `asm` feature, these are written in assembly on x86, ARM,
+//! and RISC-V so that each step is guaranteed to take at most 5 instructions, instead of depending
+//! on how LLVM handles the Rust loop of `impl_swar_steps`.
+
+// x86 CPUs with SSE are all P6 or later, which have `cmov`. Thumb targets need Thumb-2 for `it`.
+#[cfg(all(
+    feature = "width-32",
+    not(all(
+        feature = "asm",
+        not(feature = "no-unsafe"),
+        any(
+            target_arch = "x86_64",
+            all(target_arch = "x86", target_feature = "sse"),
+            target_arch = "aarch64",
+            all(
+                target_arch = "arm",
+                any(not(target_feature = "thumb-mode"), target_feature = "v6t2")
+            ),
+            target_arch = "riscv32",
+            target_arch = "riscv64"
+        )
+    ))
+))]
+impl_swar_steps!(u32_swar_steps, 32, u32, i32, inline);
+
+#[cfg(all(
+    feature = "width-64",
+    not(all(
+        feature = "asm",
+        not(feature = "no-unsafe"),
+        any(
+            target_arch = "x86_64",
+            target_arch = "aarch64",
+            target_arch = "riscv64"
+        )
+    ))
+))]
+impl_swar_steps!(u64_swar_steps, 64, u64, i64, inline);
+
+/// Performs `steps` steps of the central loop of the SWAR binary long division algorithm on `duo`,
+/// where `div` is the normalized divisor minus one (so that each step also shifts a quotient bit
+/// into `duo`), and returns the new `duo`. Each step is 4 instructions, selecting between
+/// `(duo << 1) - div` and `duo << 1` with a conditional move on the sign of the former.
+#[cfg(all(
+    feature = "width-32",
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    any(
+        target_arch = "x86_64",
+        all(target_arch = "x86", target_feature = "sse")
+    )
+))]
+#[inline]
+pub fn u32_swar_steps(duo: u32, div: u32, steps: u32) -> u32 {
+    crate::instrument::steps(crate::instrument::Loop::BinaryLong, steps);
+    let mut duo = duo;
+    unsafe {
+        core::arch::asm!(
+            "test {cnt:e}, {cnt:e}",
+            "jz 3f",
+            "2:",
+            "add {duo:e}, {duo:e}",
+            "mov {tmp:e}, {duo:e}",
+            "sub {tmp:e}, {div:e}",
+            "cmovns {duo:e}, {tmp:e}",
+            "dec {cnt:e}",
+            "jnz 2b",
+            "3:",
+            duo = inout(reg) duo,
+            div = in(reg) div,
+            cnt = inout(reg) steps => _,
+            tmp = out(reg) _,
+            options(pure, nomem, nostack)
+        );
+    }
+    duo
+}
+
+/// Performs `steps` steps of the central loop of the SWAR binary long division algorithm on `duo`,
+/// where `div` is the normalized divisor minus one (so that each step also shifts a quotient bit
+/// into `duo`), and returns the new `duo`. Each step is 4 instructions, selecting between
+/// `(duo << 1) - div` and `duo << 1` with a conditional move on the sign of the former.
+#[cfg(all(
+    feature = "width-64",
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "x86_64"
+))]
+#[inline]
+pub fn u64_swar_steps(duo: u64, div: u64, steps: u32) -> u64 {
+    crate::instrument::steps(crate::instrument::Loop::BinaryLong, steps);
+    let mut duo = duo;
+    unsafe {
+        core::arch::asm!(
+            "test {cnt:e}, {cnt:e}",
+            "jz 3f",
+            "2:",
+            "add {duo}, {duo}",
+            "mov {tmp}, {duo}",
+            "sub {tmp}, {div}",
+            "cmovns {duo}, {tmp}",
+            "dec {cnt:e}",
+            "jnz 2b",
+            "3:",
+            duo = inout(reg) duo,
+            div = in(reg) div,
+            cnt = inout(reg) steps => _,
+            tmp = out(reg) _,
+            options(pure, nomem, nostack)
+        );
+    }
+    duo
+}
+
+/// Performs `steps` steps of the central loop of the SWAR binary long division algorithm on `duo`,
+/// where `div` is the normalized divisor minus one (so that each step also shifts a quotient bit
+/// into `duo`), and returns the new `duo`. Each step is 3 instructions, selecting between
+/// `(duo << 1) - div` and `duo << 1` with `csel` on the sign of the former.
+#[cfg(all(
+    feature = "width-32",
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "aarch64"
+))]
+#[inline]
+pub fn u32_swar_steps(duo: u32, div: u32, steps: u32) -> u32 {
+    crate::instrument::steps(crate::instrument::Loop::BinaryLong, steps);
+    let mut duo = duo;
+    unsafe {
+        core::arch::asm!(
+            "cbz {cnt:w}, 3f",
+            "2:",
+            "lsl {duo:w}, {duo:w}, #1",
+            "subs {tmp:w}, {duo:w}, {div:w}",
+            "csel {duo:w}, {tmp:w}, {duo:w}, pl",
+            "subs {cnt:w}, {cnt:w}, #1",
+            "b.ne 2b",
+            "3:",
+            duo = inout(reg) duo,
+            div = in(reg) div,
+            cnt = inout(reg) steps => _,
+            tmp = out(reg) _,
+            options(pure, nomem, nostack)
+        );
+    }
+    duo
+}
+
+/// Performs `steps` steps of the central loop of the SWAR binary long division algorithm on `duo`,
+/// where `div` is the normalized divisor minus one (so that each step also shifts a quotient bit
+/// into `duo`), and returns the new `duo`. Each step is 3 instructions, selecting between
+/// `(duo << 1) - div` and `duo << 1` with `csel` on the sign of the former.
+#[cfg(all(
+    feature = "width-64",
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "aarch64"
+))]
+#[inline]
+pub fn u64_swar_steps(duo: u64, div: u64, steps: u32) -> u64 {
+    crate::instrument::steps(crate::instrument::Loop::BinaryLong, steps);
+    let mut duo = duo;
+    unsafe {
+        core::arch::asm!(
+            "cbz {cnt:w}, 3f",
+            "2:",
+            "lsl {duo}, {duo}, #1",
+            "subs {tmp}, {duo}, {div}",
+            "csel {duo}, {tmp}, {duo}, pl",
+            "subs {cnt:w}, {cnt:w}, #1",
+            "b.ne 2b",
+            "3:",
+            duo = inout(reg) duo,
+            div = in(reg) div,
+            cnt = inout(reg) steps => _,
+            tmp = out(reg) _,
+            options(pure, nomem, nostack)
+        );
+    }
+    duo
+}
+
+/// Performs `steps` steps of the central loop of the SWAR binary long division algorithm on `duo`,
+/// where `div` is the normalized divisor minus one (so that each step also shifts a quotient bit
+/// into `duo`), and returns the new `duo`. Each step is 3 instructions, selecting between
+/// `(duo << 1) - div` and `duo << 1` with a predicated move on the sign of the former (the `it`
+/// is only an instruction in Thumb-2).
+#[cfg(all(
+    feature = "width-32",
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "arm",
+    any(not(target_feature = "thumb-mode"), target_feature = "v6t2")
+))]
+#[inline]
+pub fn u32_swar_steps(duo: u32, div: u32, steps: u32) -> u32 {
+    crate::instrument::steps(crate::instrument::Loop::BinaryLong, steps);
+    let mut duo = duo;
+    unsafe {
+        core::arch::asm!(
+            "cmp {cnt}, #0",
+            "beq 3f",
+            "2:",
+            "lsl {duo}, {duo}, #1",
+            "subs {tmp}, {duo}, {div}",
+            "it pl",
+            "movpl {duo}, {tmp}",
+            "subs {cnt}, {cnt}, #1",
+            "bne 2b",
+            "3:",
+            duo = inout(reg) duo,
+            div = in(reg) div,
+            cnt = inout(reg) steps => _,
+            tmp = out(reg) _,
+            options(pure, nomem, nostack)
+        );
+    }
+    duo
+}
+
+/// Performs `steps` steps of the central loop of the SWAR binary long division algorithm on `duo`,
+/// where `div` is the normalized divisor minus one (so that each step also shifts a quotient bit
+/// into `duo`), and returns the new `duo`. RISC-V has no flags or conditional moves, so each step
+/// is the 5 instructions of the mask based loop of `impl_swar_steps`.
+#[cfg(all(
+    feature = "width-32",
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    any(target_arch = "riscv32", target_arch = "riscv64")
+))]
+#[inline]
+pub fn u32_swar_steps(duo: u32, div: u32, steps: u32) -> u32 {
+    crate::instrument::steps(crate::instrument::Loop::BinaryLong, steps);
+    let mut duo = duo;
+    // the `w` instructions of RV64 only use the lower 32 bits of the registers
+    unsafe {
+        #[cfg(target_arch = "riscv32")]
+        core::arch::asm!(
+            "beqz {cnt}, 3f",
+            "2:",
+            "add {duo}, {duo}, {duo}",
+            "sub {duo}, {duo}, {div}",
+            "srai {tmp}, {duo}, 31",
+            "and {tmp}, {tmp}, {div}",
+            "add {duo}, {duo}, {tmp}",
+            "addi {cnt}, {cnt}, -1",
+            "bnez {cnt}, 2b",
+            "3:",
+            duo = inout(reg) duo,
+            div = in(reg) div,
+            cnt = inout(reg) steps as usize => _,
+            tmp = out(reg) _,
+            options(pure, nomem, nostack)
+        );
+        #[cfg(target_arch = "riscv64")]
+        core::arch::asm!(
+            "beqz {cnt}, 3f",
+            "2:",
+            "addw {duo}, {duo}, {duo}",
+            "subw {duo}, {duo}, {div}",
+            "sraiw {tmp}, {duo}, 31",
+            "and {tmp}, {tmp}, {div}",
+            "addw {duo}, {duo}, {tmp}",
+            "addi {cnt}, {cnt}, -1",
+            "bnez {cnt}, 2b",
+            "3:",
+            duo = inout(reg) duo,
+            div = in(reg) div,
+            cnt = inout(reg) steps as usize => _,
+            tmp = out(reg) _,
+            options(pure, nomem, nostack)
+        );
+    }
+    duo
+}
+
+/// Performs `steps` steps of the central loop of the SWAR binary long division algorithm on `duo`,
+/// where `div` is the normalized divisor minus one (so that each step also shifts a quotient bit
+/// into `duo`), and returns the new `duo`. RISC-V has no flags or conditional moves, so each step
+/// is the 5 instructions of the mask based loop of `impl_swar_steps`.
+#[cfg(all(
+    feature = "width-64",
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "riscv64"
+))]
+#[inline]
+pub fn u64_swar_steps(duo: u64, div: u64, steps: u32) -> u64 {
+    crate::instrument::steps(crate::instrument::Loop::BinaryLong, steps);
+    let mut duo = duo;
+    unsafe {
+        core::arch::asm!(
+            "beqz {cnt}, 3f",
+            "2:",
+            "add {duo}, {duo}, {duo}",
+            "sub {duo}, {duo}, {div}",
+            "srai {tmp}, {duo}, 63",
+            "and {tmp}, {tmp}, {div}",
+            "add {duo}, {duo}, {tmp}",
+            "addi {cnt}, {cnt}, -1",
+            "bnez {cnt}, 2b",
+            "3:",
+            duo = inout(reg) duo,
+            div = in(reg) div,
+            cnt = inout(reg) steps as u64 => _,
+            tmp = out(reg) _,
+            options(pure, nomem, nostack)
+        );
+    }
+    duo
+}
@@ -0,0 +1,252 @@
+//! The recommended division functions for the architecture being compiled for, so that
+//! `compiler-builtins` and other integrators do not have to re-derive the results of the
+//! benchmarks. Every target has `u32_div_rem`, `i32_div_rem`, `u64_div_rem`, `i64_div_rem`,
+//! `u128_div_rem`, and `i128_div_rem`. Widths that the target can divide in hardware use the
+//! native operators, and the other widths are re-exports of the algorithms of this crate. Like
+//! the algorithms, the signed functions wrap around for `iX::MIN / -1`. The `slow-mul` feature
+//! replaces the algorithms that need a fast multiplier with `_delegate`. `selected_backend`
+//! reports which of these was chosen for each width.
+
+/// The implementation behind a function of this module, as reported by `selected_backend`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BackendKind {
+    /// The native `/` and `%` operators, which use the hardware division of the target
+    Native,
+    /// The `_binary_long` functions
+    BinaryLong,
+    /// The `_delegate` functions
+    Delegate,
+    /// The `_trifecta` functions
+    Trifecta,
+    /// The `_asymmetric` functions, which use an asymmetric hardware division
+    Asymmetric,
+    /// The `_trifecta_delegate` functions, which are `_trifecta` on top of `_delegate`
+    TrifectaDelegate,
+}
+
+/// Returns the implementation that the `u{width}_div_rem` and `i{width}_div_rem` functions of this
+/// module use on the target being compiled for, so that tests, benchmarks, and bug reports can
+/// state the active configuration.
+///
+/// # Panics
+///
+/// Panics if `width` is not 32, 64, or 128.
+pub const fn selected_backend(width: u32) -> BackendKind {
+    match width {
+        32 => family::U32_BACKEND,
+        64 => family::U64_BACKEND,
+        128 => {
+            if cfg!(feature = "slow-mul") {
+                BackendKind::Delegate
+            } else {
+                family::U128_BACKEND
+            }
+        }
+        _ => panic!("`target_defaults` only has widths 32, 64, and 128"),
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_native {
+    ($unsigned_name:ident, $signed_name:ident, $uX:ident, $iX:ident) => {
+        /// Computes the quotient and remainder of `duo` divided by `div` with the hardware
+        /// division of the target and returns them as a tuple.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        #[track_caller]
+        pub fn $unsigned_name(duo: $uX, div: $uX) -> ($uX, $uX) {
+            (duo / div, duo % div)
+        }
+
+        /// Computes the quotient and remainder of `duo` divided by `div` with the hardware
+        /// division of the target and returns them as a tuple.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        #[track_caller]
+        pub fn $signed_name(duo: $iX, div: $iX) -> ($iX, $iX) {
+            (duo.wrapping_div(div), duo.wrapping_rem(div))
+        }
+    };
+}
+
+// 64 bit division in hardware, and an asymmetric 128 by 64 bit division
+#[cfg(target_arch = "x86_64")]
+mod family {
+    #[cfg(not(feature = "slow-mul"))]
+    pub use super::super::{
+        i128_div_rem_asymmetric as i128_div_rem, u128_div_rem_asymmetric as u128_div_rem,
+    };
+    use super::BackendKind;
+
+    pub(super) const U32_BACKEND: BackendKind = BackendKind::Native;
+    pub(super) const U64_BACKEND: BackendKind = BackendKind::Native;
+    pub(super) const U128_BACKEND: BackendKind = BackendKind::Asymmetric;
+
+    impl_native!(u32_div_rem, i32_div_rem, u32, i32);
+    impl_native!(u64_div_rem, i64_div_rem, u64, i64);
+}
+
+// 64 bit division in hardware, and an asymmetric 128 by 64 bit division (`dlgr`) with the `asm`
+// feature
+#[cfg(target_arch = "s390x")]
+mod family {
+    #[cfg(all(feature = "asm", not(feature = "no-unsafe"), not(feature = "slow-mul")))]
+    pub use super::super::{
+        i128_div_rem_asymmetric as i128_div_rem, u128_div_rem_asymmetric as u128_div_rem,
+    };
+    #[cfg(all(
+        not(all(feature = "asm", not(feature = "no-unsafe"))),
+        not(feature = "slow-mul")
+    ))]
+    pub use super::super::{
+        i128_div_rem_trifecta as i128_div_rem, u128_div_rem_trifecta as u128_div_rem,
+    };
+    use super::BackendKind;
+
+    pub(super) const U32_BACKEND: BackendKind = BackendKind::Native;
+    pub(super) const U64_BACKEND: BackendKind = BackendKind::Native;
+    pub(super) const U128_BACKEND: BackendKind =
+        if cfg!(all(feature = "asm", not(feature = "no-unsafe"))) {
+            BackendKind::Asymmetric
+        } else {
+            BackendKind::Trifecta
+        };
+
+    impl_native!(u32_div_rem, i32_div_rem, u32, i32);
+    impl_native!(u64_div_rem, i64_div_rem, u64, i64);
+}
+
+// 64 bit division and fast multipliers in hardware. On PowerPC64, `divdu` takes tens of cycles but
+// `mulhdu` is fast, which is the same tradeoff that makes `_trifecta` faster than `_delegate` on the
+// others (`_delegate` does a 64 bit division per 32 bits of a large quotient). `_asymmetric` with the
+// `mulhdu` based `u128_by_u64_div_rem` is only faster when the divisor fits in 64 bits.
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "wasm64",
+    target_arch = "riscv64",
+    target_arch = "powerpc64",
+    target_arch = "mips64"
+))]
+mod family {
+    #[cfg(not(feature = "slow-mul"))]
+    pub use super::super::{
+        i128_div_rem_trifecta as i128_div_rem, u128_div_rem_trifecta as u128_div_rem,
+    };
+    use super::BackendKind;
+
+    pub(super) const U32_BACKEND: BackendKind = BackendKind::Native;
+    pub(super) const U64_BACKEND: BackendKind = BackendKind::Native;
+    pub(super) const U128_BACKEND: BackendKind = BackendKind::Trifecta;
+
+    impl_native!(u32_div_rem, i32_div_rem, u32, i32);
+    impl_native!(u64_div_rem, i64_div_rem, u64, i64);
+}
+
+// an asymmetric 64 by 32 bit division in hardware
+#[cfg(target_arch = "x86")]
+mod family {
+    // `u128_div_rem_trifecta_asymmetric` has not been benchmarked on this target yet, so `_trifecta`
+    // stays the recommendation until `x86_bench` shows otherwise
+    #[cfg(not(feature = "slow-mul"))]
+    pub use super::super::{
+        i128_div_rem_trifecta as i128_div_rem, i64_div_rem_asymmetric as i64_div_rem,
+        u128_div_rem_trifecta as u128_div_rem, u64_div_rem_asymmetric as u64_div_rem,
+    };
+
+    #[cfg(feature = "slow-mul")]
+    pub use super::super::{
+        i64_div_rem_delegate as i64_div_rem, u64_div_rem_delegate as u64_div_rem,
+    };
+    use super::BackendKind;
+
+    pub(super) const U32_BACKEND: BackendKind = BackendKind::Native;
+    pub(super) const U64_BACKEND: BackendKind = if cfg!(feature = "slow-mul") {
+        BackendKind::Delegate
+    } else {
+        BackendKind::Asymmetric
+    };
+    pub(super) const U128_BACKEND: BackendKind = BackendKind::Trifecta;
+
+    impl_native!(u32_div_rem, i32_div_rem, u32, i32);
+}
+
+// 32 bit division in hardware on ARMv7-M and ARMv8-M mainline (the target features are only
+// visible to `cfg` on nightly)
+#[cfg(all(target_arch = "arm", target_feature = "mclass", target_feature = "v7"))]
+mod family {
+    #[cfg(not(feature = "slow-mul"))]
+    pub use super::super::{
+        i128_div_rem_trifecta_delegate as i128_div_rem,
+        u128_div_rem_trifecta_delegate as u128_div_rem,
+    };
+    pub use super::super::{
+        i64_div_rem_delegate as i64_div_rem, u64_div_rem_delegate as u64_div_rem,
+    };
+    use super::BackendKind;
+
+    pub(super) const U32_BACKEND: BackendKind = BackendKind::Native;
+    pub(super) const U64_BACKEND: BackendKind = BackendKind::Delegate;
+    pub(super) const U128_BACKEND: BackendKind = BackendKind::TrifectaDelegate;
+
+    impl_native!(u32_div_rem, i32_div_rem, u32, i32);
+}
+
+// 16 bit targets (AVR and MSP430) with no hardware division wider than 16 bits and slow (or
+// absent) multipliers. `u32_div_rem_delegate` uses the `u16` division as its half division, and the
+// half divisions of the larger widths are built from it (see `half_div_16` in `implement.rs`).
+#[cfg(target_pointer_width = "16")]
+mod family {
+    pub use super::super::{
+        i128_div_rem_delegate as i128_div_rem, i32_div_rem_delegate as i32_div_rem,
+        i64_div_rem_delegate as i64_div_rem, u128_div_rem_delegate as u128_div_rem,
+        u32_div_rem_delegate as u32_div_rem, u64_div_rem_delegate as u64_div_rem,
+    };
+    use super::BackendKind;
+
+    pub(super) const U32_BACKEND: BackendKind = BackendKind::Delegate;
+    pub(super) const U64_BACKEND: BackendKind = BackendKind::Delegate;
+    pub(super) const U128_BACKEND: BackendKind = BackendKind::Delegate;
+}
+
+// Everything else is assumed to have no hardware division. The `_delegate` and `_trifecta`
+// functions are called with the functions of the smaller widths as their half divisions, which is
+// the fastest configuration found in benchmarks (see the notes in `implement.rs`).
+#[cfg(not(any(
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "wasm64",
+    target_arch = "riscv64",
+    target_arch = "powerpc64",
+    target_arch = "mips64",
+    target_arch = "s390x",
+    target_arch = "x86",
+    all(target_arch = "arm", target_feature = "mclass", target_feature = "v7"),
+    target_pointer_width = "16"
+)))]
+mod family {
+    #[cfg(not(feature = "slow-mul"))]
+    pub use super::super::{
+        i128_div_rem_trifecta as i128_div_rem, u128_div_rem_trifecta as u128_div_rem,
+    };
+    pub use super::super::{
+        i32_div_rem_binary_long as i32_div_rem, i64_div_rem_delegate as i64_div_rem,
+        u32_div_rem_binary_long as u32_div_rem, u64_div_rem_delegate as u64_div_rem,
+    };
+    use super::BackendKind;
+
+    pub(super) const U32_BACKEND: BackendKind = BackendKind::BinaryLong;
+    pub(super) const U64_BACKEND: BackendKind = BackendKind::Delegate;
+    pub(super) const U128_BACKEND: BackendKind = BackendKind::Trifecta;
+}
+
+pub use family::*;
+
+// `_trifecta` and `_asymmetric` need very fast widening multiplication
+#[cfg(feature = "slow-mul")]
+pub use super::{i128_div_rem_delegate as i128_div_rem, u128_div_rem_delegate as u128_div_rem};
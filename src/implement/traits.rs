@@ -0,0 +1,302 @@
+//! The `DivRem` trait for using the algorithms of this crate through the primitive integers and the
+//! `Wrapping` and `Saturating` newtypes, the `DivSaturatingDsp` trait for division that never
+//! panics, and the `iX_wrapping_div_rem`, `iX_overflowing_div_rem`, and `_div_rem_round`
+//! functions
+
+#[allow(unused_imports)]
+use core::num::{Saturating, Wrapping};
+
+#[allow(unused_imports)]
+use super::*;
+
+/// Division that returns the quotient and remainder together. For the primitive integers and
+/// `Wrapping`, this uses the algorithm recommended for each width when there is no hardware
+/// division, and `iX::MIN.div_rem(-1)` wraps around to `(iX::MIN, 0)`. For `Saturating`, that
+/// case instead saturates to `(iX::MAX, 0)`.
+///
+/// # Panics
+///
+/// Like the operators of `core`, this panics if `rhs` is zero.
+pub trait DivRem<Rhs = Self> {
+    /// The type of the quotient and remainder tuple
+    type Output;
+
+    /// Computes the quotient and remainder of `self` divided by `rhs` and returns them as a
+    /// tuple.
+    fn div_rem(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Division with the semantics of common DSP and fixed point hardware dividers, for audio and
+/// control loop code that can neither panic nor wrap around. Division by zero returns `MAX`
+/// instead of panicking, and the quotient saturates instead of wrapping, so `iX::MIN / -1` returns
+/// `iX::MAX`.
+pub trait DivSaturatingDsp<Rhs = Self> {
+    /// The type of the quotient
+    type Output;
+
+    /// Computes the quotient of `self` divided by `rhs`, saturating on overflow and returning `MAX`
+    /// if `rhs` is zero.
+    fn div_saturating_dsp(self, rhs: Rhs) -> Self::Output;
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_div_rem_trait {
+    (
+        $uX:ident,
+        $iX:ident,
+        $unsigned_fn:ident,
+        $signed_fn:ident,
+        $wrapping_name:ident,
+        $overflowing_name:ident,
+        $unsigned_round_name:ident,
+        $signed_round_name:ident
+    ) => {
+        impl DivRem for $uX {
+            type Output = ($uX, $uX);
+
+            #[track_caller]
+            fn div_rem(self, rhs: $uX) -> ($uX, $uX) {
+                $unsigned_fn(self, rhs)
+            }
+        }
+
+        impl DivRem for $iX {
+            type Output = ($iX, $iX);
+
+            #[track_caller]
+            fn div_rem(self, rhs: $iX) -> ($iX, $iX) {
+                $signed_fn(self, rhs)
+            }
+        }
+
+        impl DivRem for Wrapping<$uX> {
+            type Output = (Wrapping<$uX>, Wrapping<$uX>);
+
+            #[track_caller]
+            fn div_rem(self, rhs: Wrapping<$uX>) -> Self::Output {
+                let (quo, rem) = $unsigned_fn(self.0, rhs.0);
+                (Wrapping(quo), Wrapping(rem))
+            }
+        }
+
+        impl DivRem for Wrapping<$iX> {
+            type Output = (Wrapping<$iX>, Wrapping<$iX>);
+
+            #[track_caller]
+            fn div_rem(self, rhs: Wrapping<$iX>) -> Self::Output {
+                // the signed functions already wrap for `$iX::MIN / -1`
+                let (quo, rem) = $signed_fn(self.0, rhs.0);
+                (Wrapping(quo), Wrapping(rem))
+            }
+        }
+
+        impl DivRem for Saturating<$uX> {
+            type Output = (Saturating<$uX>, Saturating<$uX>);
+
+            #[track_caller]
+            fn div_rem(self, rhs: Saturating<$uX>) -> Self::Output {
+                // unsigned division cannot overflow
+                let (quo, rem) = $unsigned_fn(self.0, rhs.0);
+                (Saturating(quo), Saturating(rem))
+            }
+        }
+
+        impl DivRem for Saturating<$iX> {
+            type Output = (Saturating<$iX>, Saturating<$iX>);
+
+            #[track_caller]
+            fn div_rem(self, rhs: Saturating<$iX>) -> Self::Output {
+                if self.0 == $iX::MIN && rhs.0 == -1 {
+                    return (Saturating($iX::MAX), Saturating(0));
+                }
+                let (quo, rem) = $signed_fn(self.0, rhs.0);
+                (Saturating(quo), Saturating(rem))
+            }
+        }
+
+        impl DivSaturatingDsp for $uX {
+            type Output = $uX;
+
+            fn div_saturating_dsp(self, rhs: $uX) -> $uX {
+                if rhs == 0 {
+                    return $uX::MAX;
+                }
+                $unsigned_fn(self, rhs).0
+            }
+        }
+
+        impl DivSaturatingDsp for $iX {
+            type Output = $iX;
+
+            fn div_saturating_dsp(self, rhs: $iX) -> $iX {
+                if rhs == 0 {
+                    return $iX::MAX;
+                }
+                if self == $iX::MIN && rhs == -1 {
+                    return $iX::MAX;
+                }
+                $signed_fn(self, rhs).0
+            }
+        }
+
+        impl_wrapping_div_rem!(
+            $wrapping_name,
+            $overflowing_name,
+            $signed_fn,
+            $iX,
+            inline,
+            track_caller
+        );
+
+        impl_round_div_rem!(
+            $unsigned_round_name,
+            $signed_round_name,
+            $unsigned_fn,
+            $uX,
+            $iX,
+            inline,
+            track_caller
+        );
+    };
+}
+
+#[cfg(all(
+    feature = "width-8",
+    feature = "binary-long",
+    not(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))
+))]
+impl_div_rem_trait!(
+    u8,
+    i8,
+    u8_div_rem_binary_long,
+    i8_div_rem_binary_long,
+    i8_wrapping_div_rem,
+    i8_overflowing_div_rem,
+    u8_div_rem_round,
+    i8_div_rem_round
+);
+#[cfg(all(
+    feature = "width-16",
+    feature = "binary-long",
+    not(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))
+))]
+impl_div_rem_trait!(
+    u16,
+    i16,
+    u16_div_rem_binary_long,
+    i16_div_rem_binary_long,
+    i16_wrapping_div_rem,
+    i16_overflowing_div_rem,
+    u16_div_rem_round,
+    i16_div_rem_round
+);
+#[cfg(all(
+    feature = "width-8",
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "avr"
+))]
+impl_div_rem_trait!(
+    u8,
+    i8,
+    u8_div_rem_avr,
+    i8_div_rem_avr,
+    i8_wrapping_div_rem,
+    i8_overflowing_div_rem,
+    u8_div_rem_round,
+    i8_div_rem_round
+);
+#[cfg(all(
+    feature = "width-16",
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "avr"
+))]
+impl_div_rem_trait!(
+    u16,
+    i16,
+    u16_div_rem_avr,
+    i16_div_rem_avr,
+    i16_wrapping_div_rem,
+    i16_overflowing_div_rem,
+    u16_div_rem_round,
+    i16_div_rem_round
+);
+#[cfg(all(feature = "width-32", feature = "delegate"))]
+impl_div_rem_trait!(
+    u32,
+    i32,
+    u32_div_rem_delegate,
+    i32_div_rem_delegate,
+    i32_wrapping_div_rem,
+    i32_overflowing_div_rem,
+    u32_div_rem_round,
+    i32_div_rem_round
+);
+#[cfg(all(feature = "width-64", feature = "delegate"))]
+impl_div_rem_trait!(
+    u64,
+    i64,
+    u64_div_rem_delegate,
+    i64_div_rem_delegate,
+    i64_wrapping_div_rem,
+    i64_overflowing_div_rem,
+    u64_div_rem_round,
+    i64_div_rem_round
+);
+// On CPUs with only 32 bit hardware division, `u64_div_rem_delegate` uses it directly and the
+// `u128` functions chain on top of that. `_trifecta` needs a fast multiplier, so `_delegate` is used
+// with `slow-mul`.
+#[cfg(all(feature = "width-128", feature = "delegate", feature = "slow-mul"))]
+impl_div_rem_trait!(
+    u128,
+    i128,
+    u128_div_rem_delegate,
+    i128_div_rem_delegate,
+    i128_wrapping_div_rem,
+    i128_overflowing_div_rem,
+    u128_div_rem_round,
+    i128_div_rem_round
+);
+#[cfg(all(
+    feature = "width-128",
+    feature = "trifecta",
+    not(feature = "slow-mul"),
+    not(all(
+        feature = "delegate",
+        feature = "width-64",
+        target_arch = "arm",
+        target_feature = "mclass",
+        target_feature = "v7"
+    ))
+))]
+impl_div_rem_trait!(
+    u128,
+    i128,
+    u128_div_rem_trifecta,
+    i128_div_rem_trifecta,
+    i128_wrapping_div_rem,
+    i128_overflowing_div_rem,
+    u128_div_rem_round,
+    i128_div_rem_round
+);
+#[cfg(all(
+    feature = "width-128",
+    feature = "trifecta",
+    not(feature = "slow-mul"),
+    feature = "delegate",
+    feature = "width-64",
+    target_arch = "arm",
+    target_feature = "mclass",
+    target_feature = "v7"
+))]
+impl_div_rem_trait!(
+    u128,
+    i128,
+    u128_div_rem_trifecta_delegate,
+    i128_div_rem_trifecta_delegate,
+    i128_wrapping_div_rem,
+    i128_overflowing_div_rem,
+    u128_div_rem_round,
+    i128_div_rem_round
+);
@@ -0,0 +1,251 @@
+//! Runtime selection of the division algorithms. The functions of `target_defaults` are chosen from
+//! benchmarks of representative CPUs of each architecture, which can be wrong for a particular
+//! machine (e.g. a CPU with an unusually slow multiplier or divider). `AutoTuner` benchmarks the
+//! candidate algorithms on the running machine and caches the index of the fastest one of each
+//! width in an atomic integer, which the `_tuned` functions then call. Before tuning, the `_tuned`
+//! functions use `target_defaults`.
+//!
+//! ```
+//! use specialized_div_rem::tuner::{u128_div_rem_tuned, AutoTuner};
+//!
+//! // at the startup of a long running service
+//! AutoTuner::new().samples(1000).tune_all();
+//! assert_eq!(u128_div_rem_tuned(1 << 100, 3), ((1 << 100) / 3, 1));
+//! ```
+
+use core::{
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use std::time::Instant;
+
+use super::{target_defaults, target_defaults::BackendKind};
+use crate::{Random, Rng};
+
+/// Benchmarks the candidate algorithms for a width on the running machine and caches the fastest
+/// for the `_tuned` functions. This is intended to be run once at startup, since tuning takes time
+/// proportional to the sample budget for each candidate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutoTuner {
+    samples: u32,
+    seed: u64,
+}
+
+impl Default for AutoTuner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AutoTuner {
+    /// Creates a tuner with the default budget of 10000 divisions per candidate
+    pub const fn new() -> Self {
+        Self {
+            samples: 10000,
+            seed: 0,
+        }
+    }
+
+    /// Sets the number of divisions that each candidate is timed on. Larger budgets give more
+    /// reliable results at the cost of startup time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples == 0`.
+    pub const fn samples(mut self, samples: u32) -> Self {
+        assert!(samples != 0);
+        self.samples = samples;
+        self
+    }
+
+    /// Sets the seed of the generator of the benchmark operands
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Tunes all of the widths
+    pub fn tune_all(&self) {
+        self.tune_u32();
+        self.tune_u64();
+        self.tune_u128();
+    }
+}
+
+// The number of times that the candidates are timed, with the minimum time being used. Interleaving
+// the rounds keeps frequency scaling and other noise from favoring whichever candidate runs last.
+const ROUNDS: usize = 3;
+
+/// Generates `samples` operands with random significant bit counts, since the algorithms have
+/// different strengths for different sizes of quotients
+fn operands<T: Random + Copy>(
+    rng: &mut Rng,
+    samples: u32,
+    n: u32,
+    shr: fn(T, u32) -> T,
+    is_zero: fn(T) -> bool,
+) -> Vec<(T, T)> {
+    let mut v = Vec::with_capacity(samples as usize);
+    while v.len() < (samples as usize) {
+        let duo = shr(rng.random(), (rng.next_u64() % (n as u64)) as u32);
+        let div = shr(rng.random(), (rng.next_u64() % (n as u64)) as u32);
+        if !is_zero(div) {
+            v.push((duo, div));
+        }
+    }
+    v
+}
+
+macro_rules! impl_tuned {
+    (
+        $tune:ident, // name of the `AutoTuner` method
+        $unsigned_name:ident, // name of the unsigned tuned function
+        $signed_name:ident, // name of the signed tuned function
+        $selected:ident, // name of the atomic index of the selected candidate
+        $candidates:ident, // name of the table of candidates
+        $default:ident, // the `target_defaults` function
+        $n:expr, // the number of bits in a $uX
+        $uX:ident, // unsigned integer type
+        $iX:ident, // signed integer type
+        $($kind:ident, $candidate:ident;)* // the candidates
+    ) => {
+        // `BackendKind::Native` stands for the `/` and `%` operators, which may be software
+        // routines of `compiler-builtins`
+        static $candidates: &[(BackendKind, fn($uX, $uX) -> ($uX, $uX))] = &[
+            (BackendKind::Native, |duo, div| (duo / div, duo % div)),
+            // this covers the `_trifecta_asymmetric` and `_trifecta_delegate` chains
+            (target_defaults::selected_backend($n), target_defaults::$default),
+            $((BackendKind::$kind, super::$candidate),)*
+        ];
+
+        // the index of the selected candidate plus one, or zero until tuned
+        static $selected: AtomicUsize = AtomicUsize::new(0);
+
+        impl AutoTuner {
+            /// Benchmarks the candidates for this width, caches the fastest one for the `_tuned`
+            /// functions, and returns which one it is.
+            pub fn $tune(&self) -> BackendKind {
+                let candidates = $candidates;
+                let mut rng = Rng::new(self.seed);
+                let ops: Vec<($uX, $uX)> = operands(
+                    &mut rng,
+                    self.samples,
+                    $n,
+                    |x, s| x >> s,
+                    |x| x == 0,
+                );
+                let mut best = vec![u128::MAX; candidates.len()];
+                let mut round = 0;
+                while round < ROUNDS {
+                    let mut i = 0;
+                    while i < candidates.len() {
+                        let f = black_box(candidates[i].1);
+                        let start = Instant::now();
+                        let mut j = 0;
+                        while j < ops.len() {
+                            black_box(f(black_box(ops[j].0), black_box(ops[j].1)));
+                            j += 1;
+                        }
+                        let time = start.elapsed().as_nanos();
+                        if time < best[i] {
+                            best[i] = time;
+                        }
+                        i += 1;
+                    }
+                    round += 1;
+                }
+                let mut winner = 0;
+                let mut i = 1;
+                while i < candidates.len() {
+                    if best[i] < best[winner] {
+                        winner = i;
+                    }
+                    i += 1;
+                }
+                $selected.store(winner + 1, Ordering::Relaxed);
+                candidates[winner].0
+            }
+        }
+
+        /// Computes the quotient and remainder of `duo` divided by `div` with the algorithm chosen
+        /// by `AutoTuner`, or the one of `target_defaults` if this width has not been tuned, and
+        /// returns them as a tuple.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        #[track_caller]
+        pub fn $unsigned_name(duo: $uX, div: $uX) -> ($uX, $uX) {
+            let selected = $selected.load(Ordering::Relaxed);
+            if selected == 0 {
+                return target_defaults::$default(duo, div)
+            }
+            ($candidates[selected - 1].1)(duo, div)
+        }
+
+        /// Computes the quotient and remainder of `duo` divided by `div` with the algorithm chosen
+        /// by `AutoTuner`, or the one of `target_defaults` if this width has not been tuned, and
+        /// returns them as a tuple. Like the other signed functions, this wraps around for
+        /// `iX::MIN / -1`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        #[track_caller]
+        pub fn $signed_name(duo: $iX, div: $iX) -> ($iX, $iX) {
+            let (quo, rem) = $unsigned_name(duo.unsigned_abs(), div.unsigned_abs());
+            let mut quo = quo as $iX;
+            let mut rem = rem as $iX;
+            if duo < 0 {
+                rem = rem.wrapping_neg();
+            }
+            if (duo < 0) != (div < 0) {
+                quo = quo.wrapping_neg();
+            }
+            (quo, rem)
+        }
+    };
+}
+
+impl_tuned!(
+    tune_u32,
+    u32_div_rem_tuned,
+    i32_div_rem_tuned,
+    U32_SELECTED,
+    U32_CANDIDATES,
+    u32_div_rem,
+    32,
+    u32,
+    i32,
+    BinaryLong, u32_div_rem_binary_long;
+    Delegate, u32_div_rem_delegate;
+);
+impl_tuned!(
+    tune_u64,
+    u64_div_rem_tuned,
+    i64_div_rem_tuned,
+    U64_SELECTED,
+    U64_CANDIDATES,
+    u64_div_rem,
+    64,
+    u64,
+    i64,
+    BinaryLong, u64_div_rem_binary_long;
+    Delegate, u64_div_rem_delegate;
+    Trifecta, u64_div_rem_trifecta;
+    Asymmetric, u64_div_rem_asymmetric;
+);
+impl_tuned!(
+    tune_u128,
+    u128_div_rem_tuned,
+    i128_div_rem_tuned,
+    U128_SELECTED,
+    U128_CANDIDATES,
+    u128_div_rem,
+    128,
+    u128,
+    i128,
+    Delegate, u128_div_rem_delegate;
+    Trifecta, u128_div_rem_trifecta;
+    Asymmetric, u128_div_rem_asymmetric;
+);
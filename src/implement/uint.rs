@@ -0,0 +1,343 @@
+use core::cmp::Ordering;
+
+use super::bignum;
+
+/// A fixed width unsigned integer made of `LIMBS` `u64`s, for widths such as 320 or 384 bits that
+/// do not have a primitive integer type. The limbs are in little endian order (the least
+/// significant limb is at index 0).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Uint<const LIMBS: usize> {
+    pub limbs: [u64; LIMBS],
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// The value 0
+    pub const ZERO: Self = Self { limbs: [0; LIMBS] };
+
+    /// The largest value, `2^(64 * LIMBS) - 1`
+    pub const MAX: Self = Self {
+        limbs: [u64::MAX; LIMBS],
+    };
+
+    /// Creates a `Uint` from little endian limbs
+    pub const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+        Self { limbs }
+    }
+
+    /// Returns the little endian limbs of `self` (the least significant limb is at index 0, and
+    /// each limb is a native `u64`)
+    pub const fn into_limbs(self) -> [u64; LIMBS] {
+        self.limbs
+    }
+
+    /// Creates a `Uint` from its representation as a little endian byte array. `BYTES` has to be
+    /// `8 * LIMBS`, which is checked at compile time.
+    pub const fn from_le_bytes<const BYTES: usize>(bytes: [u8; BYTES]) -> Self {
+        const { assert!(BYTES == 8 * LIMBS) };
+        let mut tmp = Self::ZERO;
+        let mut i = 0;
+        while i < BYTES {
+            tmp.limbs[i / 8] |= (bytes[i] as u64) << (8 * (i % 8));
+            i += 1;
+        }
+        tmp
+    }
+
+    /// Creates a `Uint` from its representation as a big endian byte array (such as a hash or an
+    /// integer from a network protocol). `BYTES` has to be `8 * LIMBS`, which is checked at
+    /// compile time.
+    pub const fn from_be_bytes<const BYTES: usize>(bytes: [u8; BYTES]) -> Self {
+        const { assert!(BYTES == 8 * LIMBS) };
+        let mut tmp = Self::ZERO;
+        let mut i = 0;
+        while i < BYTES {
+            tmp.limbs[i / 8] |= (bytes[BYTES - 1 - i] as u64) << (8 * (i % 8));
+            i += 1;
+        }
+        tmp
+    }
+
+    /// Returns the representation of `self` as a little endian byte array. `BYTES` has to be
+    /// `8 * LIMBS`, which is checked at compile time.
+    pub const fn to_le_bytes<const BYTES: usize>(self) -> [u8; BYTES] {
+        const { assert!(BYTES == 8 * LIMBS) };
+        let mut bytes = [0; BYTES];
+        let mut i = 0;
+        while i < BYTES {
+            bytes[i] = (self.limbs[i / 8] >> (8 * (i % 8))) as u8;
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Returns the representation of `self` as a big endian byte array. `BYTES` has to be
+    /// `8 * LIMBS`, which is checked at compile time.
+    pub const fn to_be_bytes<const BYTES: usize>(self) -> [u8; BYTES] {
+        const { assert!(BYTES == 8 * LIMBS) };
+        let mut bytes = [0; BYTES];
+        let mut i = 0;
+        while i < BYTES {
+            bytes[BYTES - 1 - i] = (self.limbs[i / 8] >> (8 * (i % 8))) as u8;
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Returns `true` if `self` is zero
+    pub fn is_zero(&self) -> bool {
+        let mut i = 0;
+        while i < LIMBS {
+            if self.limbs[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Computes the quotient and remainder of `self` divided by `div` and returns them as a
+    /// tuple. This routes through the limb slice algorithms in `bignum` (short division for single
+    /// limb divisors, and `div_rem_limbs` otherwise).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `div` is zero.
+    #[track_caller]
+    pub fn div_rem(self, div: Self) -> (Self, Self) {
+        // the number of significant limbs in `div`
+        let n = bignum::bit_len(&div.limbs).div_ceil(64);
+        if n == 0 {
+            super::zero_div_fn()
+        }
+        let mut quo = Self::ZERO;
+        let mut rem = Self::ZERO;
+        if self < div {
+            return (quo, self);
+        }
+        if n == 1 {
+            quo = self;
+            rem.limbs[0] = bignum::div_rem_limbs_by_u64(&mut quo.limbs, div.limbs[0]);
+            return (quo, rem);
+        }
+        if n == LIMBS {
+            // The quotient fits in one limb because `div` has as many significant limbs as `self`,
+            // so at most 64 restoring steps are needed, starting with `div` shifted to align with
+            // the most significant bit of `self`.
+            let shift = (bignum::bit_len(&self.limbs) - bignum::bit_len(&div.limbs)) as u32;
+            let mut div_shifted = div;
+            bignum::shl(&mut div_shifted.limbs, shift);
+            rem = self;
+            let mut i = shift + 1;
+            while i > 0 {
+                i -= 1;
+                if bignum::sub_assign_if_ge(&mut rem.limbs, &div_shifted.limbs) {
+                    quo.limbs[0] |= 1 << i;
+                }
+                bignum::shr(&mut div_shifted.limbs, 1);
+            }
+            return (quo, rem);
+        }
+        // `div_rem_limbs` needs the most significant `n` limbs of the dividend to be less than
+        // `div`, which is the case if the most significant limb is zero. Otherwise, the upper
+        // `LIMBS - 1` limbs of `self` are divided first (with a zero limb on top), and then the
+        // `n + 1` limbs of their remainder and the least significant limb of `self`.
+        let mut tmp = self;
+        if self.limbs[LIMBS - 1] == 0 {
+            bignum::div_rem_limbs(&mut tmp.limbs, &div.limbs);
+            quo.limbs[..(LIMBS - n)].copy_from_slice(&tmp.limbs[n..]);
+            rem.limbs[..n].copy_from_slice(&tmp.limbs[..n]);
+        } else {
+            tmp.limbs.copy_within(1.., 0);
+            tmp.limbs[LIMBS - 1] = 0;
+            bignum::div_rem_limbs(&mut tmp.limbs, &div.limbs);
+            quo.limbs[1..=(LIMBS - n)].copy_from_slice(&tmp.limbs[n..]);
+            // `n + 1 <= LIMBS`, so the last step fits in `rem`
+            rem.limbs[0] = self.limbs[0];
+            rem.limbs[1..=n].copy_from_slice(&tmp.limbs[..n]);
+            bignum::div_rem_limbs(&mut rem.limbs[..=n], &div.limbs);
+            quo.limbs[0] = rem.limbs[n];
+            rem.limbs[n] = 0;
+        }
+        (quo, rem)
+    }
+
+    /// Computes `(self + rhs) % m` for `self, rhs < m`
+    fn add_mod(mut self, rhs: Self, m: Self) -> Self {
+        // if the sum overflows, it is definitely larger than `m` and the wrapping subtraction
+        // produces the correct result
+        let carry = bignum::add_assign(&mut self.limbs, &rhs.limbs);
+        if carry || (self >= m) {
+            bignum::sub_assign(&mut self.limbs, &m.limbs);
+        }
+        self
+    }
+
+    /// Computes `(self * rhs) % m` by binary multiplication with modular doublings and additions,
+    /// since there is no multiplication of `Uint`s into twice as many limbs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero.
+    #[track_caller]
+    pub fn mul_mod(self, rhs: Self, m: Self) -> Self {
+        let lhs = self.div_rem(m).1;
+        let rhs = rhs.div_rem(m).1;
+        let mut res = Self::ZERO;
+        let mut i = bignum::bit_len(&rhs.limbs);
+        while i > 0 {
+            i -= 1;
+            res = res.add_mod(res, m);
+            if ((rhs.limbs[i / 64] >> (i % 64)) & 1) != 0 {
+                res = res.add_mod(lhs, m);
+            }
+        }
+        res
+    }
+
+    /// Returns the `x < m` with `(self * x) % m == 1 % m` using the extended Euclidean
+    /// algorithm, or `None` if `self` and `m` are not coprime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero.
+    #[track_caller]
+    pub fn mod_inv(self, m: Self) -> Option<Self> {
+        let one = Self::from(1).div_rem(m).1;
+        let mut r0 = m;
+        let mut r1 = self.div_rem(m).1;
+        if one.is_zero() {
+            return Some(Self::ZERO);
+        }
+        // The coefficients of `self` for the remainders alternate in sign, so only their
+        // magnitudes are kept. They never exceed `m` before the last step (whose coefficient is
+        // not used), so they can be computed modulo `m`.
+        let mut u0 = Self::ZERO;
+        let mut u1 = one;
+        let mut odd = false;
+        while !r1.is_zero() {
+            let (quo, rem) = r0.div_rem(r1);
+            r0 = r1;
+            r1 = rem;
+            let tmp = u0.add_mod(quo.mul_mod(u1, m), m);
+            u0 = u1;
+            u1 = tmp;
+            odd = !odd;
+        }
+        if r0 != one {
+            return None;
+        }
+        if odd {
+            Some(u0)
+        } else {
+            let mut tmp = m;
+            bignum::sub_assign(&mut tmp.limbs, &u0.limbs);
+            Some(tmp)
+        }
+    }
+
+    /// Replaces each of the `values` with its inverse modulo `m`, using only one modular
+    /// inversion (and three modular multiplications per value) for every 64 values instead of one
+    /// inversion for each value. Returns `true` if all of the values were invertible. Otherwise,
+    /// the values without an inverse (such as 0) are set to 0, and `false` is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero.
+    #[track_caller]
+    pub fn batch_invert(values: &mut [Self], m: Self) -> bool {
+        // The prefix products are kept on the stack, so the values are inverted in chunks
+        const CHUNK: usize = 64;
+        let one = Self::from(1).div_rem(m).1;
+        let mut all = true;
+        let mut start = 0;
+        while start < values.len() {
+            let len = if (values.len() - start) < CHUNK {
+                values.len() - start
+            } else {
+                CHUNK
+            };
+            let mut prefix = [Self::ZERO; CHUNK];
+            let mut product = one;
+            let mut i = 0;
+            while i < len {
+                product = product.mul_mod(values[start + i], m);
+                prefix[i] = product;
+                i += 1;
+            }
+            match product.mod_inv(m) {
+                Some(mut inv) => {
+                    // `inv` is the inverse of `prefix[i]`, so `inv * prefix[i - 1]` is the inverse
+                    // of the `i`th value of the chunk, and `inv` times that value is the inverse
+                    // of `prefix[i - 1]`
+                    let mut i = len - 1;
+                    while i > 0 {
+                        let tmp = values[start + i];
+                        values[start + i] = inv.mul_mod(prefix[i - 1], m);
+                        inv = inv.mul_mod(tmp, m);
+                        i -= 1;
+                    }
+                    values[start] = inv;
+                }
+                None => {
+                    // at least one of the values has no inverse, so they are inverted separately
+                    all = false;
+                    let mut i = 0;
+                    while i < len {
+                        values[start + i] = values[start + i].mod_inv(m).unwrap_or(Self::ZERO);
+                        i += 1;
+                    }
+                }
+            }
+            start += len;
+        }
+        all
+    }
+}
+
+/// A 256 bit unsigned integer
+pub type U256 = Uint<4>;
+
+/// A 512 bit unsigned integer
+pub type U512 = Uint<8>;
+
+impl<const LIMBS: usize> Default for Uint<LIMBS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize> From<u64> for Uint<LIMBS> {
+    /// # Panics
+    ///
+    /// Panics if `LIMBS == 0` and `x != 0`.
+    fn from(x: u64) -> Self {
+        let mut tmp = Self::ZERO;
+        if x != 0 {
+            tmp.limbs[0] = x;
+        }
+        tmp
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for Uint<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for Uint<LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // compare starting from the most significant limb
+        let mut i = LIMBS;
+        loop {
+            if i == 0 {
+                return Ordering::Equal;
+            }
+            i -= 1;
+            if self.limbs[i] != other.limbs[i] {
+                return self.limbs[i].cmp(&other.limbs[i]);
+            }
+        }
+    }
+}
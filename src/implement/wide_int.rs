@@ -0,0 +1,693 @@
+//! `u256` and `i256`, 256 bit integers made of two `u128` halves, and `u512` and `i512`, which are
+//! made of two `u256` halves in turn. They have the operations that the algorithm macros use for
+//! `$uX`, `$uD`, and `$iD`, so that `impl_trifecta`, `impl_delegate`, and `impl_asymmetric` can be
+//! instantiated one level above `u128` with `u128` as the `$uX`, and `impl_trifecta` and
+//! `impl_delegate` once more with `u256` as the `$uX`.
+
+use core::{
+    cmp::Ordering,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl,
+        ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+    },
+};
+
+use super::u128_widening_mul;
+use crate::{cast::CastFrom, Random, Rng};
+
+const fn i128_cast_unsigned(x: i128) -> u128 {
+    x as u128
+}
+
+const fn u128_cast_signed(x: u128) -> i128 {
+    x as i128
+}
+
+// Creates an unsigned and a signed integer made of two halves of the unsigned integer `$uX`,
+// except that the most significant half of the signed integer is the signed `$iX`. The `const`
+// methods only use methods of the halves (which are all `const`) instead of operators, so that
+// the created types can be used as the halves of the next size up.
+macro_rules! impl_wide_int {
+    (
+        $(#[$u_attr:meta])* $uD:ident,
+        $(#[$i_attr:meta])* $iD:ident,
+        $uX:ident, // unsigned integer type of the halves
+        $iX:ident, // signed integer type of the most significant half of `$iD`
+        $half_widening_mul:path, // the full product of two `$uX`s as a tuple of halves
+        $half_cast_signed:path, // reinterprets a `$uX` as an `$iX`
+        $half_cast_unsigned:path, // reinterprets an `$iX` as a `$uX`
+        // the primitive integers that are narrower than `$uX`, unsigned and then signed
+        [$($uN:ident)*], [$($iN:ident)*]
+    ) => {
+        $(#[$u_attr])*
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        pub struct $uD {
+            pub lo: $uX,
+            pub hi: $uX,
+        }
+
+        $(#[$i_attr])*
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        pub struct $iD {
+            pub lo: $uX,
+            pub hi: $iX,
+        }
+
+        impl $uD {
+            /// The smallest value, 0
+            pub const MIN: Self = Self {
+                lo: $uX::MIN,
+                hi: $uX::MIN,
+            };
+
+            /// The largest value, `2^BITS - 1`
+            pub const MAX: Self = Self {
+                lo: $uX::MAX,
+                hi: $uX::MAX,
+            };
+
+            /// The number of bits
+            pub const BITS: u32 = $uX::BITS * 2;
+
+            /// Creates an integer from its lower and upper halves
+            pub const fn from_halves(lo: $uX, hi: $uX) -> Self {
+                Self { lo, hi }
+            }
+
+            /// Reinterprets the bits of `self` as a signed integer
+            pub const fn cast_signed(self) -> $iD {
+                $iD {
+                    lo: self.lo,
+                    hi: $half_cast_signed(self.hi),
+                }
+            }
+
+            /// Returns the number of leading zeros in the binary representation of `self`
+            pub const fn leading_zeros(self) -> u32 {
+                let hi_lz = self.hi.leading_zeros();
+                if hi_lz != $uX::BITS {
+                    hi_lz
+                } else {
+                    $uX::BITS + self.lo.leading_zeros()
+                }
+            }
+
+            /// Returns the number of trailing zeros in the binary representation of `self`
+            pub const fn trailing_zeros(self) -> u32 {
+                let lo_tz = self.lo.trailing_zeros();
+                if lo_tz != $uX::BITS {
+                    lo_tz
+                } else {
+                    $uX::BITS + self.hi.trailing_zeros()
+                }
+            }
+
+            /// Calculates `self + rhs`, and returns a tuple of the wrapped sum and if an overflow
+            /// occurred
+            pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+                let (hi, overflow_0) = self.hi.overflowing_add(rhs.hi);
+                let (hi, overflow_1) = if carry {
+                    // There is no `const` 1 for every `$uX`, but `hi + 1 == hi - MAX` in modular
+                    // arithmetic, and the addition overflows exactly when the subtraction does
+                    // not borrow.
+                    let (hi, borrow) = hi.overflowing_sub($uX::MAX);
+                    (hi, !borrow)
+                } else {
+                    (hi, false)
+                };
+                (Self { lo, hi }, overflow_0 || overflow_1)
+            }
+
+            /// Calculates `self - rhs`, and returns a tuple of the wrapped difference and if an
+            /// overflow occurred
+            pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let (lo, borrow) = self.lo.overflowing_sub(rhs.lo);
+                let (hi, overflow_0) = self.hi.overflowing_sub(rhs.hi);
+                let (hi, overflow_1) = if borrow {
+                    // like in `overflowing_add`, `hi - 1 == hi + MAX`
+                    let (hi, carry) = hi.overflowing_add($uX::MAX);
+                    (hi, !carry)
+                } else {
+                    (hi, false)
+                };
+                (Self { lo, hi }, overflow_0 || overflow_1)
+            }
+
+            /// Wrapping (modular) addition
+            pub const fn wrapping_add(self, rhs: Self) -> Self {
+                self.overflowing_add(rhs).0
+            }
+
+            /// Wrapping (modular) subtraction
+            pub const fn wrapping_sub(self, rhs: Self) -> Self {
+                self.overflowing_sub(rhs).0
+            }
+
+            /// Returns the full product of `self` and `rhs` as a tuple of the lower and upper
+            /// halves
+            pub const fn widening_mul(self, rhs: Self) -> (Self, Self) {
+                let mul_00 = $half_widening_mul(self.lo, rhs.lo);
+                let mul_01 = $half_widening_mul(self.lo, rhs.hi);
+                let mul_10 = $half_widening_mul(self.hi, rhs.lo);
+                let mul_11 = $half_widening_mul(self.hi, rhs.hi);
+                // the sum of three values less than `2^(BITS / 2)` cannot overflow
+                let mid = Self::from_halves(mul_00.1, $uX::MIN)
+                    .wrapping_add(Self::from_halves(mul_01.0, $uX::MIN))
+                    .wrapping_add(Self::from_halves(mul_10.0, $uX::MIN));
+                let lo = Self::from_halves(mul_00.0, mid.lo);
+                let hi = Self::from_halves(mul_11.0, mul_11.1)
+                    .wrapping_add(Self::from_halves(mul_01.1, $uX::MIN))
+                    .wrapping_add(Self::from_halves(mul_10.1, $uX::MIN))
+                    .wrapping_add(Self::from_halves(mid.hi, $uX::MIN));
+                (lo, hi)
+            }
+
+            /// Wrapping (modular) multiplication
+            pub const fn wrapping_mul(self, rhs: Self) -> Self {
+                let (lo, hi) = $half_widening_mul(self.lo, rhs.lo);
+                let hi = hi
+                    .wrapping_add(self.lo.wrapping_mul(rhs.hi))
+                    .wrapping_add(self.hi.wrapping_mul(rhs.lo));
+                Self { lo, hi }
+            }
+
+            /// Wrapping (modular) negation
+            pub const fn wrapping_neg(self) -> Self {
+                Self::MIN.wrapping_sub(self)
+            }
+
+            /// Shifts left by `s` bits, where `s` is masked to be less than `BITS` like the
+            /// `wrapping_shl` of the primitives
+            pub const fn wrapping_shl(self, s: u32) -> Self {
+                let s = s & (Self::BITS - 1);
+                if s >= $uX::BITS {
+                    Self {
+                        lo: $uX::MIN,
+                        hi: self.lo.wrapping_shl(s - $uX::BITS),
+                    }
+                } else if s == 0 {
+                    self
+                } else {
+                    // the bits of the two parts of `hi` do not overlap
+                    Self {
+                        lo: self.lo.wrapping_shl(s),
+                        hi: self
+                            .hi
+                            .wrapping_shl(s)
+                            .wrapping_add(self.lo.wrapping_shr($uX::BITS - s)),
+                    }
+                }
+            }
+
+            /// Shifts right by `s` bits, where `s` is masked to be less than `BITS` like the
+            /// `wrapping_shr` of the primitives
+            pub const fn wrapping_shr(self, s: u32) -> Self {
+                let s = s & (Self::BITS - 1);
+                if s >= $uX::BITS {
+                    Self {
+                        lo: self.hi.wrapping_shr(s - $uX::BITS),
+                        hi: $uX::MIN,
+                    }
+                } else if s == 0 {
+                    self
+                } else {
+                    // the bits of the two parts of `lo` do not overlap
+                    Self {
+                        lo: self
+                            .lo
+                            .wrapping_shr(s)
+                            .wrapping_add(self.hi.wrapping_shl($uX::BITS - s)),
+                        hi: self.hi.wrapping_shr(s),
+                    }
+                }
+            }
+        }
+
+        impl $iD {
+            /// The smallest value, `-2^(BITS - 1)`
+            pub const MIN: Self = Self {
+                lo: $uX::MIN,
+                hi: $iX::MIN,
+            };
+
+            /// The largest value, `2^(BITS - 1) - 1`
+            pub const MAX: Self = Self {
+                lo: $uX::MAX,
+                hi: $iX::MAX,
+            };
+
+            /// The number of bits
+            pub const BITS: u32 = $uX::BITS * 2;
+
+            /// Creates an integer from its lower and upper halves
+            pub const fn from_halves(lo: $uX, hi: $iX) -> Self {
+                Self { lo, hi }
+            }
+
+            /// Reinterprets the bits of `self` as an unsigned integer
+            pub const fn cast_unsigned(self) -> $uD {
+                $uD {
+                    lo: self.lo,
+                    hi: $half_cast_unsigned(self.hi),
+                }
+            }
+
+            /// Returns if `self` is negative
+            pub const fn is_negative(self) -> bool {
+                self.hi.is_negative()
+            }
+
+            /// Wrapping (modular) negation, which wraps around for `MIN`
+            pub const fn wrapping_neg(self) -> Self {
+                self.cast_unsigned().wrapping_neg().cast_signed()
+            }
+
+            /// Returns the absolute value of `self` as an unsigned integer, which does not
+            /// overflow for `MIN`
+            pub const fn unsigned_abs(self) -> $uD {
+                if self.is_negative() {
+                    self.cast_unsigned().wrapping_neg()
+                } else {
+                    self.cast_unsigned()
+                }
+            }
+        }
+
+        impl PartialOrd for $uD {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $uD {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.hi.cmp(&other.hi).then(self.lo.cmp(&other.lo))
+            }
+        }
+
+        impl PartialOrd for $iD {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $iD {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.hi.cmp(&other.hi).then(self.lo.cmp(&other.lo))
+            }
+        }
+
+        impl Add for $uD {
+            type Output = Self;
+
+            #[track_caller]
+            fn add(self, rhs: Self) -> Self {
+                let (sum, overflow) = self.overflowing_add(rhs);
+                if cfg!(debug_assertions) && overflow {
+                    panic!("attempt to add with overflow")
+                }
+                sum
+            }
+        }
+
+        impl Sub for $uD {
+            type Output = Self;
+
+            #[track_caller]
+            fn sub(self, rhs: Self) -> Self {
+                let (dif, overflow) = self.overflowing_sub(rhs);
+                if cfg!(debug_assertions) && overflow {
+                    panic!("attempt to subtract with overflow")
+                }
+                dif
+            }
+        }
+
+        impl AddAssign for $uD {
+            #[track_caller]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $uD {
+            #[track_caller]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl Not for $uD {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                Self {
+                    lo: !self.lo,
+                    hi: !self.hi,
+                }
+            }
+        }
+
+        impl_wide_int!(@bit_op $uD;
+            BitAnd, bitand, BitAndAssign, bitand_assign;
+            BitOr, bitor, BitOrAssign, bitor_assign;
+            BitXor, bitxor, BitXorAssign, bitxor_assign;
+        );
+
+        impl_wide_int!(@shift $uD; u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+        impl From<$uX> for $uD {
+            fn from(x: $uX) -> Self {
+                Self { lo: x, hi: $uX::MIN }
+            }
+        }
+
+        impl From<$iX> for $iD {
+            fn from(x: $iX) -> Self {
+                Self {
+                    lo: $half_cast_unsigned(x),
+                    // sign extension
+                    hi: if x.is_negative() {
+                        $half_cast_signed($uX::MAX)
+                    } else {
+                        $half_cast_signed($uX::MIN)
+                    },
+                }
+            }
+        }
+
+        // the `as` casts between these, their halves, and the narrower primitives, for
+        // `$crate::cast!`
+        $(
+            impl CastFrom<$uN> for $uD {
+                #[inline(always)]
+                fn cast_from(x: $uN) -> Self {
+                    Self::from(<$uX as CastFrom<$uN>>::cast_from(x))
+                }
+            }
+
+            impl CastFrom<$uN> for $iD {
+                #[inline(always)]
+                fn cast_from(x: $uN) -> Self {
+                    <$uD as CastFrom<$uN>>::cast_from(x).cast_signed()
+                }
+            }
+
+            impl CastFrom<$uD> for $uN {
+                #[inline(always)]
+                fn cast_from(x: $uD) -> Self {
+                    <$uN as CastFrom<$uX>>::cast_from(x.lo)
+                }
+            }
+
+            impl CastFrom<$iD> for $uN {
+                #[inline(always)]
+                fn cast_from(x: $iD) -> Self {
+                    <$uN as CastFrom<$uX>>::cast_from(x.lo)
+                }
+            }
+        )*
+        $(
+            impl CastFrom<$iN> for $iD {
+                #[inline(always)]
+                fn cast_from(x: $iN) -> Self {
+                    Self::from(<$iX as CastFrom<$iN>>::cast_from(x))
+                }
+            }
+
+            impl CastFrom<$iN> for $uD {
+                #[inline(always)]
+                fn cast_from(x: $iN) -> Self {
+                    <$iD as CastFrom<$iN>>::cast_from(x).cast_unsigned()
+                }
+            }
+
+            impl CastFrom<$uD> for $iN {
+                #[inline(always)]
+                fn cast_from(x: $uD) -> Self {
+                    <$iN as CastFrom<$uX>>::cast_from(x.lo)
+                }
+            }
+
+            impl CastFrom<$iD> for $iN {
+                #[inline(always)]
+                fn cast_from(x: $iD) -> Self {
+                    <$iN as CastFrom<$uX>>::cast_from(x.lo)
+                }
+            }
+        )*
+
+        impl CastFrom<$uX> for $uD {
+            #[inline(always)]
+            fn cast_from(x: $uX) -> Self {
+                Self::from(x)
+            }
+        }
+
+        impl CastFrom<$uX> for $iD {
+            #[inline(always)]
+            fn cast_from(x: $uX) -> Self {
+                $uD::from(x).cast_signed()
+            }
+        }
+
+        impl CastFrom<$iX> for $iD {
+            #[inline(always)]
+            fn cast_from(x: $iX) -> Self {
+                Self::from(x)
+            }
+        }
+
+        impl CastFrom<$iX> for $uD {
+            #[inline(always)]
+            fn cast_from(x: $iX) -> Self {
+                $iD::from(x).cast_unsigned()
+            }
+        }
+
+        impl CastFrom<$uD> for $uX {
+            #[inline(always)]
+            fn cast_from(x: $uD) -> Self {
+                x.lo
+            }
+        }
+
+        impl CastFrom<$iD> for $uX {
+            #[inline(always)]
+            fn cast_from(x: $iD) -> Self {
+                x.lo
+            }
+        }
+
+        impl CastFrom<$uD> for $iX {
+            #[inline(always)]
+            fn cast_from(x: $uD) -> Self {
+                $half_cast_signed(x.lo)
+            }
+        }
+
+        impl CastFrom<$iD> for $iX {
+            #[inline(always)]
+            fn cast_from(x: $iD) -> Self {
+                $half_cast_signed(x.lo)
+            }
+        }
+
+        impl CastFrom<$uD> for $uD {
+            #[inline(always)]
+            fn cast_from(x: $uD) -> Self {
+                x
+            }
+        }
+
+        impl CastFrom<$iD> for $uD {
+            #[inline(always)]
+            fn cast_from(x: $iD) -> Self {
+                x.cast_unsigned()
+            }
+        }
+
+        impl CastFrom<$uD> for $iD {
+            #[inline(always)]
+            fn cast_from(x: $uD) -> Self {
+                x.cast_signed()
+            }
+        }
+
+        impl CastFrom<$iD> for $iD {
+            #[inline(always)]
+            fn cast_from(x: $iD) -> Self {
+                x
+            }
+        }
+
+        impl Random for $uD {
+            fn random(rng: &mut Rng) -> Self {
+                Self {
+                    lo: rng.random(),
+                    hi: rng.random(),
+                }
+            }
+        }
+
+        impl Random for $iD {
+            fn random(rng: &mut Rng) -> Self {
+                Self {
+                    lo: rng.random(),
+                    hi: rng.random(),
+                }
+            }
+        }
+    };
+    (@bit_op $uD:ident; $($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident;)*) => {
+        $(
+            impl $op for $uD {
+                type Output = Self;
+
+                fn $fn(self, rhs: Self) -> Self {
+                    Self {
+                        lo: self.lo.$fn(rhs.lo),
+                        hi: self.hi.$fn(rhs.hi),
+                    }
+                }
+            }
+
+            impl $op_assign for $uD {
+                fn $fn_assign(&mut self, rhs: Self) {
+                    *self = (*self).$fn(rhs);
+                }
+            }
+        )*
+    };
+    // Like for the primitives, the shifts are implemented for every primitive integer type of shift
+    // amount, so that the type of a literal shift amount does not have to be specified
+    (@shift $uD:ident; $($rhs:ident)*) => {
+        $(
+            impl Shl<$rhs> for $uD {
+                type Output = Self;
+
+                #[track_caller]
+                fn shl(self, rhs: $rhs) -> Self {
+                    // `u128` shift amounts that do not fit in an `i128` become negative
+                    if cfg!(debug_assertions)
+                        && (((rhs as i128) < 0) || ((rhs as i128) >= (Self::BITS as i128)))
+                    {
+                        panic!("attempt to shift left with overflow")
+                    }
+                    self.wrapping_shl(rhs as u32)
+                }
+            }
+
+            impl Shr<$rhs> for $uD {
+                type Output = Self;
+
+                #[track_caller]
+                fn shr(self, rhs: $rhs) -> Self {
+                    if cfg!(debug_assertions)
+                        && (((rhs as i128) < 0) || ((rhs as i128) >= (Self::BITS as i128)))
+                    {
+                        panic!("attempt to shift right with overflow")
+                    }
+                    self.wrapping_shr(rhs as u32)
+                }
+            }
+
+            impl ShlAssign<$rhs> for $uD {
+                #[track_caller]
+                fn shl_assign(&mut self, rhs: $rhs) {
+                    *self = *self << rhs;
+                }
+            }
+
+            impl ShrAssign<$rhs> for $uD {
+                #[track_caller]
+                fn shr_assign(&mut self, rhs: $rhs) {
+                    *self = *self >> rhs;
+                }
+            }
+        )*
+    };
+}
+
+impl_wide_int!(
+    /// A 256 bit unsigned integer made of a least significant `lo` half and a most significant `hi`
+    /// half. The operations mirror those of the primitive integers, including the panics on
+    /// overflow with `debug_assertions`.
+    u256,
+    /// A 256 bit signed integer in two's complement, made of a least significant `lo` half and a
+    /// most significant `hi` half (which has the sign bit)
+    i256,
+    u128,
+    i128,
+    u128_widening_mul,
+    u128_cast_signed,
+    i128_cast_unsigned,
+    [u8 u16 u32 u64 usize],
+    [i8 i16 i32 i64 isize]
+);
+
+#[cfg(feature = "width-512")]
+impl_wide_int!(
+    /// A 512 bit unsigned integer made of a least significant `lo` half and a most significant `hi`
+    /// half. The operations mirror those of the primitive integers, including the panics on
+    /// overflow with `debug_assertions`.
+    u512,
+    /// A 512 bit signed integer in two's complement, made of a least significant `lo` half and a
+    /// most significant `hi` half (which has the sign bit)
+    i512,
+    u256,
+    i256,
+    u256::widening_mul,
+    u256::cast_signed,
+    i256::cast_unsigned,
+    [u8 u16 u32 u64 u128 usize],
+    [i8 i16 i32 i64 i128 isize]
+);
+
+#[cfg(feature = "bignum")]
+impl From<u256> for super::Uint<4> {
+    fn from(x: u256) -> Self {
+        super::Uint::from_limbs([
+            x.lo as u64,
+            (x.lo >> 64) as u64,
+            x.hi as u64,
+            (x.hi >> 64) as u64,
+        ])
+    }
+}
+
+#[cfg(feature = "bignum")]
+impl From<super::Uint<4>> for u256 {
+    fn from(x: super::Uint<4>) -> Self {
+        let limbs = x.limbs;
+        Self {
+            lo: ((limbs[1] as u128) << 64) | (limbs[0] as u128),
+            hi: ((limbs[3] as u128) << 64) | (limbs[2] as u128),
+        }
+    }
+}
+
+#[cfg(all(feature = "width-512", feature = "bignum"))]
+impl From<u512> for super::Uint<8> {
+    fn from(x: u512) -> Self {
+        let lo = super::Uint::<4>::from(x.lo).limbs;
+        let hi = super::Uint::<4>::from(x.hi).limbs;
+        super::Uint::from_limbs([lo[0], lo[1], lo[2], lo[3], hi[0], hi[1], hi[2], hi[3]])
+    }
+}
+
+#[cfg(all(feature = "width-512", feature = "bignum"))]
+impl From<super::Uint<8>> for u512 {
+    fn from(x: super::Uint<8>) -> Self {
+        let limbs = x.limbs;
+        Self {
+            lo: super::Uint::from_limbs([limbs[0], limbs[1], limbs[2], limbs[3]]).into(),
+            hi: super::Uint::from_limbs([limbs[4], limbs[5], limbs[6], limbs[7]]).into(),
+        }
+    }
+}
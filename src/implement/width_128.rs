@@ -0,0 +1,1308 @@
+#[allow(unused_imports)]
+use super::*;
+
+#[cfg(feature = "delegate")]
+impl_delegate!(
+    u128_div_rem_delegate,
+    i128_div_rem_delegate,
+    zero_div_fn,
+    u64_normalization_shift,
+    u64_by_u64_div_rem,
+    32,
+    u32,
+    u64,
+    u128,
+    i128,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_trifecta!(
+    u128_div_rem_trifecta,
+    i128_div_rem_trifecta,
+    zero_div_fn,
+    u64_by_u64_div_rem,
+    32,
+    u32,
+    u64,
+    u128,
+    i128,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+// For targets without a CLZ instruction, where LLVM's expansion of `leading_zeros` would otherwise
+// be in the hot path. `_delegate` does not need this, because it goes through `USE_LZ`.
+#[cfg(feature = "trifecta")]
+impl_trifecta!(
+    u128_div_rem_trifecta_soft_lz,
+    i128_div_rem_trifecta_soft_lz,
+    zero_div_fn,
+    u64_by_u64_div_rem,
+    leading_zeros = u128_leading_zeros,
+    32,
+    u32,
+    u64,
+    u128,
+    i128,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_asymmetric!(
+    u128_div_rem_asymmetric,
+    i128_div_rem_asymmetric,
+    zero_div_fn,
+    u64_by_u64_div_rem,
+    u128_by_u64_div_rem_unchecked,
+    32,
+    u32,
+    u64,
+    u128,
+    i128,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+// uses no integer division instructions at all
+#[cfg(all(
+    feature = "asymmetric",
+    feature = "float-reciprocal",
+    feature = "width-64"
+))]
+impl_asymmetric!(
+    u128_div_rem_asymmetric_float_reciprocal,
+    i128_div_rem_asymmetric_float_reciprocal,
+    zero_div_fn,
+    u64_div_rem_float_reciprocal,
+    u128_by_u64_div_rem_float_reciprocal,
+    32,
+    u32,
+    u64,
+    u128,
+    i128,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+
+// 32 bit x86 has no 128 by 64 bit division for `_asymmetric` to use, but its 64 by 32 bit `div`
+// instruction makes `u64_div_rem_asymmetric` fast, so that is used as the half division instead
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    feature = "asymmetric",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "x86"
+))]
+impl_trifecta!(
+    u128_div_rem_trifecta_asymmetric,
+    i128_div_rem_trifecta_asymmetric,
+    zero_div_fn,
+    u64_div_rem_asymmetric,
+    32,
+    u32,
+    u64,
+    u128,
+    i128,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+
+// ARMv7-M and ARMv8-M mainline CPUs (e.g. Cortex-M3, M4, and M7) have 32 bit hardware division but
+// no 64 bit division, so `u64_div_rem_delegate` and its 32 bit divisions are used as the half
+// division instead of a `u64` division that calls a software routine. Note that the `mclass` and
+// `v7` target features are only visible to `cfg` on nightly.
+#[cfg(all(
+    feature = "delegate",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "arm",
+    target_feature = "mclass",
+    target_feature = "v7"
+))]
+impl_trifecta!(
+    u128_div_rem_trifecta_delegate,
+    i128_div_rem_trifecta_delegate,
+    zero_div_fn,
+    u64_div_rem_delegate,
+    32,
+    u32,
+    u64,
+    u128,
+    i128,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+
+/// Computes the quotient and remainder of `duo` divided by `div` with the 128 by 64 bit hardware
+/// division instruction and returns them as a tuple.
+///
+/// # Panics
+///
+/// Panics if the quotient does not fit in a `u64` or if `div == 0`, instead of raising an
+/// exception like the hardware instruction would.
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "x86_64"))]
+#[track_caller]
+pub fn u128_by_u64_div_rem_hardware(duo: u128, div: u64) -> (u64, u64) {
+    if div == 0 {
+        zero_div_fn()
+    }
+    if div <= ((duo >> 64) as u64) {
+        panic!("quotient does not fit in a `u64`")
+    }
+    crate::unchecked_call!(u128_by_u64_div_rem_unchecked(duo, div))
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` with the shape of a signed 128 by
+/// 64 bit division instruction (such as `idiv` on x86_64), and returns them as a tuple. The
+/// quotient is truncated towards zero and the remainder has the sign of `duo`. Returns `None` in
+/// the cases where such an instruction raises an exception, which are when `div == 0` or when the
+/// quotient does not fit in an `i64`.
+pub fn i128_by_i64_div_rem_checked(duo: i128, div: i64) -> Option<(i64, i64)> {
+    if div == 0 {
+        return None;
+    }
+    let quo_neg = (duo < 0) != (div < 0);
+    let duo_abs = duo.unsigned_abs();
+    let div_abs = div.unsigned_abs();
+    if div_abs <= ((duo_abs >> 64) as u64) {
+        // the magnitude of the quotient does not even fit in a `u64`
+        return None;
+    }
+    let (quo_abs, rem_abs) =
+        crate::unchecked_call!(u128_by_u64_div_rem_unchecked(duo_abs, div_abs));
+    // `i64::MIN` is allowed for negative quotients
+    let quo = if quo_neg {
+        if quo_abs > i64::MIN.unsigned_abs() {
+            return None;
+        }
+        (quo_abs as i64).wrapping_neg()
+    } else {
+        if quo_abs > (i64::MAX as u64) {
+            return None;
+        }
+        quo_abs as i64
+    };
+    // `rem_abs < div_abs <= 2^63`, so this cannot overflow
+    let rem = if duo < 0 {
+        -(rem_abs as i64)
+    } else {
+        rem_abs as i64
+    };
+    Some((quo, rem))
+}
+
+/// A drop in replacement for the `_udiv128` intrinsic of MSVC, available on all targets. Divides
+/// the `u128` made of `high_dividend` and `low_dividend` by `divisor`, writes the remainder to
+/// `remainder`, and returns the quotient.
+///
+/// # Panics
+///
+/// Panics if `divisor == 0` or if the quotient does not fit in a `u64`, which are the cases where
+/// the intrinsic raises an exception.
+#[track_caller]
+pub fn _udiv128(high_dividend: u64, low_dividend: u64, divisor: u64, remainder: &mut u64) -> u64 {
+    if divisor == 0 {
+        zero_div_fn()
+    }
+    if divisor <= high_dividend {
+        panic!("quotient does not fit in a `u64`")
+    }
+    let duo = ((high_dividend as u128) << 64) | (low_dividend as u128);
+    let (quo, rem) = crate::unchecked_call!(u128_by_u64_div_rem_unchecked(duo, divisor));
+    *remainder = rem;
+    quo
+}
+
+/// A drop in replacement for the `_div128` intrinsic of MSVC, available on all targets. Divides
+/// the `i128` made of `high_dividend` and the bits of `low_dividend` by `divisor`, writes the
+/// remainder to `remainder`, and returns the quotient. The quotient is truncated towards zero and
+/// the remainder has the sign of the dividend.
+///
+/// # Panics
+///
+/// Panics if `divisor == 0` or if the quotient does not fit in an `i64`, which are the cases where
+/// the intrinsic raises an exception.
+#[track_caller]
+pub fn _div128(high_dividend: i64, low_dividend: i64, divisor: i64, remainder: &mut i64) -> i64 {
+    if divisor == 0 {
+        zero_div_fn()
+    }
+    let duo = ((high_dividend as i128) << 64) | (low_dividend as u64 as i128);
+    match i128_by_i64_div_rem_checked(duo, divisor) {
+        Some((quo, rem)) => {
+            *remainder = rem;
+            quo
+        }
+        None => panic!("quotient does not fit in an `i64`"),
+    }
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` with short division and returns
+/// them as a tuple. This is two 128 by 64 bit divisions (or one if the quotient fits in a `u64`),
+/// without the branches that the full `u128` divisions need to find out that `div` is small.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[track_caller]
+pub fn u128_div_rem_by_u64(duo: u128, div: u64) -> (u128, u64) {
+    if div == 0 {
+        zero_div_fn()
+    }
+    let duo_hi = (duo >> 64) as u64;
+    let (quo_hi, rem_hi) = if duo_hi < div {
+        (0, duo_hi)
+    } else {
+        u64_by_u64_div_rem(duo_hi, div)
+    };
+    // `rem_hi < div`, so the quotient fits in a `u64`
+    let (quo_lo, rem) = crate::unchecked_call!(u128_by_u64_div_rem_unchecked(
+        ((rem_hi as u128) << 64) | (duo as u64 as u128),
+        div
+    ));
+    (((quo_hi as u128) << 64) | (quo_lo as u128), rem)
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` with short division and returns
+/// them as a tuple, like `u128_div_rem_by_u64` but with the remainder as a `u32`. This is meant for
+/// dividing by small radixes, such as when parsing or printing numbers.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[track_caller]
+pub fn u128_div_rem_by_u32(duo: u128, div: u32) -> (u128, u32) {
+    let (quo, rem) = u128_div_rem_by_u64(duo, div as u64);
+    (quo, rem as u32)
+}
+
+// for the inner loops of multiple limb division, which keep the divisor normalized
+impl_normalized!(
+    u64_div_rem_normalized,
+    u128_by_u64_div_rem_normalized,
+    u64_by_u64_div_rem,
+    32,
+    u32,
+    u64,
+    u128,
+    inline
+);
+
+impl_fold!(u128_rem_pow2m1, u128_rem_pow2p1, 128, u128,);
+impl_significand_div!(
+    u128_div_significand,
+    zero_div_fn,
+    u128_by_u64_div_rem_unchecked,
+    64,
+    u64,
+    u128,
+);
+#[cfg(feature = "trifecta")]
+impl_root!(
+    u128_isqrt,
+    u128_icbrt,
+    u128_nth_root,
+    u128_div_rem_trifecta,
+    128,
+    u128,
+);
+
+// fractions
+#[cfg(feature = "trifecta")]
+impl_gcd!(u128_gcd, u128_div_rem_trifecta, u128,);
+#[cfg(feature = "trifecta")]
+impl_continued_fraction!(
+    u128_continued_fraction,
+    zero_div_fn,
+    u128_div_rem_trifecta,
+    u128,
+);
+#[cfg(feature = "trifecta")]
+impl_ratio!(
+    U128Ratio,
+    zero_div_fn,
+    u128_gcd,
+    u128_div_rem_trifecta,
+    64,
+    u64,
+    u128,
+);
+#[cfg(feature = "trifecta")]
+impl_defmt_ratio!(U128Ratio);
+
+#[cfg(feature = "trifecta")]
+impl_factor!(
+    u128_smallest_factor,
+    u128_factor_small_primes,
+    u128_div_rem_trifecta,
+    u128,
+);
+
+/// Divides `hi * 2^128` by `div` and returns a tuple of the quotient and the remainder, where
+/// `hi < div` so that the quotient fits in a `u128`. There is no 256 by 128 bit division, so this
+/// is the two digit long division of `impl_normalized` with `u64` digits.
+pub(super) const fn u128_shifted_div_rem(hi: u128, div: u128) -> (u128, u128) {
+    debug_assert!(hi < div);
+    // Estimates the next `u64` digit of the quotient of `rem << 64` divided by `div`, which is at
+    // most 2 too large because `div` is normalized, and corrects it. `rem < div` is maintained
+    // between the digits.
+    #[inline(always)]
+    const fn digit(rem: u128, div: u128, div_1: u128, div_0: u128) -> (u128, u128) {
+        let (mut quo, mut rem_hat) = u128_by_u128_div_rem(rem, div_1);
+        while ((quo >> 64) != 0) || (quo.wrapping_mul(div_0) > (rem_hat << 64)) {
+            quo -= 1;
+            rem_hat += div_1;
+            if (rem_hat >> 64) != 0 {
+                break;
+            }
+        }
+        // the true remainder is less than `div`, so the wrapping operations are exact
+        let rem = (rem << 64).wrapping_sub(quo.wrapping_mul(div));
+        (quo, rem)
+    }
+
+    // `hi < div`, so no set bits of `hi` are shifted out by the normalization shift of `div`
+    let shl = div.leading_zeros();
+    let div = div << shl;
+    let div_1 = div >> 64;
+    let div_0 = div as u64 as u128;
+    let (quo_1, rem) = digit(hi << shl, div, div_1, div_0);
+    let (quo_0, rem) = digit(rem, div, div_1, div_0);
+    ((quo_1 << 64) | quo_0, rem >> shl)
+}
+
+impl_precomputed_divisor!(
+    PrecomputedDivisor128,
+    u128_div_rem_slice,
+    zero_div_fn,
+    u128_shifted_div_rem,
+    u128_widening_mul,
+    128,
+    u128,
+);
+impl_defmt_invariant_rem!(PrecomputedDivisor128);
+impl_signed_precomputed_divisor!(
+    PrecomputedDivisorI128,
+    zero_div_fn,
+    u128_shifted_div_rem,
+    u128_widening_mul,
+    128,
+    u128,
+    i128,
+);
+impl_defmt_invariant_rem!(PrecomputedDivisorI128);
+
+impl_exact_div!(u128_exact_div, u128_is_multiple_of, zero_div_fn, 128, u128,);
+
+/// Computes `(a * b) % m` by binary multiplication with modular doublings and additions, since
+/// there is no 256 by 128 bit division.
+///
+/// # Panics
+///
+/// Panics if `m == 0`.
+#[cfg(feature = "trifecta")]
+#[track_caller]
+pub fn u128_mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    if m == 0 {
+        zero_div_fn()
+    }
+    let a = u128_div_rem_trifecta(a, m).1;
+    let b = u128_div_rem_trifecta(b, m).1;
+    // `(x + y) % m` for `x, y < m` without overflowing
+    let add_mod = |x: u128, y: u128| if x >= m - y { x - (m - y) } else { x + y };
+    let mut res = 0;
+    let mut i = 128 - b.leading_zeros();
+    while i > 0 {
+        i -= 1;
+        res = add_mod(res, res);
+        if ((b >> i) & 1) != 0 {
+            res = add_mod(res, a);
+        }
+    }
+    res
+}
+
+#[cfg(feature = "trifecta")]
+impl_miller_rabin!(
+    u128_pow_mod,
+    u128_is_probable_prime,
+    u128_is_prime,
+    u128_mul_mod,
+    u128,
+);
+#[cfg(feature = "trifecta")]
+impl_mod_sqrt!(u128_mod_sqrt, u128_pow_mod, u128_mul_mod, u128,);
+#[cfg(feature = "trifecta")]
+impl_mod_inv!(
+    u128_mod_inv,
+    u128_batch_invert,
+    u128_div_rem_trifecta,
+    u128_mul_mod,
+    u128,
+);
+
+// saturating signed division with the algorithms
+#[cfg(feature = "delegate")]
+impl_saturating_div_rem!(
+    i128_saturating_div_rem_delegate,
+    i128_saturating_div_delegate,
+    i128_div_rem_delegate,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_saturating_div_rem!(
+    i128_saturating_div_rem_trifecta,
+    i128_saturating_div_trifecta,
+    i128_div_rem_trifecta,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_saturating_div_rem!(
+    i128_saturating_div_rem_trifecta_soft_lz,
+    i128_saturating_div_trifecta_soft_lz,
+    i128_div_rem_trifecta_soft_lz,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_saturating_div_rem!(
+    i128_saturating_div_rem_asymmetric,
+    i128_saturating_div_asymmetric,
+    i128_div_rem_asymmetric,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(all(
+    feature = "asymmetric",
+    feature = "float-reciprocal",
+    feature = "width-64"
+))]
+impl_saturating_div_rem!(
+    i128_saturating_div_rem_asymmetric_float_reciprocal,
+    i128_saturating_div_asymmetric_float_reciprocal,
+    i128_div_rem_asymmetric_float_reciprocal,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    feature = "asymmetric",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "x86"
+))]
+impl_saturating_div_rem!(
+    i128_saturating_div_rem_trifecta_asymmetric,
+    i128_saturating_div_trifecta_asymmetric,
+    i128_div_rem_trifecta_asymmetric,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(all(
+    feature = "delegate",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "arm",
+    target_feature = "mclass",
+    target_feature = "v7"
+))]
+impl_saturating_div_rem!(
+    i128_saturating_div_rem_trifecta_delegate,
+    i128_saturating_div_trifecta_delegate,
+    i128_div_rem_trifecta_delegate,
+    i128,
+    inline,
+    track_caller
+);
+
+// fallible division with the algorithms
+#[cfg(feature = "delegate")]
+impl_try_div_rem!(
+    u128_try_div_rem_delegate,
+    i128_try_div_rem_delegate,
+    u128_div_rem_delegate,
+    i128_div_rem_delegate,
+    u128,
+    i128,
+    inline;
+    inline
+);
+#[cfg(feature = "trifecta")]
+impl_try_div_rem!(
+    u128_try_div_rem_trifecta,
+    i128_try_div_rem_trifecta,
+    u128_div_rem_trifecta,
+    i128_div_rem_trifecta,
+    u128,
+    i128,
+    inline;
+    inline
+);
+#[cfg(feature = "trifecta")]
+impl_try_div_rem!(
+    u128_try_div_rem_trifecta_soft_lz,
+    i128_try_div_rem_trifecta_soft_lz,
+    u128_div_rem_trifecta_soft_lz,
+    i128_div_rem_trifecta_soft_lz,
+    u128,
+    i128,
+    inline;
+    inline
+);
+#[cfg(feature = "asymmetric")]
+impl_try_div_rem!(
+    u128_try_div_rem_asymmetric,
+    i128_try_div_rem_asymmetric,
+    u128_div_rem_asymmetric,
+    i128_div_rem_asymmetric,
+    u128,
+    i128,
+    inline;
+    inline
+);
+#[cfg(all(
+    feature = "asymmetric",
+    feature = "float-reciprocal",
+    feature = "width-64"
+))]
+impl_try_div_rem!(
+    u128_try_div_rem_asymmetric_float_reciprocal,
+    i128_try_div_rem_asymmetric_float_reciprocal,
+    u128_div_rem_asymmetric_float_reciprocal,
+    i128_div_rem_asymmetric_float_reciprocal,
+    u128,
+    i128,
+    inline;
+    inline
+);
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    feature = "asymmetric",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "x86"
+))]
+impl_try_div_rem!(
+    u128_try_div_rem_trifecta_asymmetric,
+    i128_try_div_rem_trifecta_asymmetric,
+    u128_div_rem_trifecta_asymmetric,
+    i128_div_rem_trifecta_asymmetric,
+    u128,
+    i128,
+    inline;
+    inline
+);
+#[cfg(all(
+    feature = "delegate",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "arm",
+    target_feature = "mclass",
+    target_feature = "v7"
+))]
+impl_try_div_rem!(
+    u128_try_div_rem_trifecta_delegate,
+    i128_try_div_rem_trifecta_delegate,
+    u128_div_rem_trifecta_delegate,
+    i128_div_rem_trifecta_delegate,
+    u128,
+    i128,
+    inline;
+    inline
+);
+
+// The quotient-only versions of the algorithms. These instantiate the algorithms again with
+// `inline(always)`, so that the instructions only needed for the remainder are eliminated.
+mod inliner {
+    #[allow(unused_imports)]
+    use super::*;
+
+    mod imp {
+        #[allow(unused_imports)]
+        use super::*;
+
+        #[cfg(feature = "delegate")]
+        impl_delegate!(
+            u128_div_rem_delegate_inline,
+            i128_div_rem_delegate_inline,
+            zero_div_fn,
+            u64_normalization_shift,
+            u64_by_u64_div_rem,
+            32,
+            u32,
+            u64,
+            u128,
+            i128,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(feature = "trifecta")]
+        impl_trifecta!(
+            u128_div_rem_trifecta_inline,
+            i128_div_rem_trifecta_inline,
+            zero_div_fn,
+            u64_by_u64_div_rem,
+            32,
+            u32,
+            u64,
+            u128,
+            i128,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(feature = "trifecta")]
+        impl_trifecta!(
+            u128_div_rem_trifecta_soft_lz_inline,
+            i128_div_rem_trifecta_soft_lz_inline,
+            zero_div_fn,
+            u64_by_u64_div_rem,
+            leading_zeros = u128_leading_zeros,
+            32,
+            u32,
+            u64,
+            u128,
+            i128,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(feature = "asymmetric")]
+        impl_asymmetric!(
+            u128_div_rem_asymmetric_inline,
+            i128_div_rem_asymmetric_inline,
+            zero_div_fn,
+            u64_by_u64_div_rem,
+            u128_by_u64_div_rem_unchecked,
+            32,
+            u32,
+            u64,
+            u128,
+            i128,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(all(
+            feature = "asymmetric",
+            feature = "float-reciprocal",
+            feature = "width-64"
+        ))]
+        impl_asymmetric!(
+            u128_div_rem_asymmetric_float_reciprocal_inline,
+            i128_div_rem_asymmetric_float_reciprocal_inline,
+            zero_div_fn,
+            u64_div_rem_float_reciprocal,
+            u128_by_u64_div_rem_float_reciprocal,
+            32,
+            u32,
+            u64,
+            u128,
+            i128,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(all(
+            feature = "asm",
+            not(feature = "no-unsafe"),
+            feature = "asymmetric",
+            feature = "trifecta",
+            feature = "width-64",
+            target_arch = "x86"
+        ))]
+        impl_trifecta!(
+            u128_div_rem_trifecta_asymmetric_inline,
+            i128_div_rem_trifecta_asymmetric_inline,
+            zero_div_fn,
+            u64_div_rem_asymmetric,
+            32,
+            u32,
+            u64,
+            u128,
+            i128,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(all(
+            feature = "delegate",
+            feature = "trifecta",
+            feature = "width-64",
+            target_arch = "arm",
+            target_feature = "mclass",
+            target_feature = "v7"
+        ))]
+        impl_trifecta!(
+            u128_div_rem_trifecta_delegate_inline,
+            i128_div_rem_trifecta_delegate_inline,
+            zero_div_fn,
+            u64_div_rem_delegate,
+            32,
+            u32,
+            u64,
+            u128,
+            i128,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+    }
+    #[allow(unused_imports)]
+    use imp::*;
+
+    #[cfg(feature = "delegate")]
+    impl_div!(
+        u128_div_delegate,
+        i128_div_delegate,
+        u128_div_rem_delegate_inline,
+        i128_div_rem_delegate_inline,
+        u128,
+        i128,
+        track_caller;
+        track_caller
+    );
+    #[cfg(feature = "trifecta")]
+    impl_div!(
+        u128_div_trifecta,
+        i128_div_trifecta,
+        u128_div_rem_trifecta_inline,
+        i128_div_rem_trifecta_inline,
+        u128,
+        i128,
+        track_caller;
+        track_caller
+    );
+    #[cfg(feature = "trifecta")]
+    impl_div!(
+        u128_div_trifecta_soft_lz,
+        i128_div_trifecta_soft_lz,
+        u128_div_rem_trifecta_soft_lz_inline,
+        i128_div_rem_trifecta_soft_lz_inline,
+        u128,
+        i128,
+        track_caller;
+        track_caller
+    );
+    #[cfg(feature = "asymmetric")]
+    impl_div!(
+        u128_div_asymmetric,
+        i128_div_asymmetric,
+        u128_div_rem_asymmetric_inline,
+        i128_div_rem_asymmetric_inline,
+        u128,
+        i128,
+        track_caller;
+        track_caller
+    );
+    #[cfg(all(
+        feature = "asymmetric",
+        feature = "float-reciprocal",
+        feature = "width-64"
+    ))]
+    impl_div!(
+        u128_div_asymmetric_float_reciprocal,
+        i128_div_asymmetric_float_reciprocal,
+        u128_div_rem_asymmetric_float_reciprocal_inline,
+        i128_div_rem_asymmetric_float_reciprocal_inline,
+        u128,
+        i128,
+        track_caller;
+        track_caller
+    );
+    #[cfg(all(
+        feature = "asm",
+        not(feature = "no-unsafe"),
+        feature = "asymmetric",
+        feature = "trifecta",
+        feature = "width-64",
+        target_arch = "x86"
+    ))]
+    impl_div!(
+        u128_div_trifecta_asymmetric,
+        i128_div_trifecta_asymmetric,
+        u128_div_rem_trifecta_asymmetric_inline,
+        i128_div_rem_trifecta_asymmetric_inline,
+        u128,
+        i128,
+        track_caller;
+        track_caller
+    );
+    #[cfg(all(
+        feature = "delegate",
+        feature = "trifecta",
+        feature = "width-64",
+        target_arch = "arm",
+        target_feature = "mclass",
+        target_feature = "v7"
+    ))]
+    impl_div!(
+        u128_div_trifecta_delegate,
+        i128_div_trifecta_delegate,
+        u128_div_rem_trifecta_delegate_inline,
+        i128_div_rem_trifecta_delegate_inline,
+        u128,
+        i128,
+        track_caller;
+        track_caller
+    );
+}
+
+#[allow(unused_imports)]
+pub use inliner::*;
+
+/// Computes the mean of `x` and returns the quotient and remainder of the sum divided by
+/// `x.len()` as a tuple. The sum is accumulated in a `u128`, so it cannot overflow for any slice
+/// that fits in memory.
+///
+/// # Panics
+///
+/// Panics if `x` is empty.
+#[cfg(feature = "asymmetric")]
+#[track_caller]
+pub fn u64_mean(x: &[u64]) -> (u64, u64) {
+    let mut sum: u128 = 0;
+    let mut i = 0;
+    while i < x.len() {
+        sum += x[i] as u128;
+        i += 1;
+    }
+    // the mean is no larger than the maximum element, so the quotient fits in a `u64`
+    let (quo, rem) = u128_div_rem_asymmetric(sum, x.len() as u128);
+    (quo as u64, rem as u64)
+}
+
+/// Computes the mean of `x` and returns the quotient and remainder of the sum divided by
+/// `x.len()` as a tuple. The sum is accumulated in 256 bits, so it cannot overflow for any slice
+/// that fits in memory.
+///
+/// # Panics
+///
+/// Panics if `x` is empty.
+#[track_caller]
+pub fn u128_mean(x: &[u128]) -> (u128, u128) {
+    if x.is_empty() {
+        zero_div_fn()
+    }
+    // the high part of the 256 bit sum counts the carries out of `lo`
+    let mut hi: u64 = 0;
+    let mut lo: u128 = 0;
+    let mut i = 0;
+    while i < x.len() {
+        let (tmp, carry) = lo.overflowing_add(x[i]);
+        lo = tmp;
+        hi += carry as u64;
+        i += 1;
+    }
+    let len = x.len() as u64;
+    // There are fewer carries than elements, so `hi < len` and each step of the short division
+    // has a quotient that fits in a `u64`.
+    let (quo_hi, rem) = crate::unchecked_call!(u128_by_u64_div_rem_unchecked(
+        ((hi as u128) << 64) | (lo >> 64),
+        len
+    ));
+    let (quo_lo, rem) = crate::unchecked_call!(u128_by_u64_div_rem_unchecked(
+        ((rem as u128) << 64) | (lo as u64 as u128),
+        len
+    ));
+    (((quo_hi as u128) << 64) | (quo_lo as u128), rem as u128)
+}
+
+// the `Option` returning versions of the algorithms, for fallible code paths
+#[cfg(feature = "delegate")]
+impl_checked_div_rem!(
+    u128_checked_div_rem_delegate,
+    i128_checked_div_rem_delegate,
+    u128_div_rem_delegate,
+    i128_div_rem_delegate,
+    u128,
+    i128,
+    inline;
+    inline
+);
+#[cfg(feature = "trifecta")]
+impl_checked_div_rem!(
+    u128_checked_div_rem_trifecta,
+    i128_checked_div_rem_trifecta,
+    u128_div_rem_trifecta,
+    i128_div_rem_trifecta,
+    u128,
+    i128,
+    inline;
+    inline
+);
+#[cfg(feature = "trifecta")]
+impl_checked_div_rem!(
+    u128_checked_div_rem_trifecta_soft_lz,
+    i128_checked_div_rem_trifecta_soft_lz,
+    u128_div_rem_trifecta_soft_lz,
+    i128_div_rem_trifecta_soft_lz,
+    u128,
+    i128,
+    inline;
+    inline
+);
+#[cfg(feature = "asymmetric")]
+impl_checked_div_rem!(
+    u128_checked_div_rem_asymmetric,
+    i128_checked_div_rem_asymmetric,
+    u128_div_rem_asymmetric,
+    i128_div_rem_asymmetric,
+    u128,
+    i128,
+    inline;
+    inline
+);
+#[cfg(all(
+    feature = "asymmetric",
+    feature = "float-reciprocal",
+    feature = "width-64"
+))]
+impl_checked_div_rem!(
+    u128_checked_div_rem_asymmetric_float_reciprocal,
+    i128_checked_div_rem_asymmetric_float_reciprocal,
+    u128_div_rem_asymmetric_float_reciprocal,
+    i128_div_rem_asymmetric_float_reciprocal,
+    u128,
+    i128,
+    inline;
+    inline
+);
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    feature = "asymmetric",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "x86"
+))]
+impl_checked_div_rem!(
+    u128_checked_div_rem_trifecta_asymmetric,
+    i128_checked_div_rem_trifecta_asymmetric,
+    u128_div_rem_trifecta_asymmetric,
+    i128_div_rem_trifecta_asymmetric,
+    u128,
+    i128,
+    inline;
+    inline
+);
+#[cfg(all(
+    feature = "delegate",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "arm",
+    target_feature = "mclass",
+    target_feature = "v7"
+))]
+impl_checked_div_rem!(
+    u128_checked_div_rem_trifecta_delegate,
+    i128_checked_div_rem_trifecta_delegate,
+    u128_div_rem_trifecta_delegate,
+    i128_div_rem_trifecta_delegate,
+    u128,
+    i128,
+    inline;
+    inline
+);
+
+// Euclidean division with the algorithms, where the remainder is never negative
+#[cfg(feature = "delegate")]
+impl_euclid_div_rem!(
+    i128_div_rem_euclid_delegate,
+    u128_div_rem_delegate,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_euclid_div_rem!(
+    i128_div_rem_euclid_trifecta,
+    u128_div_rem_trifecta,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_euclid_div_rem!(
+    i128_div_rem_euclid_trifecta_soft_lz,
+    u128_div_rem_trifecta_soft_lz,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_euclid_div_rem!(
+    i128_div_rem_euclid_asymmetric,
+    u128_div_rem_asymmetric,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(all(
+    feature = "asymmetric",
+    feature = "float-reciprocal",
+    feature = "width-64"
+))]
+impl_euclid_div_rem!(
+    i128_div_rem_euclid_asymmetric_float_reciprocal,
+    u128_div_rem_asymmetric_float_reciprocal,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    feature = "asymmetric",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "x86"
+))]
+impl_euclid_div_rem!(
+    i128_div_rem_euclid_trifecta_asymmetric,
+    u128_div_rem_trifecta_asymmetric,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(all(
+    feature = "delegate",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "arm",
+    target_feature = "mclass",
+    target_feature = "v7"
+))]
+impl_euclid_div_rem!(
+    i128_div_rem_euclid_trifecta_delegate,
+    u128_div_rem_trifecta_delegate,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+
+// floored division with the algorithms, where the remainder has the sign of the divisor
+#[cfg(feature = "delegate")]
+impl_floor_div_rem!(
+    i128_div_rem_floor_delegate,
+    u128_div_rem_delegate,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_floor_div_rem!(
+    i128_div_rem_floor_trifecta,
+    u128_div_rem_trifecta,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_floor_div_rem!(
+    i128_div_rem_floor_trifecta_soft_lz,
+    u128_div_rem_trifecta_soft_lz,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_floor_div_rem!(
+    i128_div_rem_floor_asymmetric,
+    u128_div_rem_asymmetric,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(all(
+    feature = "asymmetric",
+    feature = "float-reciprocal",
+    feature = "width-64"
+))]
+impl_floor_div_rem!(
+    i128_div_rem_floor_asymmetric_float_reciprocal,
+    u128_div_rem_asymmetric_float_reciprocal,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    feature = "asymmetric",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "x86"
+))]
+impl_floor_div_rem!(
+    i128_div_rem_floor_trifecta_asymmetric,
+    u128_div_rem_trifecta_asymmetric,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(all(
+    feature = "delegate",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "arm",
+    target_feature = "mclass",
+    target_feature = "v7"
+))]
+impl_floor_div_rem!(
+    i128_div_rem_floor_trifecta_delegate,
+    u128_div_rem_trifecta_delegate,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+
+// ceiling division with the algorithms
+#[cfg(feature = "delegate")]
+impl_ceil_div_rem!(
+    u128_div_rem_ceil_delegate,
+    u128_div_ceil_delegate,
+    i128_div_rem_ceil_delegate,
+    i128_div_ceil_delegate,
+    u128_div_rem_delegate,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_ceil_div_rem!(
+    u128_div_rem_ceil_trifecta,
+    u128_div_ceil_trifecta,
+    i128_div_rem_ceil_trifecta,
+    i128_div_ceil_trifecta,
+    u128_div_rem_trifecta,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_ceil_div_rem!(
+    u128_div_rem_ceil_trifecta_soft_lz,
+    u128_div_ceil_trifecta_soft_lz,
+    i128_div_rem_ceil_trifecta_soft_lz,
+    i128_div_ceil_trifecta_soft_lz,
+    u128_div_rem_trifecta_soft_lz,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_ceil_div_rem!(
+    u128_div_rem_ceil_asymmetric,
+    u128_div_ceil_asymmetric,
+    i128_div_rem_ceil_asymmetric,
+    i128_div_ceil_asymmetric,
+    u128_div_rem_asymmetric,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(all(
+    feature = "asymmetric",
+    feature = "float-reciprocal",
+    feature = "width-64"
+))]
+impl_ceil_div_rem!(
+    u128_div_rem_ceil_asymmetric_float_reciprocal,
+    u128_div_ceil_asymmetric_float_reciprocal,
+    i128_div_rem_ceil_asymmetric_float_reciprocal,
+    i128_div_ceil_asymmetric_float_reciprocal,
+    u128_div_rem_asymmetric_float_reciprocal,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    feature = "asymmetric",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "x86"
+))]
+impl_ceil_div_rem!(
+    u128_div_rem_ceil_trifecta_asymmetric,
+    u128_div_ceil_trifecta_asymmetric,
+    i128_div_rem_ceil_trifecta_asymmetric,
+    i128_div_ceil_trifecta_asymmetric,
+    u128_div_rem_trifecta_asymmetric,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
+#[cfg(all(
+    feature = "delegate",
+    feature = "trifecta",
+    feature = "width-64",
+    target_arch = "arm",
+    target_feature = "mclass",
+    target_feature = "v7"
+))]
+impl_ceil_div_rem!(
+    u128_div_rem_ceil_trifecta_delegate,
+    u128_div_ceil_trifecta_delegate,
+    i128_div_rem_ceil_trifecta_delegate,
+    i128_div_ceil_trifecta_delegate,
+    u128_div_rem_trifecta_delegate,
+    u128,
+    i128,
+    inline,
+    track_caller
+);
@@ -0,0 +1,355 @@
+#[allow(unused_imports)]
+use super::*;
+
+#[cfg(feature = "binary-long")]
+impl_binary_long!(
+    u16_div_rem_binary_long,
+    i16_div_rem_binary_long,
+    zero_div_fn,
+    u16_normalization_shift,
+    16,
+    u16,
+    i16,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+
+/// Computes the quotient and remainder of `duo` divided by `div` with a table of reciprocals, and
+/// returns them as a tuple. Divisors that fit in a `u8` index the table directly. Larger divisors
+/// have quotients that fit in a `u8`, which are estimated with the reciprocal of the upper 8 bits
+/// of the divisor.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[cfg(feature = "table")]
+#[inline(never)]
+#[track_caller]
+pub fn u16_div_rem_table(duo: u16, div: u16) -> (u16, u16) {
+    if div < 256 {
+        if div == 0 {
+            zero_div_fn()
+        }
+        return u16_by_table_div_rem(duo, div);
+    }
+    // The divisor is shifted down to 8 bits and rounded up, so the quotient of the shifted `duo`
+    // divided by it can only be too small. Because the rounding changes the divisor by less than
+    // 1/128 of it, this is at most 2 too small.
+    let shift = 8 - u16_leading_zeros(div);
+    let (mut quo, _) = u16_by_table_div_rem(duo >> shift, (div >> shift) + 1);
+    let mut rem = duo - (quo * div);
+    while rem >= div {
+        quo += 1;
+        rem -= div;
+    }
+    (quo, rem)
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` and returns them as a tuple.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[cfg(feature = "table")]
+#[inline]
+#[track_caller]
+pub fn i16_div_rem_table(duo: i16, div: i16) -> (i16, i16) {
+    let (quo, rem) = u16_div_rem_table(duo.unsigned_abs(), div.unsigned_abs());
+    let mut quo = quo as i16;
+    let mut rem = rem as i16;
+    if duo < 0 {
+        rem = rem.wrapping_neg();
+    }
+    if (duo < 0) != (div < 0) {
+        quo = quo.wrapping_neg();
+    }
+    (quo, rem)
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` with restoring division loops
+/// written in AVR assembly, and returns them as a tuple. Divisors that fit in a `u8` use two 16 by
+/// 8 bit steps that only loop for 8 bits each.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+#[inline(never)]
+#[track_caller]
+pub fn u16_div_rem_avr(duo: u16, div: u16) -> (u16, u16) {
+    if div == 0 {
+        zero_div_fn()
+    }
+    if (div >> 8) == 0 {
+        // short division, where each step has a remainder less than `div` in its upper half
+        let div = div as u8;
+        let (quo_hi, rem) = unsafe { u16_by_u8_div_rem(duo >> 8, div) };
+        let (quo_lo, rem) = unsafe { u16_by_u8_div_rem(((rem as u16) << 8) | (duo & 0xff), div) };
+        return (((quo_hi as u16) << 8) | (quo_lo as u16), rem as u16);
+    }
+    let quo: u16;
+    let rem: u16;
+    unsafe {
+        // the same loop as `u8_div_rem_avr` with register pairs
+        core::arch::asm!(
+            "sub {rem:l}, {rem:l}",
+            "sub {rem:h}, {rem:h}",
+            "ldi {cnt}, 17",
+            "rjmp 2f",
+            "1:",
+            "rol {rem:l}",
+            "rol {rem:h}",
+            "cp {rem:l}, {div:l}",
+            "cpc {rem:h}, {div:h}",
+            "brcs 2f",
+            "sub {rem:l}, {div:l}",
+            "sbc {rem:h}, {div:h}",
+            "2:",
+            "rol {duo:l}",
+            "rol {duo:h}",
+            "dec {cnt}",
+            "brne 1b",
+            "com {duo:l}",
+            "com {duo:h}",
+            duo = inout(reg_pair) duo => quo,
+            div = in(reg_pair) div,
+            rem = out(reg_pair) rem,
+            cnt = out(reg_upper) _,
+            options(pure, nomem, nostack)
+        );
+    }
+    (quo, rem)
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` and returns them as a tuple.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+#[inline]
+#[track_caller]
+pub fn i16_div_rem_avr(duo: i16, div: i16) -> (i16, i16) {
+    let (quo, rem) = u16_div_rem_avr(duo.unsigned_abs(), div.unsigned_abs());
+    let mut quo = quo as i16;
+    let mut rem = rem as i16;
+    if duo < 0 {
+        rem = rem.wrapping_neg();
+    }
+    if (duo < 0) != (div < 0) {
+        quo = quo.wrapping_neg();
+    }
+    (quo, rem)
+}
+
+// the `Option` returning versions of the algorithms, for fallible code paths
+#[cfg(feature = "binary-long")]
+impl_checked_div_rem!(
+    u16_checked_div_rem_binary_long,
+    i16_checked_div_rem_binary_long,
+    u16_div_rem_binary_long,
+    i16_div_rem_binary_long,
+    u16,
+    i16,
+    inline;
+    inline
+);
+#[cfg(feature = "table")]
+impl_checked_div_rem!(
+    u16_checked_div_rem_table,
+    i16_checked_div_rem_table,
+    u16_div_rem_table,
+    i16_div_rem_table,
+    u16,
+    i16,
+    inline;
+    inline
+);
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+impl_checked_div_rem!(
+    u16_checked_div_rem_avr,
+    i16_checked_div_rem_avr,
+    u16_div_rem_avr,
+    i16_div_rem_avr,
+    u16,
+    i16,
+    inline;
+    inline
+);
+
+// floored division with the algorithms, where the remainder has the sign of the divisor
+#[cfg(feature = "binary-long")]
+impl_floor_div_rem!(
+    i16_div_rem_floor_binary_long,
+    u16_div_rem_binary_long,
+    u16,
+    i16,
+    inline,
+    track_caller
+);
+#[cfg(feature = "table")]
+impl_floor_div_rem!(
+    i16_div_rem_floor_table,
+    u16_div_rem_table,
+    u16,
+    i16,
+    inline,
+    track_caller
+);
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+impl_floor_div_rem!(
+    i16_div_rem_floor_avr,
+    u16_div_rem_avr,
+    u16,
+    i16,
+    inline,
+    track_caller
+);
+
+// ceiling division with the algorithms
+#[cfg(feature = "binary-long")]
+impl_ceil_div_rem!(
+    u16_div_rem_ceil_binary_long,
+    u16_div_ceil_binary_long,
+    i16_div_rem_ceil_binary_long,
+    i16_div_ceil_binary_long,
+    u16_div_rem_binary_long,
+    u16,
+    i16,
+    inline,
+    track_caller
+);
+#[cfg(feature = "table")]
+impl_ceil_div_rem!(
+    u16_div_rem_ceil_table,
+    u16_div_ceil_table,
+    i16_div_rem_ceil_table,
+    i16_div_ceil_table,
+    u16_div_rem_table,
+    u16,
+    i16,
+    inline,
+    track_caller
+);
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+impl_ceil_div_rem!(
+    u16_div_rem_ceil_avr,
+    u16_div_ceil_avr,
+    i16_div_rem_ceil_avr,
+    i16_div_ceil_avr,
+    u16_div_rem_avr,
+    u16,
+    i16,
+    inline,
+    track_caller
+);
+
+// saturating signed division with the algorithms
+#[cfg(feature = "binary-long")]
+impl_saturating_div_rem!(
+    i16_saturating_div_rem_binary_long,
+    i16_saturating_div_binary_long,
+    i16_div_rem_binary_long,
+    i16,
+    inline,
+    track_caller
+);
+#[cfg(feature = "table")]
+impl_saturating_div_rem!(
+    i16_saturating_div_rem_table,
+    i16_saturating_div_table,
+    i16_div_rem_table,
+    i16,
+    inline,
+    track_caller
+);
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+impl_saturating_div_rem!(
+    i16_saturating_div_rem_avr,
+    i16_saturating_div_avr,
+    i16_div_rem_avr,
+    i16,
+    inline,
+    track_caller
+);
+
+// fallible division with the algorithms
+#[cfg(feature = "binary-long")]
+impl_try_div_rem!(
+    u16_try_div_rem_binary_long,
+    i16_try_div_rem_binary_long,
+    u16_div_rem_binary_long,
+    i16_div_rem_binary_long,
+    u16,
+    i16,
+    inline;
+    inline
+);
+#[cfg(feature = "table")]
+impl_try_div_rem!(
+    u16_try_div_rem_table,
+    i16_try_div_rem_table,
+    u16_div_rem_table,
+    i16_div_rem_table,
+    u16,
+    i16,
+    inline;
+    inline
+);
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+impl_try_div_rem!(
+    u16_try_div_rem_avr,
+    i16_try_div_rem_avr,
+    u16_div_rem_avr,
+    i16_div_rem_avr,
+    u16,
+    i16,
+    inline;
+    inline
+);
+
+// The quotient-only versions of the algorithms. These instantiate the algorithms again with
+// `inline(always)`, so that the instructions only needed for the remainder are eliminated.
+mod inliner {
+    #[allow(unused_imports)]
+    use super::*;
+
+    mod imp {
+        #[allow(unused_imports)]
+        use super::*;
+
+        #[cfg(feature = "binary-long")]
+        impl_binary_long!(
+            u16_div_rem_binary_long_inline,
+            i16_div_rem_binary_long_inline,
+            zero_div_fn,
+            u16_normalization_shift,
+            16,
+            u16,
+            i16,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+    }
+    #[allow(unused_imports)]
+    use imp::*;
+
+    #[cfg(feature = "binary-long")]
+    impl_div!(
+        u16_div_binary_long,
+        i16_div_binary_long,
+        u16_div_rem_binary_long_inline,
+        i16_div_rem_binary_long_inline,
+        u16,
+        i16,
+        track_caller;
+        track_caller
+    );
+}
+
+#[allow(unused_imports)]
+pub use inliner::*;
@@ -0,0 +1,354 @@
+#[allow(unused_imports)]
+use super::*;
+
+// The `u128` divisions are the half divisions here, like the `u64` divisions are for the `u128`
+// functions. There is no 256 by 128 bit division instruction, so `_asymmetric` uses the two digit
+// long division of `impl_normalized` instead.
+#[cfg(feature = "asymmetric")]
+impl_normalized!(
+    u128_div_rem_normalized,
+    u256_by_u128_div_rem_normalized,
+    u128_by_u128_div_rem,
+    64,
+    u64,
+    u128,
+    u256,
+    inline
+);
+
+#[cfg(feature = "asymmetric")]
+crate::unchecked_fn! {
+    /// Divides `duo` by `div` and returns a tuple of the quotient and the remainder.
+    ///
+    /// # Safety
+    ///
+    /// The quotient has to fit in a `u128` (`duo.hi < div`), otherwise the results are wrong.
+    fn u256_by_u128_div_rem(duo: u256, div: u128) -> (u128, u128) {
+        debug_assert!(duo.hi < div);
+        // `duo < div * 2^128`, so no set bits of `duo` are shifted out by the normalization shift
+        // of `div`
+        let shl = div.leading_zeros();
+        let (quo, rem) =
+            crate::unchecked_call!(u256_by_u128_div_rem_normalized(duo << shl, div << shl));
+        (quo, rem >> shl)
+    }
+}
+
+#[cfg(feature = "delegate")]
+impl_delegate!(
+    u256_div_rem_delegate,
+    i256_div_rem_delegate,
+    zero_div_fn,
+    u128_normalization_shift,
+    u128_by_u128_div_rem,
+    64,
+    u64,
+    u128,
+    u256,
+    i256,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_trifecta!(
+    u256_div_rem_trifecta,
+    i256_div_rem_trifecta,
+    zero_div_fn,
+    u128_by_u128_div_rem,
+    64,
+    u64,
+    u128,
+    u256,
+    i256,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_asymmetric!(
+    u256_div_rem_asymmetric,
+    i256_div_rem_asymmetric,
+    zero_div_fn,
+    u128_by_u128_div_rem,
+    u256_by_u128_div_rem,
+    64,
+    u64,
+    u128,
+    u256,
+    i256,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+
+// the `Option` returning versions of the algorithms, for fallible code paths
+#[cfg(feature = "delegate")]
+impl_checked_div_rem!(
+    u256_checked_div_rem_delegate,
+    i256_checked_div_rem_delegate,
+    u256_div_rem_delegate,
+    i256_div_rem_delegate,
+    u256,
+    i256,
+    inline;
+    inline
+);
+#[cfg(feature = "trifecta")]
+impl_checked_div_rem!(
+    u256_checked_div_rem_trifecta,
+    i256_checked_div_rem_trifecta,
+    u256_div_rem_trifecta,
+    i256_div_rem_trifecta,
+    u256,
+    i256,
+    inline;
+    inline
+);
+#[cfg(feature = "asymmetric")]
+impl_checked_div_rem!(
+    u256_checked_div_rem_asymmetric,
+    i256_checked_div_rem_asymmetric,
+    u256_div_rem_asymmetric,
+    i256_div_rem_asymmetric,
+    u256,
+    i256,
+    inline;
+    inline
+);
+
+// floored division with the algorithms, where the remainder has the sign of the divisor
+#[cfg(feature = "delegate")]
+impl_floor_div_rem!(
+    i256_div_rem_floor_delegate,
+    u256_div_rem_delegate,
+    u256,
+    i256,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_floor_div_rem!(
+    i256_div_rem_floor_trifecta,
+    u256_div_rem_trifecta,
+    u256,
+    i256,
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_floor_div_rem!(
+    i256_div_rem_floor_asymmetric,
+    u256_div_rem_asymmetric,
+    u256,
+    i256,
+    inline,
+    track_caller
+);
+
+// ceiling division with the algorithms
+#[cfg(feature = "delegate")]
+impl_ceil_div_rem!(
+    u256_div_rem_ceil_delegate,
+    u256_div_ceil_delegate,
+    i256_div_rem_ceil_delegate,
+    i256_div_ceil_delegate,
+    u256_div_rem_delegate,
+    u256,
+    i256,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_ceil_div_rem!(
+    u256_div_rem_ceil_trifecta,
+    u256_div_ceil_trifecta,
+    i256_div_rem_ceil_trifecta,
+    i256_div_ceil_trifecta,
+    u256_div_rem_trifecta,
+    u256,
+    i256,
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_ceil_div_rem!(
+    u256_div_rem_ceil_asymmetric,
+    u256_div_ceil_asymmetric,
+    i256_div_rem_ceil_asymmetric,
+    i256_div_ceil_asymmetric,
+    u256_div_rem_asymmetric,
+    u256,
+    i256,
+    inline,
+    track_caller
+);
+
+// saturating signed division with the algorithms
+#[cfg(feature = "delegate")]
+impl_saturating_div_rem!(
+    i256_saturating_div_rem_delegate,
+    i256_saturating_div_delegate,
+    i256_div_rem_delegate,
+    i256,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_saturating_div_rem!(
+    i256_saturating_div_rem_trifecta,
+    i256_saturating_div_trifecta,
+    i256_div_rem_trifecta,
+    i256,
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_saturating_div_rem!(
+    i256_saturating_div_rem_asymmetric,
+    i256_saturating_div_asymmetric,
+    i256_div_rem_asymmetric,
+    i256,
+    inline,
+    track_caller
+);
+
+// fallible division with the algorithms
+#[cfg(feature = "delegate")]
+impl_try_div_rem!(
+    u256_try_div_rem_delegate,
+    i256_try_div_rem_delegate,
+    u256_div_rem_delegate,
+    i256_div_rem_delegate,
+    u256,
+    i256,
+    inline;
+    inline
+);
+#[cfg(feature = "trifecta")]
+impl_try_div_rem!(
+    u256_try_div_rem_trifecta,
+    i256_try_div_rem_trifecta,
+    u256_div_rem_trifecta,
+    i256_div_rem_trifecta,
+    u256,
+    i256,
+    inline;
+    inline
+);
+#[cfg(feature = "asymmetric")]
+impl_try_div_rem!(
+    u256_try_div_rem_asymmetric,
+    i256_try_div_rem_asymmetric,
+    u256_div_rem_asymmetric,
+    i256_div_rem_asymmetric,
+    u256,
+    i256,
+    inline;
+    inline
+);
+
+// The quotient-only versions of the algorithms. These instantiate the algorithms again with
+// `inline(always)`, so that the instructions only needed for the remainder are eliminated.
+mod inliner {
+    #[allow(unused_imports)]
+    use super::*;
+
+    mod imp {
+        #[allow(unused_imports)]
+        use super::*;
+
+        #[cfg(feature = "delegate")]
+        impl_delegate!(
+            u256_div_rem_delegate_inline,
+            i256_div_rem_delegate_inline,
+            zero_div_fn,
+            u128_normalization_shift,
+            u128_by_u128_div_rem,
+            64,
+            u64,
+            u128,
+            u256,
+            i256,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(feature = "trifecta")]
+        impl_trifecta!(
+            u256_div_rem_trifecta_inline,
+            i256_div_rem_trifecta_inline,
+            zero_div_fn,
+            u128_by_u128_div_rem,
+            64,
+            u64,
+            u128,
+            u256,
+            i256,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(feature = "asymmetric")]
+        impl_asymmetric!(
+            u256_div_rem_asymmetric_inline,
+            i256_div_rem_asymmetric_inline,
+            zero_div_fn,
+            u128_by_u128_div_rem,
+            u256_by_u128_div_rem,
+            64,
+            u64,
+            u128,
+            u256,
+            i256,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+    }
+    #[allow(unused_imports)]
+    use imp::*;
+
+    #[cfg(feature = "delegate")]
+    impl_div!(
+        u256_div_delegate,
+        i256_div_delegate,
+        u256_div_rem_delegate_inline,
+        i256_div_rem_delegate_inline,
+        u256,
+        i256,
+        track_caller;
+        track_caller
+    );
+    #[cfg(feature = "trifecta")]
+    impl_div!(
+        u256_div_trifecta,
+        i256_div_trifecta,
+        u256_div_rem_trifecta_inline,
+        i256_div_rem_trifecta_inline,
+        u256,
+        i256,
+        track_caller;
+        track_caller
+    );
+    #[cfg(feature = "asymmetric")]
+    impl_div!(
+        u256_div_asymmetric,
+        i256_div_asymmetric,
+        u256_div_rem_asymmetric_inline,
+        i256_div_rem_asymmetric_inline,
+        u256,
+        i256,
+        track_caller;
+        track_caller
+    );
+}
+
+#[allow(unused_imports)]
+pub use inliner::*;
@@ -0,0 +1,462 @@
+#[allow(unused_imports)]
+use super::*;
+
+#[cfg(feature = "binary-long")]
+impl_binary_long!(
+    u32_div_rem_binary_long,
+    i32_div_rem_binary_long,
+    zero_div_fn,
+    u32_normalization_shift,
+    swar_steps = u32_swar_steps,
+    32,
+    u32,
+    i32,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+#[cfg(feature = "delegate")]
+impl_delegate!(
+    u32_div_rem_delegate,
+    i32_div_rem_delegate,
+    zero_div_fn,
+    u16_normalization_shift,
+    u16_by_u16_div_rem,
+    8,
+    u8,
+    u16,
+    u32,
+    i32,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+
+impl_hardware_oracle!(
+    u32_div_rem_hardware,
+    i32_div_rem_hardware,
+    u32,
+    i32,
+    ":e",
+    ":w"
+);
+
+impl_invariant_rem!(
+    U32InvariantRem,
+    zero_div_fn,
+    u32_by_u32_div_rem,
+    u64_by_u32_div_rem_unchecked,
+    32,
+    u32,
+    u64,
+);
+impl_defmt_invariant_rem!(U32InvariantRem);
+
+impl_magic_table!(U32MagicTable, 32, u32, u64,);
+
+impl_exact_div!(u32_exact_div, u32_is_multiple_of, zero_div_fn, 32, u32,);
+
+// RISC-V cores without the M extension (such as `riscv32i` and `riscv32e`) have no division and no
+// CLZ instruction, so the restoring loop of `impl_binary_long` is written out in assembly for them.
+// Only `a0` to `a5` and `t0` to `t2` are used, which RV32E also has. The symbol is hidden so that it
+// is not exported from shared libraries.
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "riscv32",
+    not(target_feature = "m")
+))]
+core::arch::global_asm!(
+    ".section .text.__specialized_div_rem_u32_div_rem_binary_long,\"ax\",@progbits",
+    ".globl __specialized_div_rem_u32_div_rem_binary_long",
+    ".hidden __specialized_div_rem_u32_div_rem_binary_long",
+    ".type __specialized_div_rem_u32_div_rem_binary_long,@function",
+    ".p2align 2",
+    "__specialized_div_rem_u32_div_rem_binary_long:",
+    // `a0` is `duo` and `a1` is `div` (which is not 0). `duo < div` returns early.
+    "bltu a0, a1, 4f",
+    // The normalization shifts `div` into `t0` and the quotient bit `pow` into `t1` left until
+    // `2 * t0 > duo`, which is checked against `duo >> 1` so that it cannot overflow. This is 3
+    // instructions per bit of the quotient.
+    "srli a3, a0, 1",
+    "mv t0, a1",
+    "li t1, 1",
+    "li a2, 0",
+    "bltu a3, t0, 2f",
+    "1:",
+    "slli t0, t0, 1",
+    "slli t1, t1, 1",
+    "bgeu a3, t0, 1b",
+    "j 2f",
+    // The restoring loop, with `quo` in `a2`. A step without a quotient bit is 3 instructions
+    // (`bltu` and the two shifts), and a step with a quotient bit is 6 instructions. The
+    // `duo < div_original` check is the only exit, which is always reached because `duo < div`
+    // after the step with `pow == 1`.
+    "3:",
+    "srli t0, t0, 1",
+    "srli t1, t1, 1",
+    "2:",
+    "bltu a0, t0, 3b",
+    "sub a0, a0, t0",
+    "or a2, a2, t1",
+    "bgeu a0, a1, 3b",
+    // the quotient is returned in `a0` and the remainder in `a1`, as the lower and upper halves of
+    // a `u64`
+    "mv a1, a0",
+    "mv a0, a2",
+    "ret",
+    "4:",
+    "mv a1, a0",
+    "li a0, 0",
+    "ret",
+    ".size __specialized_div_rem_u32_div_rem_binary_long, . - \
+     __specialized_div_rem_u32_div_rem_binary_long",
+);
+
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "riscv32",
+    not(target_feature = "m")
+))]
+extern "C" {
+    fn __specialized_div_rem_u32_div_rem_binary_long(duo: u32, div: u32) -> u64;
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` with the restoring binary long
+/// division loop written in RISC-V assembly, and returns them as a tuple. This is for cores without
+/// the M extension, where each step takes 3 instructions without a quotient bit and 6 with one.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "riscv32",
+    not(target_feature = "m")
+))]
+#[inline]
+#[track_caller]
+pub fn u32_div_rem_binary_long_asm(duo: u32, div: u32) -> (u32, u32) {
+    if div == 0 {
+        zero_div_fn()
+    }
+    // Safety: `div != 0`, and the routine follows the C calling convention
+    let tmp = unsafe { __specialized_div_rem_u32_div_rem_binary_long(duo, div) };
+    (tmp as u32, (tmp >> 32) as u32)
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` and returns them as a tuple.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "riscv32",
+    not(target_feature = "m")
+))]
+#[inline]
+#[track_caller]
+pub fn i32_div_rem_binary_long_asm(duo: i32, div: i32) -> (i32, i32) {
+    let (quo, rem) = u32_div_rem_binary_long_asm(duo.unsigned_abs(), div.unsigned_abs());
+    let mut quo = quo as i32;
+    let mut rem = rem as i32;
+    if duo < 0 {
+        rem = rem.wrapping_neg();
+    }
+    if (duo < 0) != (div < 0) {
+        quo = quo.wrapping_neg();
+    }
+    (quo, rem)
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` with the 3 instruction
+/// `ADDS`/`ADC`/`ADDCC` loop described in the documentation of `impl_binary_long`, written in ARM
+/// assembly, and returns them as a tuple. In Thumb-2, the `ADDCC` needs an `it` before it, and the
+/// loop counter adds another 2 instructions per step.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "arm",
+    any(not(target_feature = "thumb-mode"), target_feature = "v6t2")
+))]
+#[inline(never)]
+#[track_caller]
+pub fn u32_div_rem_binary_long_arm(duo: u32, div: u32) -> (u32, u32) {
+    if div == 0 {
+        zero_div_fn()
+    }
+    if duo < div {
+        return (0, duo);
+    }
+    // the setup of the 3 instruction algorithm in `impl_binary_long`, which makes the first step
+    // and handles the edge case where the most significant bit of the shifted `div` is set
+    let div_original = div;
+    let mut shl = u32_normalization_shift(duo, div, true) as u32;
+    let mut div = div << shl;
+    let mut duo = duo.wrapping_sub(div);
+    let mut quo: u32 = 1;
+    if duo < div_original {
+        return (1 << shl, duo);
+    }
+    let div_neg = if (div as i32) < 0 {
+        div >>= 1;
+        let div_neg = div.wrapping_neg();
+        let (sub, carry) = duo.overflowing_add(div_neg);
+        duo = sub;
+        quo = quo.wrapping_add(quo).wrapping_add(carry as u32);
+        if !carry {
+            duo = duo.wrapping_add(div);
+        }
+        shl -= 1;
+        div_neg
+    } else {
+        div.wrapping_neg()
+    };
+    unsafe {
+        // `ADDS` subtracts `div` from `duo << 1` by adding `div_neg`, which sets the carry exactly
+        // when the subtraction is normalized. `ADC` shifts the carry into `quo`, and `ADDCC` undoes
+        // the subtraction otherwise.
+        core::arch::asm!(
+            "cmp {cnt}, #0",
+            "beq 3f",
+            "2:",
+            "adds {duo}, {div_neg}, {duo}, lsl #1",
+            "adc {quo}, {quo}, {quo}",
+            "it cc",
+            "addcc {duo}, {duo}, {div}",
+            "subs {cnt}, {cnt}, #1",
+            "bne 2b",
+            "3:",
+            duo = inout(reg) duo,
+            quo = inout(reg) quo,
+            div = in(reg) div,
+            div_neg = in(reg) div_neg,
+            cnt = inout(reg) shl => _,
+            options(pure, nomem, nostack)
+        );
+    }
+    (quo, duo >> shl)
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` and returns them as a tuple.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "arm",
+    any(not(target_feature = "thumb-mode"), target_feature = "v6t2")
+))]
+#[inline]
+#[track_caller]
+pub fn i32_div_rem_binary_long_arm(duo: i32, div: i32) -> (i32, i32) {
+    let (quo, rem) = u32_div_rem_binary_long_arm(duo.unsigned_abs(), div.unsigned_abs());
+    let mut quo = quo as i32;
+    let mut rem = rem as i32;
+    if duo < 0 {
+        rem = rem.wrapping_neg();
+    }
+    if (duo < 0) != (div < 0) {
+        quo = quo.wrapping_neg();
+    }
+    (quo, rem)
+}
+
+// the `Option` returning versions of the algorithms, for fallible code paths
+#[cfg(feature = "binary-long")]
+impl_checked_div_rem!(
+    u32_checked_div_rem_binary_long,
+    i32_checked_div_rem_binary_long,
+    u32_div_rem_binary_long,
+    i32_div_rem_binary_long,
+    u32,
+    i32,
+    inline;
+    inline
+);
+#[cfg(feature = "delegate")]
+impl_checked_div_rem!(
+    u32_checked_div_rem_delegate,
+    i32_checked_div_rem_delegate,
+    u32_div_rem_delegate,
+    i32_div_rem_delegate,
+    u32,
+    i32,
+    inline;
+    inline
+);
+
+// floored division with the algorithms, where the remainder has the sign of the divisor
+#[cfg(feature = "binary-long")]
+impl_floor_div_rem!(
+    i32_div_rem_floor_binary_long,
+    u32_div_rem_binary_long,
+    u32,
+    i32,
+    inline,
+    track_caller
+);
+#[cfg(feature = "delegate")]
+impl_floor_div_rem!(
+    i32_div_rem_floor_delegate,
+    u32_div_rem_delegate,
+    u32,
+    i32,
+    inline,
+    track_caller
+);
+
+// ceiling division with the algorithms
+#[cfg(feature = "binary-long")]
+impl_ceil_div_rem!(
+    u32_div_rem_ceil_binary_long,
+    u32_div_ceil_binary_long,
+    i32_div_rem_ceil_binary_long,
+    i32_div_ceil_binary_long,
+    u32_div_rem_binary_long,
+    u32,
+    i32,
+    inline,
+    track_caller
+);
+#[cfg(feature = "delegate")]
+impl_ceil_div_rem!(
+    u32_div_rem_ceil_delegate,
+    u32_div_ceil_delegate,
+    i32_div_rem_ceil_delegate,
+    i32_div_ceil_delegate,
+    u32_div_rem_delegate,
+    u32,
+    i32,
+    inline,
+    track_caller
+);
+
+// saturating signed division with the algorithms
+#[cfg(feature = "binary-long")]
+impl_saturating_div_rem!(
+    i32_saturating_div_rem_binary_long,
+    i32_saturating_div_binary_long,
+    i32_div_rem_binary_long,
+    i32,
+    inline,
+    track_caller
+);
+#[cfg(feature = "delegate")]
+impl_saturating_div_rem!(
+    i32_saturating_div_rem_delegate,
+    i32_saturating_div_delegate,
+    i32_div_rem_delegate,
+    i32,
+    inline,
+    track_caller
+);
+
+// fallible division with the algorithms
+#[cfg(feature = "binary-long")]
+impl_try_div_rem!(
+    u32_try_div_rem_binary_long,
+    i32_try_div_rem_binary_long,
+    u32_div_rem_binary_long,
+    i32_div_rem_binary_long,
+    u32,
+    i32,
+    inline;
+    inline
+);
+#[cfg(feature = "delegate")]
+impl_try_div_rem!(
+    u32_try_div_rem_delegate,
+    i32_try_div_rem_delegate,
+    u32_div_rem_delegate,
+    i32_div_rem_delegate,
+    u32,
+    i32,
+    inline;
+    inline
+);
+
+// The quotient-only versions of the algorithms. These instantiate the algorithms again with
+// `inline(always)`, so that the instructions only needed for the remainder are eliminated.
+mod inliner {
+    #[allow(unused_imports)]
+    use super::*;
+
+    mod imp {
+        #[allow(unused_imports)]
+        use super::*;
+
+        #[cfg(feature = "binary-long")]
+        impl_binary_long!(
+            u32_div_rem_binary_long_inline,
+            i32_div_rem_binary_long_inline,
+            zero_div_fn,
+            u32_normalization_shift,
+            swar_steps = u32_swar_steps,
+            32,
+            u32,
+            i32,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(feature = "delegate")]
+        impl_delegate!(
+            u32_div_rem_delegate_inline,
+            i32_div_rem_delegate_inline,
+            zero_div_fn,
+            u16_normalization_shift,
+            u16_by_u16_div_rem,
+            8,
+            u8,
+            u16,
+            u32,
+            i32,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+    }
+    #[allow(unused_imports)]
+    use imp::*;
+
+    #[cfg(feature = "binary-long")]
+    impl_div!(
+        u32_div_binary_long,
+        i32_div_binary_long,
+        u32_div_rem_binary_long_inline,
+        i32_div_rem_binary_long_inline,
+        u32,
+        i32,
+        track_caller;
+        track_caller
+    );
+    #[cfg(feature = "delegate")]
+    impl_div!(
+        u32_div_delegate,
+        i32_div_delegate,
+        u32_div_rem_delegate_inline,
+        i32_div_rem_delegate_inline,
+        u32,
+        i32,
+        track_caller;
+        track_caller
+    );
+}
+
+#[allow(unused_imports)]
+pub use inliner::*;
@@ -0,0 +1,226 @@
+#[allow(unused_imports)]
+use super::*;
+
+// The `u256` divisions are the half divisions here, one level above `width_256`. There is no
+// `_asymmetric`, which would need a 512 by 256 bit division.
+#[cfg(feature = "delegate")]
+impl_delegate!(
+    u512_div_rem_delegate,
+    i512_div_rem_delegate,
+    zero_div_fn,
+    u256_normalization_shift,
+    u256_div_rem_delegate,
+    128,
+    u128,
+    u256,
+    u512,
+    i512,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_trifecta!(
+    u512_div_rem_trifecta,
+    i512_div_rem_trifecta,
+    zero_div_fn,
+    u256_div_rem_trifecta,
+    128,
+    u128,
+    u256,
+    u512,
+    i512,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+
+// the `Option` returning versions of the algorithms, for fallible code paths
+#[cfg(feature = "delegate")]
+impl_checked_div_rem!(
+    u512_checked_div_rem_delegate,
+    i512_checked_div_rem_delegate,
+    u512_div_rem_delegate,
+    i512_div_rem_delegate,
+    u512,
+    i512,
+    inline;
+    inline
+);
+#[cfg(feature = "trifecta")]
+impl_checked_div_rem!(
+    u512_checked_div_rem_trifecta,
+    i512_checked_div_rem_trifecta,
+    u512_div_rem_trifecta,
+    i512_div_rem_trifecta,
+    u512,
+    i512,
+    inline;
+    inline
+);
+
+// floored division with the algorithms, where the remainder has the sign of the divisor
+#[cfg(feature = "delegate")]
+impl_floor_div_rem!(
+    i512_div_rem_floor_delegate,
+    u512_div_rem_delegate,
+    u512,
+    i512,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_floor_div_rem!(
+    i512_div_rem_floor_trifecta,
+    u512_div_rem_trifecta,
+    u512,
+    i512,
+    inline,
+    track_caller
+);
+
+// ceiling division with the algorithms
+#[cfg(feature = "delegate")]
+impl_ceil_div_rem!(
+    u512_div_rem_ceil_delegate,
+    u512_div_ceil_delegate,
+    i512_div_rem_ceil_delegate,
+    i512_div_ceil_delegate,
+    u512_div_rem_delegate,
+    u512,
+    i512,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_ceil_div_rem!(
+    u512_div_rem_ceil_trifecta,
+    u512_div_ceil_trifecta,
+    i512_div_rem_ceil_trifecta,
+    i512_div_ceil_trifecta,
+    u512_div_rem_trifecta,
+    u512,
+    i512,
+    inline,
+    track_caller
+);
+
+// saturating signed division with the algorithms
+#[cfg(feature = "delegate")]
+impl_saturating_div_rem!(
+    i512_saturating_div_rem_delegate,
+    i512_saturating_div_delegate,
+    i512_div_rem_delegate,
+    i512,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_saturating_div_rem!(
+    i512_saturating_div_rem_trifecta,
+    i512_saturating_div_trifecta,
+    i512_div_rem_trifecta,
+    i512,
+    inline,
+    track_caller
+);
+
+// fallible division with the algorithms
+#[cfg(feature = "delegate")]
+impl_try_div_rem!(
+    u512_try_div_rem_delegate,
+    i512_try_div_rem_delegate,
+    u512_div_rem_delegate,
+    i512_div_rem_delegate,
+    u512,
+    i512,
+    inline;
+    inline
+);
+#[cfg(feature = "trifecta")]
+impl_try_div_rem!(
+    u512_try_div_rem_trifecta,
+    i512_try_div_rem_trifecta,
+    u512_div_rem_trifecta,
+    i512_div_rem_trifecta,
+    u512,
+    i512,
+    inline;
+    inline
+);
+
+// The quotient-only versions of the algorithms. These instantiate the algorithms again with
+// `inline(always)`, so that the instructions only needed for the remainder are eliminated.
+mod inliner {
+    #[allow(unused_imports)]
+    use super::*;
+
+    mod imp {
+        #[allow(unused_imports)]
+        use super::*;
+
+        #[cfg(feature = "delegate")]
+        impl_delegate!(
+            u512_div_rem_delegate_inline,
+            i512_div_rem_delegate_inline,
+            zero_div_fn,
+            u256_normalization_shift,
+            u256_div_rem_delegate,
+            128,
+            u128,
+            u256,
+            u512,
+            i512,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(feature = "trifecta")]
+        impl_trifecta!(
+            u512_div_rem_trifecta_inline,
+            i512_div_rem_trifecta_inline,
+            zero_div_fn,
+            u256_div_rem_trifecta,
+            128,
+            u128,
+            u256,
+            u512,
+            i512,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+    }
+    #[allow(unused_imports)]
+    use imp::*;
+
+    #[cfg(feature = "delegate")]
+    impl_div!(
+        u512_div_delegate,
+        i512_div_delegate,
+        u512_div_rem_delegate_inline,
+        i512_div_rem_delegate_inline,
+        u512,
+        i512,
+        track_caller;
+        track_caller
+    );
+    #[cfg(feature = "trifecta")]
+    impl_div!(
+        u512_div_trifecta,
+        i512_div_trifecta,
+        u512_div_rem_trifecta_inline,
+        i512_div_rem_trifecta_inline,
+        u512,
+        i512,
+        track_caller;
+        track_caller
+    );
+}
+
+#[allow(unused_imports)]
+pub use inliner::*;
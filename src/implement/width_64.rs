@@ -0,0 +1,875 @@
+#[allow(unused_imports)]
+use super::*;
+
+#[cfg(feature = "binary-long")]
+impl_binary_long!(
+    u64_div_rem_binary_long,
+    i64_div_rem_binary_long,
+    zero_div_fn,
+    u64_normalization_shift,
+    swar_steps = u64_swar_steps,
+    64,
+    u64,
+    i64,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+#[cfg(feature = "delegate")]
+impl_delegate!(
+    u64_div_rem_delegate,
+    i64_div_rem_delegate,
+    zero_div_fn,
+    u32_normalization_shift,
+    u32_by_u32_div_rem,
+    16,
+    u16,
+    u32,
+    u64,
+    i64,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_trifecta!(
+    u64_div_rem_trifecta,
+    i64_div_rem_trifecta,
+    zero_div_fn,
+    u32_by_u32_div_rem,
+    16,
+    u16,
+    u32,
+    u64,
+    i64,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+// `_delegate` with the ARM assembly loop of `u32_div_rem_binary_long_arm` as the half division,
+// for ARM CPUs without a hardware divider
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    feature = "delegate",
+    feature = "width-32",
+    target_arch = "arm",
+    any(not(target_feature = "thumb-mode"), target_feature = "v6t2")
+))]
+impl_delegate!(
+    u64_div_rem_delegate_arm,
+    i64_div_rem_delegate_arm,
+    zero_div_fn,
+    u32_normalization_shift,
+    u32_div_rem_binary_long_arm,
+    16,
+    u16,
+    u32,
+    u64,
+    i64,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+// For targets without a CLZ instruction, where LLVM's expansion of `leading_zeros` would otherwise
+// be in the hot path. `_delegate` does not need this, because it goes through `USE_LZ`.
+#[cfg(feature = "trifecta")]
+impl_trifecta!(
+    u64_div_rem_trifecta_soft_lz,
+    i64_div_rem_trifecta_soft_lz,
+    zero_div_fn,
+    u32_by_u32_div_rem,
+    leading_zeros = u64_leading_zeros,
+    16,
+    u16,
+    u32,
+    u64,
+    i64,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_asymmetric!(
+    u64_div_rem_asymmetric,
+    i64_div_rem_asymmetric,
+    zero_div_fn,
+    u32_by_u32_div_rem,
+    u64_by_u32_div_rem_unchecked,
+    16,
+    u16,
+    u32,
+    u64,
+    i64,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+
+// for the inner loops of multiple limb division, which keep the divisor normalized
+impl_normalized!(
+    u32_div_rem_normalized,
+    u64_by_u32_div_rem_normalized,
+    u32_by_u32_div_rem,
+    16,
+    u16,
+    u32,
+    u64,
+    inline
+);
+
+/// Computes the quotient and remainder of `duo` divided by `div` and returns them as a tuple. When
+/// the quotient fits in a `u32`, this uses the 64 by 32 bit hardware division on x86 with the
+/// `asm` feature (or an equivalent elsewhere), and otherwise falls back to `u64_div_rem_delegate`.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[cfg(feature = "delegate")]
+#[track_caller]
+pub fn u64_by_u32_div_rem_checked(duo: u64, div: u32) -> (u64, u32) {
+    if div == 0 {
+        zero_div_fn()
+    }
+    if div > ((duo >> 32) as u32) {
+        // the quotient fits in a `u32`
+        let (quo, rem) = crate::unchecked_call!(u64_by_u32_div_rem_unchecked(duo, div));
+        (quo as u64, rem)
+    } else {
+        let (quo, rem) = u64_div_rem_delegate(duo, div as u64);
+        (quo, rem as u32)
+    }
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` with short division and returns
+/// them as a tuple. This is two 64 by 32 bit divisions (or one if the quotient fits in a `u32`),
+/// without the branches that the full `u64` divisions need to find out that `div` is small.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[track_caller]
+pub fn u64_div_rem_by_u16(duo: u64, div: u16) -> (u64, u16) {
+    if div == 0 {
+        zero_div_fn()
+    }
+    let div = div as u32;
+    let duo_hi = (duo >> 32) as u32;
+    let (quo_hi, rem_hi) = if duo_hi < div {
+        (0, duo_hi)
+    } else {
+        u32_by_u32_div_rem(duo_hi, div)
+    };
+    // `rem_hi < div`, so the quotient fits in a `u32`
+    let (quo_lo, rem) = crate::unchecked_call!(u64_by_u32_div_rem_unchecked(
+        ((rem_hi as u64) << 32) | (duo as u32 as u64),
+        div
+    ));
+    (((quo_hi as u64) << 32) | (quo_lo as u64), rem as u16)
+}
+#[cfg(feature = "float-reciprocal")]
+impl_float_reciprocal!(
+    u64_div_rem_float_reciprocal,
+    i64_div_rem_float_reciprocal,
+    u128_by_u64_div_rem_float_reciprocal,
+    zero_div_fn,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+
+impl_hardware_oracle!(
+    u64_div_rem_hardware,
+    i64_div_rem_hardware,
+    u64,
+    i64,
+    ":r",
+    ":x"
+);
+
+// fractions
+impl_gcd!(u64_gcd, u64_by_u64_div_rem, u64,);
+impl_continued_fraction!(u64_continued_fraction, zero_div_fn, u64_by_u64_div_rem, u64,);
+impl_ratio!(
+    U64Ratio,
+    zero_div_fn,
+    u64_gcd,
+    u64_by_u64_div_rem,
+    32,
+    u32,
+    u64,
+);
+impl_defmt_ratio!(U64Ratio);
+
+impl_invariant_rem!(
+    U64InvariantRem,
+    zero_div_fn,
+    u64_by_u64_div_rem,
+    u128_by_u64_div_rem_unchecked,
+    64,
+    u64,
+    u128,
+);
+impl_defmt_invariant_rem!(U64InvariantRem);
+
+impl_magic_table!(U64MagicTable, 64, u64, u128,);
+
+/// Returns the full product of `lhs` and `rhs` as a tuple of the lower and upper halves
+pub(super) const fn u64_widening_mul(lhs: u64, rhs: u64) -> (u64, u64) {
+    let tmp = (lhs as u128) * (rhs as u128);
+    (tmp as u64, (tmp >> 64) as u64)
+}
+
+/// Divides `hi * 2^64` by `div` and returns a tuple of the quotient and the remainder, where
+/// `hi < div` so that the quotient fits in a `u64`. This uses the `/` operator instead of
+/// `u128_by_u64_div_rem_unchecked` so that it can be evaluated at compile time.
+const fn u64_shifted_div_rem(hi: u64, div: u64) -> (u64, u64) {
+    debug_assert!(hi < div);
+    let duo = (hi as u128) << 64;
+    ((duo / (div as u128)) as u64, (duo % (div as u128)) as u64)
+}
+
+impl_precomputed_divisor!(
+    PrecomputedDivisor64,
+    u64_div_rem_slice,
+    zero_div_fn,
+    u64_shifted_div_rem,
+    u64_widening_mul,
+    64,
+    u64,
+);
+impl_defmt_invariant_rem!(PrecomputedDivisor64);
+impl_signed_precomputed_divisor!(
+    PrecomputedDivisorI64,
+    zero_div_fn,
+    u64_shifted_div_rem,
+    u64_widening_mul,
+    64,
+    u64,
+    i64,
+);
+impl_defmt_invariant_rem!(PrecomputedDivisorI64);
+
+impl_exact_div!(u64_exact_div, u64_is_multiple_of, zero_div_fn, 64, u64,);
+
+impl_fold!(u64_rem_pow2m1, u64_rem_pow2p1, 64, u64,);
+impl_significand_div!(
+    u64_div_significand,
+    zero_div_fn,
+    u64_by_u32_div_rem_unchecked,
+    32,
+    u32,
+    u64,
+);
+impl_root!(
+    u64_isqrt,
+    u64_icbrt,
+    u64_nth_root,
+    u64_by_u64_div_rem,
+    64,
+    u64,
+);
+
+impl_factor!(
+    u64_smallest_factor,
+    u64_factor_small_primes,
+    u64_by_u64_div_rem,
+    u64,
+);
+
+/// Computes `(a * b) % m` with a widening multiplication and the 128 by 64 bit division.
+///
+/// # Panics
+///
+/// Panics if `m == 0`.
+#[track_caller]
+pub fn u64_mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    if m == 0 {
+        zero_div_fn()
+    }
+    let a = u64_by_u64_div_rem(a, m).1;
+    let b = u64_by_u64_div_rem(b, m).1;
+    // `a * b < m^2`, so the quotient fits in a `u64`
+    crate::unchecked_call!(u128_by_u64_div_rem_unchecked((a as u128) * (b as u128), m)).1
+}
+
+impl_miller_rabin!(
+    u64_pow_mod,
+    u64_is_probable_prime,
+    u64_is_prime,
+    u64_mul_mod,
+    u64,
+);
+impl_mod_sqrt!(u64_mod_sqrt, u64_pow_mod, u64_mul_mod, u64,);
+impl_mod_inv!(
+    u64_mod_inv,
+    u64_batch_invert,
+    u64_by_u64_div_rem,
+    u64_mul_mod,
+    u64,
+);
+
+// the `Option` returning versions of the algorithms, for fallible code paths
+#[cfg(feature = "binary-long")]
+impl_checked_div_rem!(
+    u64_checked_div_rem_binary_long,
+    i64_checked_div_rem_binary_long,
+    u64_div_rem_binary_long,
+    i64_div_rem_binary_long,
+    u64,
+    i64,
+    inline;
+    inline
+);
+#[cfg(feature = "delegate")]
+impl_checked_div_rem!(
+    u64_checked_div_rem_delegate,
+    i64_checked_div_rem_delegate,
+    u64_div_rem_delegate,
+    i64_div_rem_delegate,
+    u64,
+    i64,
+    inline;
+    inline
+);
+#[cfg(feature = "trifecta")]
+impl_checked_div_rem!(
+    u64_checked_div_rem_trifecta,
+    i64_checked_div_rem_trifecta,
+    u64_div_rem_trifecta,
+    i64_div_rem_trifecta,
+    u64,
+    i64,
+    inline;
+    inline
+);
+#[cfg(feature = "trifecta")]
+impl_checked_div_rem!(
+    u64_checked_div_rem_trifecta_soft_lz,
+    i64_checked_div_rem_trifecta_soft_lz,
+    u64_div_rem_trifecta_soft_lz,
+    i64_div_rem_trifecta_soft_lz,
+    u64,
+    i64,
+    inline;
+    inline
+);
+#[cfg(feature = "asymmetric")]
+impl_checked_div_rem!(
+    u64_checked_div_rem_asymmetric,
+    i64_checked_div_rem_asymmetric,
+    u64_div_rem_asymmetric,
+    i64_div_rem_asymmetric,
+    u64,
+    i64,
+    inline;
+    inline
+);
+#[cfg(feature = "float-reciprocal")]
+impl_checked_div_rem!(
+    u64_checked_div_rem_float_reciprocal,
+    i64_checked_div_rem_float_reciprocal,
+    u64_div_rem_float_reciprocal,
+    i64_div_rem_float_reciprocal,
+    u64,
+    i64,
+    inline;
+    inline
+);
+
+// Euclidean division with the algorithms, where the remainder is never negative
+#[cfg(feature = "binary-long")]
+impl_euclid_div_rem!(
+    i64_div_rem_euclid_binary_long,
+    u64_div_rem_binary_long,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "delegate")]
+impl_euclid_div_rem!(
+    i64_div_rem_euclid_delegate,
+    u64_div_rem_delegate,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_euclid_div_rem!(
+    i64_div_rem_euclid_trifecta,
+    u64_div_rem_trifecta,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_euclid_div_rem!(
+    i64_div_rem_euclid_trifecta_soft_lz,
+    u64_div_rem_trifecta_soft_lz,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_euclid_div_rem!(
+    i64_div_rem_euclid_asymmetric,
+    u64_div_rem_asymmetric,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "float-reciprocal")]
+impl_euclid_div_rem!(
+    i64_div_rem_euclid_float_reciprocal,
+    u64_div_rem_float_reciprocal,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+
+// floored division with the algorithms, where the remainder has the sign of the divisor
+#[cfg(feature = "binary-long")]
+impl_floor_div_rem!(
+    i64_div_rem_floor_binary_long,
+    u64_div_rem_binary_long,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "delegate")]
+impl_floor_div_rem!(
+    i64_div_rem_floor_delegate,
+    u64_div_rem_delegate,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_floor_div_rem!(
+    i64_div_rem_floor_trifecta,
+    u64_div_rem_trifecta,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_floor_div_rem!(
+    i64_div_rem_floor_trifecta_soft_lz,
+    u64_div_rem_trifecta_soft_lz,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_floor_div_rem!(
+    i64_div_rem_floor_asymmetric,
+    u64_div_rem_asymmetric,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "float-reciprocal")]
+impl_floor_div_rem!(
+    i64_div_rem_floor_float_reciprocal,
+    u64_div_rem_float_reciprocal,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+
+// ceiling division with the algorithms
+#[cfg(feature = "binary-long")]
+impl_ceil_div_rem!(
+    u64_div_rem_ceil_binary_long,
+    u64_div_ceil_binary_long,
+    i64_div_rem_ceil_binary_long,
+    i64_div_ceil_binary_long,
+    u64_div_rem_binary_long,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "delegate")]
+impl_ceil_div_rem!(
+    u64_div_rem_ceil_delegate,
+    u64_div_ceil_delegate,
+    i64_div_rem_ceil_delegate,
+    i64_div_ceil_delegate,
+    u64_div_rem_delegate,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_ceil_div_rem!(
+    u64_div_rem_ceil_trifecta,
+    u64_div_ceil_trifecta,
+    i64_div_rem_ceil_trifecta,
+    i64_div_ceil_trifecta,
+    u64_div_rem_trifecta,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_ceil_div_rem!(
+    u64_div_rem_ceil_trifecta_soft_lz,
+    u64_div_ceil_trifecta_soft_lz,
+    i64_div_rem_ceil_trifecta_soft_lz,
+    i64_div_ceil_trifecta_soft_lz,
+    u64_div_rem_trifecta_soft_lz,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_ceil_div_rem!(
+    u64_div_rem_ceil_asymmetric,
+    u64_div_ceil_asymmetric,
+    i64_div_rem_ceil_asymmetric,
+    i64_div_ceil_asymmetric,
+    u64_div_rem_asymmetric,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "float-reciprocal")]
+impl_ceil_div_rem!(
+    u64_div_rem_ceil_float_reciprocal,
+    u64_div_ceil_float_reciprocal,
+    i64_div_rem_ceil_float_reciprocal,
+    i64_div_ceil_float_reciprocal,
+    u64_div_rem_float_reciprocal,
+    u64,
+    i64,
+    inline,
+    track_caller
+);
+
+// saturating signed division with the algorithms
+#[cfg(feature = "binary-long")]
+impl_saturating_div_rem!(
+    i64_saturating_div_rem_binary_long,
+    i64_saturating_div_binary_long,
+    i64_div_rem_binary_long,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "delegate")]
+impl_saturating_div_rem!(
+    i64_saturating_div_rem_delegate,
+    i64_saturating_div_delegate,
+    i64_div_rem_delegate,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_saturating_div_rem!(
+    i64_saturating_div_rem_trifecta,
+    i64_saturating_div_trifecta,
+    i64_div_rem_trifecta,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "trifecta")]
+impl_saturating_div_rem!(
+    i64_saturating_div_rem_trifecta_soft_lz,
+    i64_saturating_div_trifecta_soft_lz,
+    i64_div_rem_trifecta_soft_lz,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "asymmetric")]
+impl_saturating_div_rem!(
+    i64_saturating_div_rem_asymmetric,
+    i64_saturating_div_asymmetric,
+    i64_div_rem_asymmetric,
+    i64,
+    inline,
+    track_caller
+);
+#[cfg(feature = "float-reciprocal")]
+impl_saturating_div_rem!(
+    i64_saturating_div_rem_float_reciprocal,
+    i64_saturating_div_float_reciprocal,
+    i64_div_rem_float_reciprocal,
+    i64,
+    inline,
+    track_caller
+);
+
+// fallible division with the algorithms
+#[cfg(feature = "binary-long")]
+impl_try_div_rem!(
+    u64_try_div_rem_binary_long,
+    i64_try_div_rem_binary_long,
+    u64_div_rem_binary_long,
+    i64_div_rem_binary_long,
+    u64,
+    i64,
+    inline;
+    inline
+);
+#[cfg(feature = "delegate")]
+impl_try_div_rem!(
+    u64_try_div_rem_delegate,
+    i64_try_div_rem_delegate,
+    u64_div_rem_delegate,
+    i64_div_rem_delegate,
+    u64,
+    i64,
+    inline;
+    inline
+);
+#[cfg(feature = "trifecta")]
+impl_try_div_rem!(
+    u64_try_div_rem_trifecta,
+    i64_try_div_rem_trifecta,
+    u64_div_rem_trifecta,
+    i64_div_rem_trifecta,
+    u64,
+    i64,
+    inline;
+    inline
+);
+#[cfg(feature = "trifecta")]
+impl_try_div_rem!(
+    u64_try_div_rem_trifecta_soft_lz,
+    i64_try_div_rem_trifecta_soft_lz,
+    u64_div_rem_trifecta_soft_lz,
+    i64_div_rem_trifecta_soft_lz,
+    u64,
+    i64,
+    inline;
+    inline
+);
+#[cfg(feature = "asymmetric")]
+impl_try_div_rem!(
+    u64_try_div_rem_asymmetric,
+    i64_try_div_rem_asymmetric,
+    u64_div_rem_asymmetric,
+    i64_div_rem_asymmetric,
+    u64,
+    i64,
+    inline;
+    inline
+);
+#[cfg(feature = "float-reciprocal")]
+impl_try_div_rem!(
+    u64_try_div_rem_float_reciprocal,
+    i64_try_div_rem_float_reciprocal,
+    u64_div_rem_float_reciprocal,
+    i64_div_rem_float_reciprocal,
+    u64,
+    i64,
+    inline;
+    inline
+);
+
+// The quotient-only versions of the algorithms. These instantiate the algorithms again with
+// `inline(always)`, so that the instructions only needed for the remainder are eliminated.
+mod inliner {
+    #[allow(unused_imports)]
+    use super::*;
+
+    // the division step of `impl_float_reciprocal` is not needed here
+    #[allow(dead_code)]
+    mod imp {
+        #[allow(unused_imports)]
+        use super::*;
+
+        #[cfg(feature = "binary-long")]
+        impl_binary_long!(
+            u64_div_rem_binary_long_inline,
+            i64_div_rem_binary_long_inline,
+            zero_div_fn,
+            u64_normalization_shift,
+            swar_steps = u64_swar_steps,
+            64,
+            u64,
+            i64,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(feature = "delegate")]
+        impl_delegate!(
+            u64_div_rem_delegate_inline,
+            i64_div_rem_delegate_inline,
+            zero_div_fn,
+            u32_normalization_shift,
+            u32_by_u32_div_rem,
+            16,
+            u16,
+            u32,
+            u64,
+            i64,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(feature = "trifecta")]
+        impl_trifecta!(
+            u64_div_rem_trifecta_inline,
+            i64_div_rem_trifecta_inline,
+            zero_div_fn,
+            u32_by_u32_div_rem,
+            16,
+            u16,
+            u32,
+            u64,
+            i64,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(feature = "trifecta")]
+        impl_trifecta!(
+            u64_div_rem_trifecta_soft_lz_inline,
+            i64_div_rem_trifecta_soft_lz_inline,
+            zero_div_fn,
+            u32_by_u32_div_rem,
+            leading_zeros = u64_leading_zeros,
+            16,
+            u16,
+            u32,
+            u64,
+            i64,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(feature = "asymmetric")]
+        impl_asymmetric!(
+            u64_div_rem_asymmetric_inline,
+            i64_div_rem_asymmetric_inline,
+            zero_div_fn,
+            u32_by_u32_div_rem,
+            u64_by_u32_div_rem_unchecked,
+            16,
+            u16,
+            u32,
+            u64,
+            i64,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+        #[cfg(feature = "float-reciprocal")]
+        impl_float_reciprocal!(
+            u64_div_rem_float_reciprocal_inline,
+            i64_div_rem_float_reciprocal_inline,
+            u128_by_u64_div_rem_float_reciprocal_inline,
+            zero_div_fn,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+    }
+    #[allow(unused_imports)]
+    use imp::*;
+
+    #[cfg(feature = "binary-long")]
+    impl_div!(
+        u64_div_binary_long,
+        i64_div_binary_long,
+        u64_div_rem_binary_long_inline,
+        i64_div_rem_binary_long_inline,
+        u64,
+        i64,
+        track_caller;
+        track_caller
+    );
+    #[cfg(feature = "delegate")]
+    impl_div!(
+        u64_div_delegate,
+        i64_div_delegate,
+        u64_div_rem_delegate_inline,
+        i64_div_rem_delegate_inline,
+        u64,
+        i64,
+        track_caller;
+        track_caller
+    );
+    #[cfg(feature = "trifecta")]
+    impl_div!(
+        u64_div_trifecta,
+        i64_div_trifecta,
+        u64_div_rem_trifecta_inline,
+        i64_div_rem_trifecta_inline,
+        u64,
+        i64,
+        track_caller;
+        track_caller
+    );
+    #[cfg(feature = "trifecta")]
+    impl_div!(
+        u64_div_trifecta_soft_lz,
+        i64_div_trifecta_soft_lz,
+        u64_div_rem_trifecta_soft_lz_inline,
+        i64_div_rem_trifecta_soft_lz_inline,
+        u64,
+        i64,
+        track_caller;
+        track_caller
+    );
+    #[cfg(feature = "asymmetric")]
+    impl_div!(
+        u64_div_asymmetric,
+        i64_div_asymmetric,
+        u64_div_rem_asymmetric_inline,
+        i64_div_rem_asymmetric_inline,
+        u64,
+        i64,
+        track_caller;
+        track_caller
+    );
+    #[cfg(feature = "float-reciprocal")]
+    impl_div!(
+        u64_div_float_reciprocal,
+        i64_div_float_reciprocal,
+        u64_div_rem_float_reciprocal_inline,
+        i64_div_rem_float_reciprocal_inline,
+        u64,
+        i64,
+        track_caller;
+        track_caller
+    );
+}
+
+#[allow(unused_imports)]
+pub use inliner::*;
@@ -0,0 +1,331 @@
+#[allow(unused_imports)]
+use super::*;
+
+#[cfg(feature = "binary-long")]
+impl_binary_long!(
+    u8_div_rem_binary_long,
+    i8_div_rem_binary_long,
+    zero_div_fn,
+    u8_normalization_shift,
+    8,
+    u8,
+    i8,
+    inline(never),
+    track_caller;
+    inline,
+    track_caller
+);
+
+/// Computes the quotient and remainder of `duo` divided by `div` with a table of reciprocals, and
+/// returns them as a tuple. The quotient is estimated with one multiplication and corrected at
+/// most once, which avoids the setup and loop of `u8_div_rem_binary_long`.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[cfg(feature = "table")]
+#[inline(never)]
+#[track_caller]
+pub fn u8_div_rem_table(duo: u8, div: u8) -> (u8, u8) {
+    if div == 0 {
+        zero_div_fn()
+    }
+    let (quo, rem) = u16_by_table_div_rem(duo as u16, div as u16);
+    (quo as u8, rem as u8)
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` and returns them as a tuple.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[cfg(feature = "table")]
+#[inline]
+#[track_caller]
+pub fn i8_div_rem_table(duo: i8, div: i8) -> (i8, i8) {
+    let (quo, rem) = u8_div_rem_table(duo.unsigned_abs(), div.unsigned_abs());
+    let mut quo = quo as i8;
+    let mut rem = rem as i8;
+    if duo < 0 {
+        rem = rem.wrapping_neg();
+    }
+    if (duo < 0) != (div < 0) {
+        quo = quo.wrapping_neg();
+    }
+    (quo, rem)
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` with a restoring division loop
+/// written in AVR assembly, and returns them as a tuple. This avoids the setup of
+/// `u8_div_rem_binary_long`, which dominates at this width.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+#[inline(never)]
+#[track_caller]
+pub fn u8_div_rem_avr(duo: u8, div: u8) -> (u8, u8) {
+    if div == 0 {
+        zero_div_fn()
+    }
+    let quo: u8;
+    let rem: u8;
+    unsafe {
+        // The bits of `duo` are shifted into `rem` from the top, and the complemented bits of the
+        // quotient are shifted into `duo` from the bottom through the carry flag (which `cp` sets
+        // if `rem < div`). The loop runs 9 times because the first shift only sets up the carry.
+        core::arch::asm!(
+            "sub {rem}, {rem}",
+            "ldi {cnt}, 9",
+            "rjmp 2f",
+            "1:",
+            "rol {rem}",
+            "cp {rem}, {div}",
+            "brcs 2f",
+            "sub {rem}, {div}",
+            "2:",
+            "rol {duo}",
+            "dec {cnt}",
+            "brne 1b",
+            "com {duo}",
+            duo = inout(reg) duo => quo,
+            div = in(reg) div,
+            rem = out(reg) rem,
+            cnt = out(reg_upper) _,
+            options(pure, nomem, nostack)
+        );
+    }
+    (quo, rem)
+}
+
+/// Computes the quotient and remainder of `duo` divided by `div` and returns them as a tuple.
+///
+/// # Panics
+///
+/// Panics if `div == 0`.
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+#[inline]
+#[track_caller]
+pub fn i8_div_rem_avr(duo: i8, div: i8) -> (i8, i8) {
+    let (quo, rem) = u8_div_rem_avr(duo.unsigned_abs(), div.unsigned_abs());
+    let mut quo = quo as i8;
+    let mut rem = rem as i8;
+    if duo < 0 {
+        rem = rem.wrapping_neg();
+    }
+    if (duo < 0) != (div < 0) {
+        quo = quo.wrapping_neg();
+    }
+    (quo, rem)
+}
+
+// the `Option` returning versions of the algorithms, for fallible code paths
+#[cfg(feature = "binary-long")]
+impl_checked_div_rem!(
+    u8_checked_div_rem_binary_long,
+    i8_checked_div_rem_binary_long,
+    u8_div_rem_binary_long,
+    i8_div_rem_binary_long,
+    u8,
+    i8,
+    inline;
+    inline
+);
+#[cfg(feature = "table")]
+impl_checked_div_rem!(
+    u8_checked_div_rem_table,
+    i8_checked_div_rem_table,
+    u8_div_rem_table,
+    i8_div_rem_table,
+    u8,
+    i8,
+    inline;
+    inline
+);
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+impl_checked_div_rem!(
+    u8_checked_div_rem_avr,
+    i8_checked_div_rem_avr,
+    u8_div_rem_avr,
+    i8_div_rem_avr,
+    u8,
+    i8,
+    inline;
+    inline
+);
+
+// floored division with the algorithms, where the remainder has the sign of the divisor
+#[cfg(feature = "binary-long")]
+impl_floor_div_rem!(
+    i8_div_rem_floor_binary_long,
+    u8_div_rem_binary_long,
+    u8,
+    i8,
+    inline,
+    track_caller
+);
+#[cfg(feature = "table")]
+impl_floor_div_rem!(
+    i8_div_rem_floor_table,
+    u8_div_rem_table,
+    u8,
+    i8,
+    inline,
+    track_caller
+);
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+impl_floor_div_rem!(
+    i8_div_rem_floor_avr,
+    u8_div_rem_avr,
+    u8,
+    i8,
+    inline,
+    track_caller
+);
+
+// ceiling division with the algorithms
+#[cfg(feature = "binary-long")]
+impl_ceil_div_rem!(
+    u8_div_rem_ceil_binary_long,
+    u8_div_ceil_binary_long,
+    i8_div_rem_ceil_binary_long,
+    i8_div_ceil_binary_long,
+    u8_div_rem_binary_long,
+    u8,
+    i8,
+    inline,
+    track_caller
+);
+#[cfg(feature = "table")]
+impl_ceil_div_rem!(
+    u8_div_rem_ceil_table,
+    u8_div_ceil_table,
+    i8_div_rem_ceil_table,
+    i8_div_ceil_table,
+    u8_div_rem_table,
+    u8,
+    i8,
+    inline,
+    track_caller
+);
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+impl_ceil_div_rem!(
+    u8_div_rem_ceil_avr,
+    u8_div_ceil_avr,
+    i8_div_rem_ceil_avr,
+    i8_div_ceil_avr,
+    u8_div_rem_avr,
+    u8,
+    i8,
+    inline,
+    track_caller
+);
+
+// saturating signed division with the algorithms
+#[cfg(feature = "binary-long")]
+impl_saturating_div_rem!(
+    i8_saturating_div_rem_binary_long,
+    i8_saturating_div_binary_long,
+    i8_div_rem_binary_long,
+    i8,
+    inline,
+    track_caller
+);
+#[cfg(feature = "table")]
+impl_saturating_div_rem!(
+    i8_saturating_div_rem_table,
+    i8_saturating_div_table,
+    i8_div_rem_table,
+    i8,
+    inline,
+    track_caller
+);
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+impl_saturating_div_rem!(
+    i8_saturating_div_rem_avr,
+    i8_saturating_div_avr,
+    i8_div_rem_avr,
+    i8,
+    inline,
+    track_caller
+);
+
+// fallible division with the algorithms
+#[cfg(feature = "binary-long")]
+impl_try_div_rem!(
+    u8_try_div_rem_binary_long,
+    i8_try_div_rem_binary_long,
+    u8_div_rem_binary_long,
+    i8_div_rem_binary_long,
+    u8,
+    i8,
+    inline;
+    inline
+);
+#[cfg(feature = "table")]
+impl_try_div_rem!(
+    u8_try_div_rem_table,
+    i8_try_div_rem_table,
+    u8_div_rem_table,
+    i8_div_rem_table,
+    u8,
+    i8,
+    inline;
+    inline
+);
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"))]
+impl_try_div_rem!(
+    u8_try_div_rem_avr,
+    i8_try_div_rem_avr,
+    u8_div_rem_avr,
+    i8_div_rem_avr,
+    u8,
+    i8,
+    inline;
+    inline
+);
+
+// The quotient-only versions of the algorithms. These instantiate the algorithms again with
+// `inline(always)`, so that the instructions only needed for the remainder are eliminated.
+mod inliner {
+    #[allow(unused_imports)]
+    use super::*;
+
+    mod imp {
+        #[allow(unused_imports)]
+        use super::*;
+
+        #[cfg(feature = "binary-long")]
+        impl_binary_long!(
+            u8_div_rem_binary_long_inline,
+            i8_div_rem_binary_long_inline,
+            zero_div_fn,
+            u8_normalization_shift,
+            8,
+            u8,
+            i8,
+            inline(always),
+            track_caller;
+            inline(always),
+            track_caller
+        );
+    }
+    #[allow(unused_imports)]
+    use imp::*;
+
+    #[cfg(feature = "binary-long")]
+    impl_div!(
+        u8_div_binary_long,
+        i8_div_binary_long,
+        u8_div_rem_binary_long_inline,
+        i8_div_rem_binary_long_inline,
+        u8,
+        i8,
+        track_caller;
+        track_caller
+    );
+}
+
+#[allow(unused_imports)]
+pub use inliner::*;
@@ -0,0 +1,59 @@
+//! Loop iteration counters used by the division macros through `$crate::instrument`, so that tests
+//! can check the documented upper bounds on the number of iterations and catch complexity
+//! regressions, not just wrong answers. The counters only exist with the `instrument` feature, and
+//! `step` is a no-op otherwise.
+
+/// The loops of the division algorithms that are counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Loop {
+    /// The central loop of `impl_binary_long`
+    BinaryLong,
+    /// The binary long division loops of `impl_delegate`
+    Delegate,
+    /// The undersubtracting long division steps of `impl_trifecta`
+    Undersubtracting,
+}
+
+#[cfg(feature = "instrument")]
+std::thread_local! {
+    static COUNTS: core::cell::Cell<[u32; 3]> = const { core::cell::Cell::new([0; 3]) };
+}
+
+/// Counts one iteration of `l` on the current thread
+#[inline(always)]
+pub fn step(l: Loop) {
+    #[cfg(feature = "instrument")]
+    COUNTS.with(|counts| {
+        let mut tmp = counts.get();
+        tmp[l as usize] += 1;
+        counts.set(tmp);
+    });
+    #[cfg(not(feature = "instrument"))]
+    let _ = l;
+}
+
+/// Counts `n` iterations of `l` on the current thread, for loops written in assembly
+#[inline(always)]
+pub fn steps(l: Loop, n: u32) {
+    #[cfg(feature = "instrument")]
+    COUNTS.with(|counts| {
+        let mut tmp = counts.get();
+        tmp[l as usize] += n;
+        counts.set(tmp);
+    });
+    #[cfg(not(feature = "instrument"))]
+    let _ = (l, n);
+}
+
+/// Returns the number of iterations of `l` counted on the current thread since the last call, and
+/// resets the count to zero
+#[cfg(feature = "instrument")]
+pub fn take(l: Loop) -> u32 {
+    COUNTS.with(|counts| {
+        let mut tmp = counts.get();
+        let count = tmp[l as usize];
+        tmp[l as usize] = 0;
+        counts.set(tmp);
+        count
+    })
+}
@@ -0,0 +1,68 @@
+/// Creates a type for calculating remainders by an invariant divisor entirely with
+/// multiplications, without a quotient ever being computed. This is for loops dominated by modulo
+/// operations, where even the register pressure of producing the quotient hurts.
+#[macro_export]
+macro_rules! impl_invariant_rem {
+    (
+        $name:ident, // name of the invariant remainder struct
+        $zero_div_fn:ident, // function called when division by zero is attempted
+        $half_division:ident, // function for division of a $uX by a $uX
+        $asymmetric_division:ident, // unsafe function for division of a $uD by a $uX
+        $n:expr, // the number of bits in a $uX
+        $uX:ident, // unsigned integer type for the dividend, divisor, and remainder
+        $uD:ident, // unsigned integer with double the bit width of $uX
+    ) => {
+        /// An invariant divisor with a precomputed reciprocal for calculating remainders with only
+        /// multiplications.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name {
+            div: $uX,
+            recip: $uD,
+        }
+
+        impl $name {
+            /// Precomputes the reciprocal of `div`. This involves a division, so it is only worth
+            /// it if many remainders by `div` are calculated.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `div == 0`.
+            #[track_caller]
+            pub fn new(div: $uX) -> Self {
+                if div == 0 {
+                    $zero_div_fn()
+                }
+                // `recip = ceil(2^(2*n) / div)`, calculated as `$uD::MAX / div + 1` with a short
+                // division. When `div == 1` the increment wraps `recip` to 0, which happens to
+                // still produce the correct remainders of 0.
+                let (quo_hi, rem_hi) = $half_division($uX::MAX, div);
+                // `rem_hi < div`, so the quotient fits in a `$uX`
+                let (quo_lo, _) = $crate::unchecked_call!($asymmetric_division(
+                    ($uX::MAX as $uD) | ((rem_hi as $uD) << $n),
+                    div
+                ));
+                let recip = ((quo_lo as $uD) | ((quo_hi as $uD) << $n)).wrapping_add(1);
+                Self { div, recip }
+            }
+
+            /// Returns the divisor
+            pub fn divisor(self) -> $uX {
+                self.div
+            }
+
+            /// Returns `duo % self.divisor()`
+            pub fn rem_of(self, duo: $uX) -> $uX {
+                // This is the "fastmod" algorithm from "Faster Remainder by Direct Computation"
+                // (Lemire, Kaser, Kurz). The lower bits of `recip * duo` are the fractional part
+                // of `duo / div` with enough precision that multiplying them by `div` and keeping
+                // the integer part gives the exact remainder.
+                let frac = self.recip.wrapping_mul(duo as $uD);
+                // the high `$uX` of the `$uD` by `$uX` multiplication `frac * div`
+                let frac_lo = frac as $uX;
+                let frac_hi = (frac >> $n) as $uX;
+                let carry = ((frac_lo as $uD) * (self.div as $uD)) >> $n;
+                (((frac_hi as $uD) * (self.div as $uD) + carry) >> $n) as $uX
+            }
+        }
+    };
+}
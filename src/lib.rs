@@ -3,13 +3,54 @@
 // `compiler-builtins` which cannot use `memcpy`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "branch-hints", feature(core_intrinsics))]
+#![cfg_attr(feature = "branch-hints", allow(internal_features))]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
+// inline assembly on AVR requires a nightly compiler, like the AVR target itself
+#![cfg_attr(
+    all(feature = "asm", not(feature = "no-unsafe"), target_arch = "avr"),
+    feature(asm_experimental_arch)
+)]
+// this makes sure that `no-unsafe` really removes every use of `unsafe`
+#![cfg_attr(feature = "no-unsafe", forbid(unsafe_code))]
+
+#[cfg(all(feature = "no-unsafe", feature = "export-aeabi"))]
+compile_error!(
+    "the `export-aeabi` feature needs `unsafe` code and cannot be used with `no-unsafe`"
+);
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[doc(hidden)]
+pub mod hint;
+
+#[macro_use]
+mod unchecked;
+
+#[doc(hidden)]
+#[macro_use]
+pub mod cast;
+
+#[doc(hidden)]
+pub mod instrument;
+
+mod rng;
+// for the fuzzers of the test macros
+#[doc(hidden)]
+pub use rng::{Random, Rng};
 
 #[macro_use]
 mod test;
+#[cfg(feature = "std")]
+pub use test::fuzz_rng;
 
 #[macro_use]
 mod norm_shift;
 
+#[macro_use]
+mod bit_scan;
+
 #[macro_use]
 mod binary_long;
 
@@ -22,7 +63,80 @@ mod trifecta;
 #[macro_use]
 mod asymmetric;
 
-#[cfg(feature = "implement")]
+#[macro_use]
+mod float_reciprocal;
+
+#[macro_use]
+mod variants;
+pub use variants::{DivError, Round};
+
+#[macro_use]
+mod normalized;
+
+#[macro_use]
+mod gcd;
+
+#[macro_use]
+mod ratio;
+
+#[macro_use]
+mod invariant_rem;
+
+#[macro_use]
+mod magic_table;
+
+#[macro_use]
+mod precomputed;
+
+#[macro_use]
+mod exact;
+
+#[macro_use]
+mod factor;
+
+#[macro_use]
+mod miller_rabin;
+
+#[macro_use]
+mod mod_sqrt;
+
+#[macro_use]
+mod mod_inv;
+
+#[macro_use]
+mod backend;
+pub use backend::DivRemBackend;
+
+#[macro_use]
+mod fold;
+
+#[macro_use]
+mod root;
+
+#[macro_use]
+mod significand;
+pub use significand::RoundBits;
+
+#[cfg(any(
+    feature = "width-8",
+    feature = "width-16",
+    feature = "width-32",
+    feature = "width-64",
+    feature = "width-128",
+    feature = "width-256",
+    feature = "width-512",
+    feature = "bignum"
+))]
 mod implement;
-#[cfg(feature = "implement")]
+#[cfg(any(
+    feature = "width-8",
+    feature = "width-16",
+    feature = "width-32",
+    feature = "width-64",
+    feature = "width-128",
+    feature = "width-256",
+    feature = "width-512",
+    feature = "bignum"
+))]
+#[allow(unused_imports)]
 pub use implement::*;
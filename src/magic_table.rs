@@ -0,0 +1,85 @@
+/// Creates a table of magic multipliers and shifts for all divisors in a range, which can be built
+/// at compile time by `new` being a `const fn`. This is for interpreters and codecs that divide by
+/// many different small divisors, where a single invariant divisor type does not help.
+#[macro_export]
+macro_rules! impl_magic_table {
+    (
+        $name:ident, // name of the magic table struct
+        $n:expr, // the number of bits in a $uX
+        $uX:ident, // unsigned integer type for the dividend, divisor, and quotient
+        $uD:ident, // unsigned integer with double the bit width of $uX
+    ) => {
+        /// A table of `(multiplier, shift)` pairs for the `LEN` divisors starting at `start`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name<const LEN: usize> {
+            start: $uX,
+            magic: [($uX, u32); LEN],
+        }
+
+        impl<const LEN: usize> $name<LEN> {
+            /// Computes the magic numbers for the divisors `start..(start + LEN)`. This is intended
+            /// to be evaluated in a `const` or `static`, such as
+            /// `static TABLE: U32MagicTable<4096> = U32MagicTable::new(1);`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `start == 0` or if `start + LEN - 1` overflows.
+            pub const fn new(start: $uX) -> Self {
+                if start == 0 {
+                    panic!("attempt to divide by zero")
+                }
+                let mut magic = [(0, 0); LEN];
+                let mut i = 0;
+                while i < LEN {
+                    // `start + (i as $uX)` would silently wrap in release builds of runtime calls
+                    let div = match start.checked_add(i as $uX) {
+                        Some(div) => div,
+                        None => panic!("attempt to add with overflow"),
+                    };
+                    // This is the round up method from "Division by Invariant Integers using
+                    // Multiplication" (Granlund, Montgomery). With `shift = ceil(log2(div))`, the
+                    // multiplier `m = ceil(2^(n + shift) / div)` produces exact quotients for all
+                    // dividends. `2^n <= m <= 2^(n + 1)`, and the `2^n` part is implicit so that
+                    // the stored multiplier fits in a `$uX`. `m - 2^n` is calculated as
+                    // `ceil(2^n * (2^shift - div) / div)` to avoid overflowing a `$uD`.
+                    let shift = $n - (div - 1).leading_zeros();
+                    let num = (((1 as $uD) << shift) - (div as $uD)) << $n;
+                    let mul = num.div_ceil(div as $uD);
+                    magic[i] = (mul as $uX, shift);
+                    i += 1;
+                }
+                Self { start, magic }
+            }
+
+            /// Returns the first divisor in the table
+            pub const fn start(&self) -> $uX {
+                self.start
+            }
+
+            /// Returns the `(multiplier, shift)` pair for `div`, or `None` if `div` is not in the
+            /// table.
+            pub const fn get(&self, div: $uX) -> Option<($uX, u32)> {
+                if div < self.start || ((div - self.start) as usize) >= LEN {
+                    return None;
+                }
+                Some(self.magic[(div - self.start) as usize])
+            }
+
+            /// Returns `duo / div` calculated with the magic numbers for `div`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `div` is not in the table.
+            #[track_caller]
+            pub fn div_by_table(&self, duo: $uX, div: $uX) -> $uX {
+                let (mul, shift) = match self.get(div) {
+                    Some(magic) => magic,
+                    None => panic!("divisor is not in the table"),
+                };
+                // `(duo * m) >> (n + shift)` with the implicit `2^n` part of `m` added back
+                let tmp = ((duo as $uD) * (mul as $uD)) >> $n;
+                ((tmp + (duo as $uD)) >> shift) as $uX
+            }
+        }
+    };
+}
@@ -0,0 +1,92 @@
+/// Creates modular exponentiation and Miller-Rabin primality tests on top of a modular
+/// multiplication function.
+#[macro_export]
+macro_rules! impl_miller_rabin {
+    (
+        $pow_mod:ident, // name of the modular exponentiation function
+        $is_probable_prime:ident, // name of the Miller-Rabin test with caller chosen witnesses
+        $is_prime:ident, // name of the primality test with the fixed witness set
+        $mul_mod:ident, // function for `(a * b) % m` without overflow
+        $uX:ident, // unsigned integer type for the inputs
+    ) => {
+        /// Computes `base^exp % m` by square and multiply.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `m == 0`.
+        pub fn $pow_mod(base: $uX, exp: $uX, m: $uX) -> $uX {
+            // also reduces `base` and checks for `m == 0`
+            let mut base = $mul_mod(base, 1, m);
+            if m == 1 {
+                return 0;
+            }
+            let mut exp = exp;
+            let mut res = 1;
+            loop {
+                if (exp & 1) != 0 {
+                    res = $mul_mod(res, base, m);
+                }
+                exp >>= 1;
+                if exp == 0 {
+                    return res;
+                }
+                base = $mul_mod(base, base, m);
+            }
+        }
+
+        /// Runs the Miller-Rabin test on `x` with each of the `witnesses`. Returns `false` if `x`
+        /// is definitely composite, and `true` if `x` is prime or a strong pseudoprime to all of
+        /// the witnesses. Witnesses that are multiples of `x` are skipped.
+        pub fn $is_probable_prime(x: $uX, witnesses: &[$uX]) -> bool {
+            if x < 4 {
+                return x >= 2;
+            }
+            if (x & 1) == 0 {
+                return false;
+            }
+            // `x - 1 == d * 2^s` with `d` odd
+            let s = (x - 1).trailing_zeros();
+            let d = (x - 1) >> s;
+            let mut i = 0;
+            'witness: while i < witnesses.len() {
+                let a = witnesses[i];
+                i += 1;
+                let mut y = $pow_mod(a, d, x);
+                if y == 0 || y == 1 || y == x - 1 {
+                    continue;
+                }
+                let mut j = 1;
+                while j < s {
+                    y = $mul_mod(y, y, x);
+                    if y == x - 1 {
+                        continue 'witness;
+                    }
+                    j += 1;
+                }
+                return false;
+            }
+            true
+        }
+
+        /// Returns if `x` is prime, with trial division by the primes up to 37 followed by the
+        /// Miller-Rabin test with those primes as witnesses. This is deterministic for all
+        /// `x < 318_665_857_834_031_151_167_461` (which is greater than `2^78`, so this covers
+        /// every `u64`). Above that, this is only a strong probable prime test, and
+        /// `$is_probable_prime` can be used with more witnesses.
+        pub fn $is_prime(x: $uX) -> bool {
+            const WITNESSES: [$uX; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+            let mut i = 0;
+            while i < WITNESSES.len() {
+                let p = WITNESSES[i];
+                if x == p {
+                    return true;
+                }
+                if $mul_mod(x, 1, p) == 0 {
+                    return false;
+                }
+                i += 1;
+            }
+            $is_probable_prime(x, &WITNESSES)
+        }
+    };
+}
@@ -0,0 +1,114 @@
+/// Creates a modular inverse function using the extended Euclidean algorithm on top of a division
+/// function from this crate, and a batch inversion function using Montgomery's trick on top of
+/// that.
+#[macro_export]
+macro_rules! impl_mod_inv {
+    (
+        $mod_inv:ident, // name of the modular inverse function
+        $batch_invert:ident, // name of the batch inversion function
+        $div_rem:ident, // function for division of a $uX by a $uX
+        $mul_mod:ident, // function for `(a * b) % m` without overflow
+        $uX:ident, // unsigned integer type for the inputs and outputs
+    ) => {
+        /// Returns the `x < m` with `(a * x) % m == 1 % m`, or `None` if `a` and `m` are not
+        /// coprime.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `m == 0`.
+        pub fn $mod_inv(a: $uX, m: $uX) -> Option<$uX> {
+            // also reduces `a` and checks for `m == 0`
+            let a = $mul_mod(a, 1, m);
+            if m == 1 {
+                return Some(0);
+            }
+            // The coefficients of `a` for the remainders alternate in sign, so only their
+            // magnitudes `u` are kept. They never exceed `m`, so `u0 + q * u1` cannot overflow.
+            let mut r0 = m;
+            let mut r1 = a;
+            let mut u0: $uX = 0;
+            let mut u1: $uX = 1;
+            let mut odd = false;
+            while r1 != 0 {
+                let (quo, rem) = $div_rem(r0, r1);
+                r0 = r1;
+                r1 = rem;
+                let tmp = u0 + (quo * u1);
+                u0 = u1;
+                u1 = tmp;
+                odd = !odd;
+            }
+            if r0 != 1 {
+                return None;
+            }
+            if odd {
+                Some(u0)
+            } else {
+                Some(m - u0)
+            }
+        }
+
+        /// Replaces each of the `values` with its inverse modulo `m`, using only one modular
+        /// inversion (and three modular multiplications per value) for every 64 values instead of
+        /// one inversion for each value. Returns `true` if all of the values were invertible.
+        /// Otherwise, the values without an inverse (such as 0) are set to 0, and `false` is
+        /// returned.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `m == 0`.
+        pub fn $batch_invert(values: &mut [$uX], m: $uX) -> bool {
+            // The prefix products are kept on the stack, so the values are inverted in chunks
+            const CHUNK: usize = 64;
+            // also checks for `m == 0`
+            let one = $mul_mod(1, 1, m);
+            let mut all = true;
+            let mut start = 0;
+            while start < values.len() {
+                let len = if (values.len() - start) < CHUNK {
+                    values.len() - start
+                } else {
+                    CHUNK
+                };
+                let mut prefix: [$uX; CHUNK] = [0; CHUNK];
+                let mut product = one;
+                let mut i = 0;
+                while i < len {
+                    product = $mul_mod(product, values[start + i], m);
+                    prefix[i] = product;
+                    i += 1;
+                }
+                match $mod_inv(product, m) {
+                    Some(mut inv) => {
+                        // `inv` is the inverse of `prefix[i]`, so `inv * prefix[i - 1]` is the
+                        // inverse of the `i`th value of the chunk, and `inv` times that value is
+                        // the inverse of `prefix[i - 1]`
+                        let mut i = len - 1;
+                        while i > 0 {
+                            let tmp = values[start + i];
+                            values[start + i] = $mul_mod(inv, prefix[i - 1], m);
+                            inv = $mul_mod(inv, tmp, m);
+                            i -= 1;
+                        }
+                        values[start] = inv;
+                    }
+                    None => {
+                        // at least one of the values has no inverse, so they are inverted
+                        // separately
+                        all = false;
+                        let mut i = 0;
+                        while i < len {
+                            values[start + i] = match $mod_inv(values[start + i], m) {
+                                Some(inv) => inv,
+                                None => 0,
+                            };
+                            i += 1;
+                        }
+                    }
+                }
+                start += len;
+            }
+            all
+        }
+    };
+}
@@ -0,0 +1,84 @@
+/// Creates a modular square root function using the Tonelli-Shanks algorithm on top of the
+/// modular arithmetic of `impl_miller_rabin`.
+#[macro_export]
+macro_rules! impl_mod_sqrt {
+    (
+        $name:ident, // name of the modular square root function
+        $pow_mod:ident, // function for `base^exp % m`
+        $mul_mod:ident, // function for `(a * b) % m` without overflow
+        $uX:ident, // unsigned integer type for the inputs and output
+    ) => {
+        /// Returns an `r` with `(r * r) % p == a % p` for the prime modulus `p`, or `None` if `a`
+        /// is a quadratic nonresidue modulo `p`. Of the two roots `r` and `p - r`, the smaller is
+        /// returned. If `p` is not prime, the result is unspecified, and so is the running time
+        /// (the search for a nonresidue can try up to `p` candidates).
+        ///
+        /// # Panics
+        ///
+        /// Panics if `p == 0`.
+        pub fn $name(a: $uX, p: $uX) -> Option<$uX> {
+            // also reduces `a` and checks for `p == 0`
+            let a = $mul_mod(a, 1, p);
+            if (a == 0) || (p == 2) {
+                return Some(a);
+            }
+            // Euler's criterion, which also rejects the even composite moduli
+            let half = (p - 1) >> 1;
+            if $pow_mod(a, half, p) != 1 {
+                return None;
+            }
+            let root = if (p & 0b11) == 0b11 {
+                // `a^((p + 1) / 4)` is a root when `p == 3 (mod 4)`, and `(p + 1) / 4` is
+                // computed without overflowing for `p == $uX::MAX`
+                $pow_mod(a, (p >> 2) + 1, p)
+            } else {
+                // `p - 1 == q * 2^s` with `q` odd
+                let s = (p - 1).trailing_zeros();
+                let q = (p - 1) >> s;
+                // half of the residues are nonresidues, so this search is short
+                let mut z: $uX = 2;
+                while $pow_mod(z, half, p) != (p - 1) {
+                    z += 1;
+                    if z == p {
+                        return None;
+                    }
+                }
+                // `c` generates the 2-Sylow subgroup, `t` is the part of `a^q` that is not yet
+                // accounted for, and `r^2 == a * t` is maintained
+                let mut m = s;
+                let mut c = $pow_mod(z, q, p);
+                let mut t = $pow_mod(a, q, p);
+                let mut r = $pow_mod(a, (q >> 1) + 1, p);
+                while t != 1 {
+                    // the least `i` with `t^(2^i) == 1`, which is less than `m` for prime `p`
+                    let mut i = 0;
+                    let mut tmp = t;
+                    while tmp != 1 {
+                        tmp = $mul_mod(tmp, tmp, p);
+                        i += 1;
+                        if i == m {
+                            return None;
+                        }
+                    }
+                    // `b = c^(2^(m - i - 1))`
+                    let mut b = c;
+                    let mut j = i + 1;
+                    while j < m {
+                        b = $mul_mod(b, b, p);
+                        j += 1;
+                    }
+                    m = i;
+                    c = $mul_mod(b, b, p);
+                    t = $mul_mod(t, c, p);
+                    r = $mul_mod(r, b, p);
+                }
+                r
+            };
+            if root > (p - root) {
+                Some(p - root)
+            } else {
+                Some(root)
+            }
+        }
+    };
+}
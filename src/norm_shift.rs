@@ -1,7 +1,14 @@
 /// Creates a function used by some division algorithms to compute the "normalization shift".
+///
+/// `const` can be put before `$name` to create a `const fn` for the primitive integers, which is
+/// how `impl_bit_scan` uses the software bisection.
 #[macro_export]
 macro_rules! impl_normalization_shift {
+    (const $($rest:tt)*) => {
+        $crate::impl_normalization_shift!(@fn [const] $($rest)*);
+    };
     (
+        @fn [$($qualifier:tt)*] // `const` or nothing
         $name:ident, // name of the normalization shift function
         // boolean for if `$uX::leading_zeros` should be used (if an architecture does not have a
         // hardware instruction for `usize::leading_zeros`, then this should be `true`)
@@ -46,7 +53,7 @@ macro_rules! impl_normalization_shift {
         $(
             #[$unsigned_attr]
         )*
-        fn $name(duo: $uX, div: $uX, full_normalization: bool) -> usize {
+        $($qualifier)* fn $name(duo: $uX, div: $uX, full_normalization: bool) -> usize {
             // We have to find the leading zeros of `div` to know where its msb (most significant
             // set bit) is to even begin binary long division. It is also good to know where the msb
             // of `duo` is so that useful work can be started instead of shifting `div` for all
@@ -102,5 +109,8 @@ macro_rules! impl_normalization_shift {
             */
             shl
         }
-    }
+    };
+    ($($rest:tt)*) => {
+        $crate::impl_normalization_shift!(@fn [] $($rest)*);
+    };
 }
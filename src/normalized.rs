@@ -0,0 +1,89 @@
+/// Creates division functions for divisors that are already normalized (the most significant bit
+/// of `div` is set), as maintained by the inner loops of multiple limb division. These skip the
+/// normalization shifts and the branches for small divisors that the general algorithms need.
+#[macro_export]
+macro_rules! impl_normalized {
+    (
+        $name:ident, // name of the `$uX` by `$uX` division function
+        $asymmetric_name:ident, // name of the `$uD` by `$uX` division function
+        $half_division:ident, // function for division of a $uX by a $uX
+        $n_h:expr, // the number of bits in a $uH
+        $uH:ident, // unsigned integer with half the bit width of $uX
+        $uX:ident, // unsigned integer with half the bit width of $uD
+        $uD:ident, // unsigned integer type for the dividend of `$asymmetric_name`
+        $($attr:meta),* // attributes for the functions
+    ) => {
+        $crate::unchecked_fn! {
+            /// Computes the quotient and remainder of `duo` divided by the normalized `div` and
+            /// returns them as a tuple. The quotient is always 0 or 1.
+            ///
+            /// # Safety
+            ///
+            /// The most significant bit of `div` must be set. This does not cause undefined
+            /// behavior otherwise, but the results are meaningless. The function is `unsafe` so
+            /// that callers have to uphold the precondition like for the hardware division
+            /// instructions.
+            $(
+                #[$attr]
+            )*
+            pub fn $name(duo: $uX, div: $uX) -> ($uX, $uX) {
+                debug_assert!((div >> ($n_h * 2 - 1)) != 0);
+                if duo >= div {
+                    (1, duo - div)
+                } else {
+                    (0, duo)
+                }
+            }
+        }
+
+        $crate::unchecked_fn! {
+            /// Computes the quotient and remainder of `duo` divided by the normalized `div` and
+            /// returns them as a tuple. This is one step of the long division of multiple limb
+            /// integers, with two digits of `$uH` (as in "Hacker's Delight" by Henry S. Warren, but
+            /// without the normalization shifts).
+            ///
+            /// # Safety
+            ///
+            /// The most significant bit of `div` must be set and the quotient must fit in a `$uX`
+            /// (`(duo >> $uX::BITS) < div`). This does not cause undefined behavior otherwise, but
+            /// the results are meaningless. The function is `unsafe` so that callers have to uphold
+            /// the preconditions like for the hardware division instructions.
+            $(
+                #[$attr]
+            )*
+            pub fn $asymmetric_name(duo: $uD, div: $uX) -> ($uX, $uX) {
+                let n: u32 = $n_h * 2;
+                debug_assert!((div >> (n - 1)) != 0);
+                debug_assert!($crate::cast!(duo >> n, $uX) < div);
+                let div_1 = div >> $n_h;
+                let div_0 = div as $uH as $uX;
+
+                // Estimates the next `$uH` digit of the quotient of `(rem << n_h) | duo_digit`
+                // divided by `div`, which is at most 2 too large because `div` is normalized, and
+                // corrects it. `rem < div` is maintained between the digits.
+                #[inline(always)]
+                fn digit(rem: $uX, duo_digit: $uX, div: $uX, div_1: $uX, div_0: $uX) -> ($uX, $uX) {
+                    let (mut quo, mut rem_hat) = $half_division(rem, div_1);
+                    while ((quo >> $n_h) != 0)
+                        || (quo.wrapping_mul(div_0) > ((rem_hat << $n_h) | duo_digit))
+                    {
+                        quo -= 1;
+                        rem_hat += div_1;
+                        if (rem_hat >> $n_h) != 0 {
+                            break
+                        }
+                    }
+                    // the true remainder is less than `div`, so the wrapping operations are exact
+                    let rem = ((rem << $n_h) | duo_digit).wrapping_sub(quo.wrapping_mul(div));
+                    (quo, rem)
+                }
+
+                let duo_lo = $crate::cast!(duo, $uX);
+                let duo_hi = $crate::cast!(duo >> n, $uX);
+                let (quo_1, rem) = digit(duo_hi, duo_lo >> $n_h, div, div_1, div_0);
+                let (quo_0, rem) = digit(rem, duo_lo as $uH as $uX, div, div_1, div_0);
+                ((quo_1 << $n_h) | quo_0, rem)
+            }
+        }
+    };
+}
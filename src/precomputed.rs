@@ -0,0 +1,277 @@
+/// Creates a type for dividing by an invariant divisor with a precomputed magic multiplier and
+/// shift, in the style of libdivide. After the magic numbers are computed once, every quotient
+/// costs a high multiplication and a few shifts and additions, with no division at all. This is
+/// for loops that divide many values by the same divisor that is only known at runtime.
+#[macro_export]
+macro_rules! impl_precomputed_divisor {
+    (
+        $name:ident, // name of the precomputed divisor struct
+        $slice_name:ident, // name of the function dividing whole slices
+        $zero_div_fn:ident, // `const` function called when division by zero is attempted
+        // `const` function that divides `hi * 2^$n` by a `div` greater than `hi`, returning a tuple
+        // of the quotient and the remainder
+        $shifted_division:path,
+        // `const` function for the full product of two `$uX`s as a tuple of halves, lower first
+        $widening_mul:path,
+        $n:expr, // the number of bits in a $uX
+        $uX:ident, // unsigned integer type for the dividend, divisor, quotient, and remainder
+    ) => {
+        /// An invariant divisor with a precomputed magic multiplier and shift for calculating
+        /// quotients and remainders with only multiplications.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name {
+            div: $uX,
+            // the multiplier without its implicit most significant bit, or 0 for powers of two
+            magic: $uX,
+            shift: u32,
+            // if the multiplier needs `$n + 1` bits
+            add: bool,
+        }
+
+        impl $name {
+            /// Precomputes the magic numbers of `div`. This involves a division, so it is only
+            /// worth it if many values are divided by `div`. This is a `const fn`, so divisors
+            /// known at compile time can have their magic numbers computed in a `const` or
+            /// `static`, such as
+            /// `const D: PrecomputedDivisor64 = PrecomputedDivisor64::new(1_000_000_007);`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `div == 0`.
+            #[track_caller]
+            pub const fn new(div: $uX) -> Self {
+                if div == 0 {
+                    $zero_div_fn()
+                }
+                // `floor(log2(div))`
+                let log = $n - 1 - div.leading_zeros();
+                if div.is_power_of_two() {
+                    return Self {
+                        div,
+                        magic: 0,
+                        shift: log,
+                        add: false,
+                    };
+                }
+                // This is the round up method from "Division by Invariant Integers using
+                // Multiplication" (Granlund, Montgomery) with the improvements of libdivide. The
+                // smallest multiplier `m = floor(2^($n + log) / div) + 1` is tried first, which
+                // produces exact quotients if its error `div - rem` is less than `2^log`.
+                // `2^log < div`, so the quotient fits in a `$uX`.
+                let (quo, rem) = $shifted_division((1 as $uX) << log, div);
+                let (magic, add) = if (div - rem) < ((1 as $uX) << log) {
+                    (quo.wrapping_add(1), false)
+                } else {
+                    // Otherwise, `m = floor(2^($n + log + 1) / div) + 1` is always exact but needs
+                    // `$n + 1` bits, whose most significant bit is implicit. The quotient and
+                    // remainder are doubled instead of dividing again.
+                    let mut quo = quo.wrapping_add(quo);
+                    let rem2 = rem.wrapping_add(rem);
+                    if (rem2 >= div) || (rem2 < rem) {
+                        quo = quo.wrapping_add(1);
+                    }
+                    (quo.wrapping_add(1), true)
+                };
+                Self {
+                    div,
+                    magic,
+                    shift: log,
+                    add,
+                }
+            }
+
+            /// Returns the divisor
+            pub const fn divisor(self) -> $uX {
+                self.div
+            }
+
+            /// Returns `duo / self.divisor()`
+            pub const fn div_of(self, duo: $uX) -> $uX {
+                if self.magic == 0 {
+                    return duo >> self.shift;
+                }
+                let quo = $widening_mul(duo, self.magic).1;
+                if self.add {
+                    // `(duo * m) >> ($n + log + 1)` with the implicit `2^$n` part of `m` added
+                    // back. `quo + duo` could overflow, so their average is calculated without
+                    // the carry and the extra shift is taken out of it.
+                    (((duo - quo) >> 1) + quo) >> self.shift
+                } else {
+                    quo >> self.shift
+                }
+            }
+
+            /// Returns `duo % self.divisor()`
+            pub const fn rem_of(self, duo: $uX) -> $uX {
+                self.div_rem_of(duo).1
+            }
+
+            /// Returns `(duo / self.divisor(), duo % self.divisor())`
+            pub const fn div_rem_of(self, duo: $uX) -> ($uX, $uX) {
+                let quo = self.div_of(duo);
+                (quo, duo - quo.wrapping_mul(self.div))
+            }
+        }
+
+        /// Divides every element of `duos` by `div`, and writes the quotients to `quos` and the
+        /// remainders to `rems`. The magic numbers of `div` are computed once for the whole slice.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0` or if the lengths of the slices differ.
+        #[track_caller]
+        pub fn $slice_name(duos: &[$uX], div: $uX, quos: &mut [$uX], rems: &mut [$uX]) {
+            assert!(
+                (duos.len() == quos.len()) && (duos.len() == rems.len()),
+                "the lengths of the slices differ"
+            );
+            let pre = $name::new(div);
+            for ((duo, quo), rem) in duos.iter().zip(quos.iter_mut()).zip(rems.iter_mut()) {
+                (*quo, *rem) = pre.div_rem_of(*duo);
+            }
+        }
+    };
+}
+
+/// Creates a type for dividing signed integers by an invariant divisor with a precomputed magic
+/// multiplier and shift, like `impl_precomputed_divisor`. The quotients round toward zero like the
+/// `/` operator, using the corrections of the signed algorithms of libdivide.
+#[macro_export]
+macro_rules! impl_signed_precomputed_divisor {
+    (
+        $name:ident, // name of the precomputed divisor struct
+        $zero_div_fn:ident, // `const` function called when division by zero is attempted
+        // `const` function that divides `hi * 2^$n` by a `div` greater than `hi`, returning a tuple
+        // of the quotient and the remainder
+        $shifted_division:path,
+        // `const` function for the full product of two `$uX`s as a tuple of halves, lower first
+        $widening_mul:path,
+        $n:expr, // the number of bits in a $uX
+        $uX:ident, // unsigned version of $iX
+        $iX:ident, // signed integer type for the dividend, divisor, quotient, and remainder
+    ) => {
+        /// An invariant signed divisor with a precomputed magic multiplier and shift for
+        /// calculating quotients and remainders with only multiplications.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name {
+            div: $iX,
+            // the multiplier with the sign of `div`, or 0 for powers of two
+            magic: $iX,
+            shift: u32,
+            // if the dividend has to be added to the high product
+            add: bool,
+            negative: bool,
+        }
+
+        impl $name {
+            /// Precomputes the magic numbers of `div`. This involves a division, so it is only
+            /// worth it if many values are divided by `div`. Like for the unsigned types, this is a
+            /// `const fn`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `div == 0`.
+            #[track_caller]
+            pub const fn new(div: $iX) -> Self {
+                if div == 0 {
+                    $zero_div_fn()
+                }
+                let negative = div < 0;
+                let abs = div.unsigned_abs();
+                // `floor(log2(abs))`
+                let log = $n - 1 - abs.leading_zeros();
+                if abs.is_power_of_two() {
+                    return Self {
+                        div,
+                        magic: 0,
+                        shift: log,
+                        add: false,
+                        negative,
+                    };
+                }
+                // The same derivation as for the unsigned types, but with one bit less because
+                // the magnitude of the dividend is at most `2^($n - 1)`. `abs` is at least 3, so
+                // `log` is at least 1.
+                let (quo, rem) = $shifted_division((1 as $uX) << (log - 1), abs);
+                let (magic, shift, add) = if (abs - rem) < ((1 as $uX) << log) {
+                    (quo.wrapping_add(1), log - 1, false)
+                } else {
+                    // The multiplier needs `$n` bits and does not fit as a positive `$iX`, so the
+                    // `2^$n` that it is off by is compensated for by adding the dividend.
+                    let mut quo = quo.wrapping_add(quo);
+                    let rem2 = rem.wrapping_add(rem);
+                    if (rem2 >= abs) || (rem2 < rem) {
+                        quo = quo.wrapping_add(1);
+                    }
+                    (quo.wrapping_add(1), log, true)
+                };
+                let magic = if negative {
+                    magic.wrapping_neg()
+                } else {
+                    magic
+                };
+                Self {
+                    div,
+                    magic: magic as $iX,
+                    shift,
+                    add,
+                    negative,
+                }
+            }
+
+            /// Returns the divisor
+            pub const fn divisor(self) -> $iX {
+                self.div
+            }
+
+            /// Returns `duo / self.divisor()`, rounded toward zero. `$iX::MIN / -1` wraps around
+            /// to `$iX::MIN`.
+            pub const fn div_of(self, duo: $iX) -> $iX {
+                if self.magic == 0 {
+                    // `2^shift - 1` is added to negative dividends, so that the arithmetic shift
+                    // rounds toward zero instead of toward negative infinity
+                    let mask = ((1 as $uX) << self.shift).wrapping_sub(1);
+                    let tmp = (duo as $uX).wrapping_add(((duo >> ($n - 1)) as $uX) & mask);
+                    let quo = (tmp as $iX) >> self.shift;
+                    return if self.negative {
+                        quo.wrapping_neg()
+                    } else {
+                        quo
+                    };
+                }
+                // the high half of the signed product `magic * duo`, from the unsigned product
+                let (_, hi) = $widening_mul(self.magic as $uX, duo as $uX);
+                let mut quo = hi as $iX;
+                if self.magic < 0 {
+                    quo = quo.wrapping_sub(duo);
+                }
+                if duo < 0 {
+                    quo = quo.wrapping_sub(self.magic);
+                }
+                if self.add {
+                    quo = if self.negative {
+                        quo.wrapping_sub(duo)
+                    } else {
+                        quo.wrapping_add(duo)
+                    };
+                }
+                quo >>= self.shift;
+                // the arithmetic shift rounds toward negative infinity, so negative quotients are
+                // incremented to round toward zero
+                quo.wrapping_add(((quo as $uX) >> ($n - 1)) as $iX)
+            }
+
+            /// Returns `duo % self.divisor()`. `$iX::MIN % -1` is 0.
+            pub const fn rem_of(self, duo: $iX) -> $iX {
+                self.div_rem_of(duo).1
+            }
+
+            /// Returns `(duo / self.divisor(), duo % self.divisor())`. `$iX::MIN / -1` wraps
+            /// around to `($iX::MIN, 0)`.
+            pub const fn div_rem_of(self, duo: $iX) -> ($iX, $iX) {
+                let quo = self.div_of(duo);
+                (quo, duo.wrapping_sub(quo.wrapping_mul(self.div)))
+            }
+        }
+    };
+}
@@ -0,0 +1,189 @@
+/// Creates a minimal, allocation-free rational number type for exact fractions of unsigned
+/// integers. Comparisons are done by cross multiplication with widening multiplies, so they never
+/// overflow and never need to reduce the fractions first.
+#[macro_export]
+macro_rules! impl_ratio {
+    (
+        $name:ident, // name of the ratio struct
+        $zero_div_fn:ident, // function called when division by zero is attempted
+        $gcd:ident, // function for the greatest common divisor of two $uX
+        $div_rem:ident, // function for division of a $uX by a $uX
+        $n_h:expr, // the number of bits in a $uH
+        $uH:ident, // unsigned integer with half the bit width of $uX
+        $uX:ident, // unsigned integer type for the numerator and denominator
+    ) => {
+        /// The fraction `num / den`. The denominator should never be zero, which `new` checks for.
+        /// Fractions are not automatically reduced, but `==` and the other comparisons are done on
+        /// the values of the fractions and not their representations.
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name {
+            /// The numerator
+            pub num: $uX,
+            /// The denominator
+            pub den: $uX,
+        }
+
+        impl $name {
+            /// Creates the fraction `num / den`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `den == 0`.
+            #[track_caller]
+            pub fn new(num: $uX, den: $uX) -> Self {
+                if den == 0 {
+                    $zero_div_fn()
+                }
+                Self { num, den }
+            }
+
+            /// Returns the equivalent fraction with the numerator and denominator in lowest terms.
+            pub fn reduce(self) -> Self {
+                // `den != 0`, so `gcd != 0`
+                let gcd = $gcd(self.num, self.den);
+                Self {
+                    num: $div_rem(self.num, gcd).0,
+                    den: $div_rem(self.den, gcd).0,
+                }
+            }
+
+            /// Divides the fraction by the integer `rhs` and returns a tuple of the floored
+            /// quotient and the remainder. The remainder keeps the denominator of `self` and is
+            /// always less than `rhs`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `rhs == 0`.
+            #[track_caller]
+            pub fn div_rem(self, rhs: $uX) -> ($uX, Self) {
+                if rhs == 0 {
+                    $zero_div_fn()
+                }
+                // The integer part is split off first so that `den * rhs` is never needed. Because
+                // `(whole % rhs) <= whole`, the new numerator is at most `self.num` and cannot
+                // overflow.
+                let (whole, frac) = $div_rem(self.num, self.den);
+                let (quo, rem) = $div_rem(whole, rhs);
+                (
+                    quo,
+                    Self {
+                        num: (rem * self.den) + frac,
+                        den: self.den,
+                    },
+                )
+            }
+
+            /// Returns the closest fraction to `self` with a denominator at most `max_den`, in
+            /// lowest terms. If two fractions are equally close, the one with the smaller
+            /// denominator is returned. This walks the continued fraction expansion of `self`
+            /// (the same loop as `$crate::impl_continued_fraction`) and checks the best
+            /// semiconvergent when the denominators would exceed `max_den`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `max_den == 0`.
+            #[track_caller]
+            pub fn best_rational_approx(self, max_den: $uX) -> Self {
+                if max_den == 0 {
+                    panic!("`max_den` is zero")
+                }
+                let mut num = self.num;
+                let mut den = self.den;
+                // the previous two convergents `h0 / k0` and `h1 / k1`, starting from `0 / 1` and
+                // `1 / 0`
+                let (mut h0, mut k0): ($uX, $uX) = (0, 1);
+                let (mut h1, mut k1): ($uX, $uX) = (1, 0);
+                loop {
+                    if den == 0 {
+                        // the expansion ended with `h1 / k1 == self`
+                        return Self { num: h1, den: k1 };
+                    }
+                    let (a, rem) = $div_rem(num, den);
+                    // `k1 == 0` only happens for the first quotient, where the new denominator is
+                    // 1. Otherwise, `t` is the largest multiplier for which `t * k1 + k0` does
+                    // not exceed `max_den`.
+                    let t = if k1 == 0 {
+                        a
+                    } else {
+                        $div_rem(max_den - k0, k1).0
+                    };
+                    if t < a {
+                        // The convergents stop here. With `x` being the complete quotient
+                        // `num / den`, the semiconvergent `(t * h1 + h0) / (t * k1 + k0)` is
+                        // closer than `h1 / k1` if and only if `x - t < t + (k0 / k1)`.
+                        let semi_num = (t * h1) + h0;
+                        let semi_den = (t * k1) + k0;
+                        let lhs = Self {
+                            num: num - (t * den),
+                            den,
+                        };
+                        let rhs = Self {
+                            num: semi_den,
+                            den: k1,
+                        };
+                        if lhs < rhs {
+                            return Self {
+                                num: semi_num,
+                                den: semi_den,
+                            };
+                        } else {
+                            return Self { num: h1, den: k1 };
+                        }
+                    }
+                    // The convergents are bounded by `self.num` and `self.den` in lowest terms, so
+                    // they cannot overflow
+                    let h2 = (a * h1) + h0;
+                    let k2 = (a * k1) + k0;
+                    h0 = h1;
+                    k0 = k1;
+                    h1 = h2;
+                    k1 = k2;
+                    num = den;
+                    den = rem;
+                }
+            }
+
+            /// Returns the full product of `lhs` and `rhs` as a `(lo, hi)` tuple. This is only
+            /// composed of half sized multiplications, so it works for the largest integer types.
+            fn widening_mul(lhs: $uX, rhs: $uX) -> ($uX, $uX) {
+                let lhs_lo = lhs as $uH as $uX;
+                let lhs_hi = lhs >> $n_h;
+                let rhs_lo = rhs as $uH as $uX;
+                let rhs_hi = rhs >> $n_h;
+                let lo_lo = lhs_lo * rhs_lo;
+                let lo_hi = lhs_lo * rhs_hi;
+                let hi_lo = lhs_hi * rhs_lo;
+                let hi_hi = lhs_hi * rhs_hi;
+                // three `$uH` sized values cannot overflow a `$uX`
+                let mid = (lo_lo >> $n_h) + (lo_hi as $uH as $uX) + (hi_lo as $uH as $uX);
+                (
+                    (lo_lo as $uH as $uX) | (mid << $n_h),
+                    hi_hi + (lo_hi >> $n_h) + (hi_lo >> $n_h) + (mid >> $n_h),
+                )
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == core::cmp::Ordering::Equal
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                // `a/b < c/d` <=> `a*d < c*b` for positive denominators
+                let lhs = Self::widening_mul(self.num, other.den);
+                let rhs = Self::widening_mul(other.num, self.den);
+                (lhs.1, lhs.0).cmp(&(rhs.1, rhs.0))
+            }
+        }
+    };
+}
@@ -0,0 +1,91 @@
+//! A small pseudorandom number generator for the fuzzers of the test macros, so that crates using
+//! them do not need any extra dependencies and get the same numbers on every platform
+
+/// A seedable xorshift64* pseudorandom number generator. This is not cryptographically secure,
+/// but it is fast, works in `no_std`, and has more than enough quality for fuzzing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a generator from `seed`. Any seed can be used, including 0.
+    pub const fn new(seed: u64) -> Self {
+        // The seed is scrambled with the SplitMix64 finalizer so that similar seeds result in
+        // unrelated streams, and the all zeros state (which xorshift can never leave) is avoided.
+        let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        if z == 0 {
+            z = 0x9e37_79b9_7f4a_7c15;
+        }
+        Self { state: z }
+    }
+
+    /// Returns the next 64 random bits
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Returns a uniformly random value of type `T`
+    pub fn random<T: Random>(&mut self) -> T {
+        T::random(self)
+    }
+}
+
+/// Types that `Rng` can generate uniformly random values of
+pub trait Random {
+    /// Returns a uniformly random value using `rng`
+    fn random(rng: &mut Rng) -> Self;
+}
+
+macro_rules! impl_random {
+    ($($uX:ident, $iX:ident);+;) => {
+        $(
+            impl Random for $uX {
+                fn random(rng: &mut Rng) -> Self {
+                    // the upper bits of xorshift64* are better than the lower bits
+                    (rng.next_u64() >> (64 - $uX::BITS)) as $uX
+                }
+            }
+
+            impl Random for $iX {
+                fn random(rng: &mut Rng) -> Self {
+                    rng.random::<$uX>() as $iX
+                }
+            }
+        )+
+    };
+}
+
+impl_random!(
+    u8, i8;
+    u16, i16;
+    u32, i32;
+    u64, i64;
+    usize, isize;
+);
+
+impl Random for u128 {
+    fn random(rng: &mut Rng) -> Self {
+        ((rng.next_u64() as u128) << 64) | (rng.next_u64() as u128)
+    }
+}
+
+impl Random for i128 {
+    fn random(rng: &mut Rng) -> Self {
+        rng.random::<u128>() as i128
+    }
+}
+
+impl Random for bool {
+    fn random(rng: &mut Rng) -> Self {
+        (rng.next_u64() >> 63) != 0
+    }
+}
@@ -0,0 +1,68 @@
+/// Creates integer root functions using Newton's method over the integers on top of a division
+/// function from this crate.
+#[macro_export]
+macro_rules! impl_root {
+    (
+        $isqrt:ident, // name of the square root function
+        $icbrt:ident, // name of the cube root function
+        $nth_root:ident, // name of the general root function
+        $div_rem:ident, // function for division of a $uX by a $uX
+        $n:tt, // the number of bits in a $uX
+        $uX:ident, // unsigned integer type for the inputs and outputs
+    ) => {
+        /// Returns the floor of the `n`th root of `x`, which is the largest `y` with `y^n <= x`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `n == 0`.
+        #[track_caller]
+        pub fn $nth_root(x: $uX, n: u32) -> $uX {
+            assert!(n != 0, "the 0th root is undefined");
+            if (n == 1) || (x <= 1) {
+                return x;
+            }
+            let bits = $n - x.leading_zeros();
+            if n >= bits {
+                // `2^n > x`, so the root is 1
+                return 1;
+            }
+            // Newton's iteration `y = ((n - 1) * y + x / y^(n - 1)) / n` decreases monotonically
+            // to the floor of the root when started from above it (the integer version of the
+            // AM-GM inequality keeps it at or above the root). `2^(floor((bits - 1) / n) + 1)` is
+            // above the root, and at most `2^($n / 2)` because `2 <= n < bits <= $n`, so nothing
+            // below can overflow except for `y^(n - 1)`.
+            let mut y: $uX = 1 << (((bits - 1) / n) + 1);
+            loop {
+                // if `y^(n - 1)` overflows, `x / y^(n - 1)` is 0
+                let mut pow: $uX = 1;
+                let mut i = 1;
+                while i < n {
+                    match pow.checked_mul(y) {
+                        Some(tmp) => pow = tmp,
+                        None => {
+                            pow = 0;
+                            break;
+                        }
+                    }
+                    i += 1;
+                }
+                let quo = if pow == 0 { 0 } else { $div_rem(x, pow).0 };
+                let next = $div_rem(((n - 1) as $uX) * y + quo, n as $uX).0;
+                if next >= y {
+                    return y;
+                }
+                y = next;
+            }
+        }
+
+        /// Returns the floor of the square root of `x`
+        pub fn $isqrt(x: $uX) -> $uX {
+            $nth_root(x, 2)
+        }
+
+        /// Returns the floor of the cube root of `x`
+        pub fn $icbrt(x: $uX) -> $uX {
+            $nth_root(x, 3)
+        }
+    };
+}
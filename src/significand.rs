@@ -0,0 +1,122 @@
+/// The information about the rest of a truncated quotient that is needed for rounding it, as in
+/// the significand division of floating point numbers. The guard bit is the first bit below the
+/// truncated quotient, the round bit is the bit below that, and the sticky bit is set if any of
+/// the infinitely many bits below the round bit is set.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RoundBits {
+    /// The first bit below the truncated quotient
+    pub guard: bool,
+    /// The second bit below the truncated quotient
+    pub round: bool,
+    /// If any bit below the round bit is set (i.e. the remainder after the round bit is nonzero)
+    pub sticky: bool,
+}
+
+impl RoundBits {
+    /// Returns if the truncated quotient is exact
+    pub const fn is_exact(self) -> bool {
+        !(self.guard || self.round || self.sticky)
+    }
+
+    /// Returns if the truncated quotient has to be incremented to round it to nearest with ties to
+    /// even, where `odd` is the least significant bit of the truncated quotient.
+    pub const fn round_up_nearest_even(self, odd: bool) -> bool {
+        self.guard && (self.round || self.sticky || odd)
+    }
+}
+
+/// Creates a function that divides significands for soft float implementations (such as
+/// `__divsf3`, `__divdf3`, and `__divtf3`), returning the quotient truncated to a requested number
+/// of significant bits along with the `RoundBits` of the rest of the quotient.
+#[macro_export]
+macro_rules! impl_significand_div {
+    (
+        $name:ident, // name of the function
+        $zero_div_fn:ident, // function called when division by zero is attempted
+        $asymmetric_division:ident, // unsafe function for division of a $uX by a $uH
+        $n_h:expr, // the number of bits in a $uH
+        $uH:ident, // unsigned integer with half the bit width of $uX
+        $uX:ident, // unsigned integer type for the inputs and outputs
+        $($attr:meta),* // attributes for the function
+    ) => {
+        /// Computes the quotient of `duo` divided by `div` truncated to `bits` significant bits,
+        /// and returns a tuple of the truncated quotient `quo`, the exponent `exp`, and the
+        /// `RoundBits` of the rest of the quotient. The exact quotient is `(quo + rest) * 2^exp`
+        /// with `0 <= rest < 1`, and bit `bits - 1` is the most significant set bit of `quo`
+        /// unless `duo == 0`, in which case everything is zero.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`, or if `bits` is 0 or more than the bit width minus 2 (so that
+        /// the guard and round bits can be computed along with the quotient).
+        $(
+            #[$attr]
+        )*
+        pub fn $name(duo: $uX, div: $uX, bits: u32) -> ($uX, i32, $crate::RoundBits) {
+            let n: u32 = $n_h * 2;
+            if div == 0 {
+                $zero_div_fn()
+            }
+            assert!((bits != 0) && (bits <= (n - 2)), "`bits` is out of range");
+            if duo == 0 {
+                return (0, 0, $crate::RoundBits::default());
+            }
+            let duo_lz = duo.leading_zeros();
+            let div_lz = div.leading_zeros();
+            let duo = duo << duo_lz;
+            let div = div << div_lz;
+            let div_1 = div >> $n_h;
+            let div_0 = div as $uH as $uX;
+
+            // The quotient of the normalized operands is in `(1/2, 2)`, so its integer bit is
+            // found with a comparison, and then enough fractional bits are generated for `bits`
+            // significant bits plus the guard and round bits. This fits in a `$uX` because
+            // `bits <= n - 2`.
+            let (mut quo, mut rem, mut frac) = if duo >= div {
+                (1, duo - div, bits + 1)
+            } else {
+                (0, duo, bits + 2)
+            };
+            let exp = (div_lz as i32) - (duo_lz as i32) + 2 - (frac as i32);
+            let mut sticky = false;
+            while frac != 0 {
+                // Estimates the digit of `(rem << n_h) / div` from the upper half of `div`, which
+                // is at most 2 too large because `div` is normalized, and corrects it. `rem < div`
+                // is maintained between the digits, so the digit fits in a `$uH`.
+                let rem_1 = (rem >> $n_h) as $uH;
+                let (mut digit, mut rem_hat): ($uX, $uX) = if rem_1 < (div_1 as $uH) {
+                    // Safety: the quotient fits in a `$uH` because `rem_1 < div_1`
+                    let (quo, rem) = $crate::unchecked_call!($asymmetric_division(rem, div_1 as $uH));
+                    (quo as $uX, rem as $uX)
+                } else {
+                    // `rem_1 == div_1`, for which the estimate would overflow
+                    let digit = $uH::MAX as $uX;
+                    (digit, rem - digit * div_1)
+                };
+                while ((rem_hat >> $n_h) == 0) && (digit.wrapping_mul(div_0) > (rem_hat << $n_h)) {
+                    digit -= 1;
+                    rem_hat += div_1;
+                }
+                // the true remainder is less than `div`, so the wrapping operations are exact
+                rem = (rem << $n_h).wrapping_sub(digit.wrapping_mul(div));
+                if frac >= $n_h {
+                    quo = (quo << $n_h) | digit;
+                    frac -= $n_h;
+                } else {
+                    // only the upper `frac` bits of the last digit are needed, and the rest of
+                    // them are part of the sticky bit
+                    let shift = $n_h - frac;
+                    quo = (quo << frac) | (digit >> shift);
+                    sticky = (digit & ((1 << shift) - 1)) != 0;
+                    frac = 0;
+                }
+            }
+            let round_bits = $crate::RoundBits {
+                guard: (quo & 0b10) != 0,
+                round: (quo & 0b1) != 0,
+                sticky: sticky || (rem != 0),
+            };
+            (quo >> 2, exp, round_bits)
+        }
+    };
+}
@@ -1,4 +1,37 @@
-/// Creates multiple intensive test functions for division functions of a certain size
+/// Returns the `Rng` for the random fuzzer of a test created by the macros of this module, seeded
+/// from the `SPECIALIZED_DIV_REM_SEED` environment variable if it is set and randomly otherwise.
+/// The seed is printed along with `test_name`, which the test harness only shows if the test fails.
+#[doc(hidden)]
+#[cfg(feature = "std")]
+pub fn fuzz_rng(test_name: &str) -> crate::Rng {
+    let seed: u64 = match std::env::var("SPECIALIZED_DIV_REM_SEED") {
+        Ok(s) => s
+            .parse()
+            .expect("`SPECIALIZED_DIV_REM_SEED` should be a `u64`"),
+        Err(_) => {
+            // `RandomState` is randomly keyed on platforms that have a source of randomness, and
+            // fixed otherwise
+            use std::hash::{BuildHasher, Hasher};
+            std::collections::hash_map::RandomState::new()
+                .build_hasher()
+                .finish()
+        }
+    };
+    println!("{} seed: {}", test_name, seed);
+    crate::Rng::new(seed)
+}
+
+/// Creates multiple intensive test functions for division functions of a certain size. The crate
+/// using this macro needs `wasm-bindgen-test` as a dependency when testing on
+/// `wasm32-unknown-unknown`, and this crate needs the `std` feature. Random numbers come from this
+/// crate's `Rng`, so the fuzzing of a given seed is identical on every platform.
+///
+/// The randomized fuzzer runs for 1 million iterations by default. Setting the
+/// `SPECIALIZED_DIV_REM_SOAK` environment variable to a number of seconds enables a soak mode where
+/// the fuzzer keeps running in chunks of 1 million iterations until that much wall-clock time has
+/// passed, printing progress about once a minute (use `--nocapture` to see it). The seed of each
+/// test is printed and can be fixed with the `SPECIALIZED_DIV_REM_SEED` environment variable in
+/// order to reproduce failures.
 #[macro_export]
 macro_rules! test {
     (
@@ -10,11 +43,36 @@ macro_rules! test {
         $($test_name:ident, $unsigned_name:ident, $signed_name:ident);+;
     ) => {
         $(
-            #[test]
+            #[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+            #[cfg_attr(
+                all(target_arch = "wasm32", target_os = "unknown"),
+                wasm_bindgen_test::wasm_bindgen_test
+            )]
             fn $test_name() {
+                // Computes `lhs * rhs + add` exactly and returns the low and high halves of the
+                // result. Checking `quo * div + rem == duo` with wrapping arithmetic could miss a
+                // wrong `(quo, rem)` pair that aliases the correct one modulo `2^n`, so this uses
+                // `n / 2` bit limbs to work for the widest types.
+                fn exact_mul_add(lhs: $uX, rhs: $uX, add: $uX) -> ($uX, $uX) {
+                    let h = $n / 2;
+                    let mask: $uX = !0 >> h;
+                    let (lhs_lo, lhs_hi) = (lhs & mask, lhs >> h);
+                    let (rhs_lo, rhs_hi) = (rhs & mask, rhs >> h);
+                    let lo_lo = lhs_lo * rhs_lo;
+                    let lo_hi = lhs_lo * rhs_hi;
+                    let hi_lo = lhs_hi * rhs_lo;
+                    let hi_hi = lhs_hi * rhs_hi;
+                    // less than `3 * 2^h`, so this cannot overflow
+                    let mid = (lo_lo >> h) + (lo_hi & mask) + (hi_lo & mask);
+                    let lo = (lo_lo & mask) | (mid << h);
+                    let hi = hi_hi + (lo_hi >> h) + (hi_lo >> h) + (mid >> h);
+                    let (lo, carry) = lo.overflowing_add(add);
+                    (lo, hi + (carry as $uX))
+                }
+
                 fn assert_invariants(lhs: $uX, rhs: $uX) {
                     let (quo, rem) = $unsigned_name(lhs, rhs);
-                    if rhs <= rem || (lhs != rhs.wrapping_mul(quo).wrapping_add(rem)) {
+                    if rhs <= rem || (exact_mul_add(rhs, quo, rem) != (lhs, 0)) {
                         panic!(
                             "unsigned division function failed with lhs:{} rhs:{} \
                             expected:({}, {}) found:({}, {})",
@@ -31,10 +89,8 @@ macro_rules! test {
                     let lhs = lhs as $iX;
                     let rhs = rhs as $iX;
                     let (quo, rem) = $signed_name(lhs, rhs);
-                    // We cannot just test that
-                    // `lhs == rhs.wrapping_mul(quo).wrapping_add(rem)`, but also
-                    // need to make sure the remainder isn't larger than the divisor
-                    // and has the correct sign.
+                    // We cannot just test that `lhs == rhs * quo + rem`, but also need to make
+                    // sure the remainder isn't larger than the divisor and has the correct sign.
                     let incorrect_rem = if rem == 0 {
                         false
                     } else if rhs == $iX::MIN {
@@ -45,7 +101,18 @@ macro_rules! test {
                         (lhs.is_negative() != rem.is_negative())
                         || (rhs.wrapping_abs() <= rem.wrapping_abs())
                     };
-                    if incorrect_rem || lhs != rhs.wrapping_mul(quo).wrapping_add(rem) {
+                    let incorrect_quo = if lhs == $iX::MIN && rhs == -1 {
+                        // the only quotient that overflows, which wraps around
+                        quo != $iX::MIN
+                    } else {
+                        // With the signs checked, `lhs == rhs * quo + rem` is equivalent to
+                        // `|lhs| == |rhs| * |quo| + |rem|`, which can be checked exactly.
+                        let quo_neg = lhs.is_negative() != rhs.is_negative();
+                        (quo != 0 && (quo.is_negative() != quo_neg))
+                        || exact_mul_add(rhs.unsigned_abs(), quo.unsigned_abs(), rem.unsigned_abs())
+                            != (lhs.unsigned_abs(), 0)
+                    };
+                    if incorrect_rem || incorrect_quo {
                         panic!(
                             "signed division function failed with lhs:{} rhs:{} \
                             expected:({}, {}) found:({}, {})",
@@ -94,53 +161,507 @@ macro_rules! test {
                 }
                 // creates a mask for indexing the bits of the type
                 let bit_indexing_mask = $n - 1;
-                for _ in 0..1_000_000 {
-                    // Randomly OR, AND, and XOR randomly sized and shifted continuous strings of
-                    // ones with `lhs` and `rhs`. XOR is performed most often because OR and AND
-                    // tend to be destructive. This results in excellent fuzzing entropy such as:
-                    // lhs: 00101011110101010101010101010000 rhs: 11111111100001111110111111111111
-                    // lhs: 01110101000101010100000000000101 rhs: 11111111100001111110111111111111
-                    // lhs: 00000000000000000001000000000000 rhs: 11111111100001111110111111111111
-                    // lhs: 00000000000000000001000000000000 rhs: 11111111100011011111111111111111
-                    // lhs: 00000000000000000010111111100000 rhs: 00000000000000000000101000000000
-                    // lhs: 00000000000000000010111111100000 rhs: 10101000000000000000011101101010
-                    // lhs: 00000000000000000010000001100000 rhs: 11111101010101000000011101111111
-                    // lhs: 10000000000000101010101011101010 rhs: 11111101010101000000011101111000
-                    // The msb is set half of the time by the fuzzer, but `assert_invariants` tests
-                    // both the signed and unsigned functions.
-                    let r0: u32 = bit_indexing_mask & random::<u32>();
-                    let r1: u32 = bit_indexing_mask & random::<u32>();
-                    let mask = ones.wrapping_shr(r0).rotate_left(r1);
-                    match (random(), random(), random()) {
-                        (false, false, false) => lhs |= mask,
-                        (false, false, true) => lhs &= mask,
-                        (false, true, _) => lhs ^= mask,
-                        (true, false, false) => rhs |= mask,
-                        (true, false, true) => rhs &= mask,
-                        (true, true, _) => rhs ^= mask,
+
+                let soak_secs: Option<u64> = std::env::var("SPECIALIZED_DIV_REM_SOAK")
+                    .ok()
+                    .map(|s| s.parse().expect("`SPECIALIZED_DIV_REM_SOAK` should be seconds"));
+                let mut rng = $crate::fuzz_rng(stringify!($test_name));
+                // `std::time` is not available on all targets (e.x. `wasm32-unknown-unknown`), so
+                // the clock is only read in soak mode
+                let start = soak_secs.map(|_| std::time::Instant::now());
+                let mut iterations: u64 = 0;
+                let mut last_report: u64 = 0;
+                // Randomly OR, AND, and XOR randomly sized and shifted continuous strings of
+                // ones with `lhs` and `rhs`. XOR is performed most often because OR and AND
+                // tend to be destructive. This results in excellent fuzzing entropy such as:
+                // lhs: 00101011110101010101010101010000 rhs: 11111111100001111110111111111111
+                // lhs: 01110101000101010100000000000101 rhs: 11111111100001111110111111111111
+                // lhs: 00000000000000000001000000000000 rhs: 11111111100001111110111111111111
+                // lhs: 00000000000000000001000000000000 rhs: 11111111100011011111111111111111
+                // lhs: 00000000000000000010111111100000 rhs: 00000000000000000000101000000000
+                // lhs: 00000000000000000010111111100000 rhs: 10101000000000000000011101101010
+                // lhs: 00000000000000000010000001100000 rhs: 11111101010101000000011101111111
+                // lhs: 10000000000000101010101011101010 rhs: 11111101010101000000011101111000
+                // The msb is set half of the time by the fuzzer, but `assert_invariants` tests
+                // both the signed and unsigned functions.
+                loop {
+                    for _ in 0..1_000_000 {
+                        let r0: u32 = bit_indexing_mask & rng.random::<u32>();
+                        let r1: u32 = bit_indexing_mask & rng.random::<u32>();
+                        let mask = ones.wrapping_shr(r0).rotate_left(r1);
+                        match (rng.random(), rng.random(), rng.random()) {
+                            (false, false, false) => lhs |= mask,
+                            (false, false, true) => lhs &= mask,
+                            (false, true, _) => lhs ^= mask,
+                            (true, false, false) => rhs |= mask,
+                            (true, false, true) => rhs &= mask,
+                            (true, true, _) => rhs ^= mask,
+                        }
+                        // do the same for alternating ones and zeros
+                        let r0: u32 = bit_indexing_mask & rng.random::<u32>();
+                        let r1: u32 = bit_indexing_mask & rng.random::<u32>();
+                        let mask = alt_ones.wrapping_shr(r0).rotate_left(r1);
+                        match (rng.random(), rng.random(), rng.random()) {
+                            (false, false, false) => lhs |= mask,
+                            (false, false, true) => lhs &= mask,
+                            (false, true, _) => lhs ^= mask,
+                            (true, false, false) => rhs |= mask,
+                            (true, false, true) => rhs &= mask,
+                            (true, true, _) => rhs ^= mask,
+                        }
+
+                        if rhs != 0 {
+                            assert_invariants(lhs, rhs);
+                        }
+                    }
+                    iterations += 1_000_000;
+                    match soak_secs {
+                        None => break,
+                        Some(secs) => {
+                            let elapsed = start.unwrap().elapsed().as_secs();
+                            let done = elapsed >= secs;
+                            if done || (last_report + 60 <= elapsed) {
+                                last_report = elapsed;
+                                println!(
+                                    "{} soak: {} iterations in {}s of {}s",
+                                    stringify!($test_name),
+                                    iterations,
+                                    elapsed,
+                                    secs
+                                );
+                            }
+                            if done {
+                                break
+                            }
+                        }
+                    }
+                }
+            }
+        )+
+    }
+}
+
+/// Creates a differential fuzzing test that runs every given division function of a certain size
+/// on the same stream of operands and asserts that they all produce identical outputs. This
+/// catches bugs even when there is no trusted oracle for a width. The `SPECIALIZED_DIV_REM_SEED`
+/// environment variable works the same as with `test!`.
+#[macro_export]
+macro_rules! test_differential {
+    (
+        $n:expr, // the number of bits in a $iX or $uX
+        $uX:ident, // unsigned integer that will be shifted
+        $iX:ident, // signed version of $uX
+        $test_name:ident, // name of the test
+        // list of pairs of the unsigned division function and the signed division function
+        $($unsigned_name:ident, $signed_name:ident);+;
+    ) => {
+        #[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+        #[cfg_attr(
+            all(target_arch = "wasm32", target_os = "unknown"),
+            wasm_bindgen_test::wasm_bindgen_test
+        )]
+        fn $test_name() {
+            fn assert_identical(lhs: $uX, rhs: $uX) {
+                let unsigned = [$(($unsigned_name(lhs, rhs), stringify!($unsigned_name))),+];
+                for (found, name) in unsigned.iter() {
+                    if *found != unsigned[0].0 {
+                        panic!(
+                            "unsigned division functions {} and {} differ with lhs:{} rhs:{} \
+                            ({:?} vs {:?})",
+                            unsigned[0].1,
+                            name,
+                            lhs,
+                            rhs,
+                            unsigned[0].0,
+                            found
+                        );
                     }
-                    // do the same for alternating ones and zeros
-                    let r0: u32 = bit_indexing_mask & random::<u32>();
-                    let r1: u32 = bit_indexing_mask & random::<u32>();
-                    let mask = alt_ones.wrapping_shr(r0).rotate_left(r1);
-                    match (random(), random(), random()) {
-                        (false, false, false) => lhs |= mask,
-                        (false, false, true) => lhs &= mask,
-                        (false, true, _) => lhs ^= mask,
-                        (true, false, false) => rhs |= mask,
-                        (true, false, true) => rhs &= mask,
-                        (true, true, _) => rhs ^= mask,
+                }
+                let lhs = lhs as $iX;
+                let rhs = rhs as $iX;
+                let signed = [$(($signed_name(lhs, rhs), stringify!($signed_name))),+];
+                for (found, name) in signed.iter() {
+                    if *found != signed[0].0 {
+                        panic!(
+                            "signed division functions {} and {} differ with lhs:{} rhs:{} \
+                            ({:?} vs {:?})",
+                            signed[0].1,
+                            name,
+                            lhs,
+                            rhs,
+                            signed[0].0,
+                            found
+                        );
                     }
+                }
+            }
 
-                    if rhs != 0 {
-                        assert_invariants(lhs, rhs);
+            // the same brute force fuzzer as in `test!`
+            for lhs_len in (0..$n).rev() {
+                for lhs_shift in 0..=lhs_len {
+                    for rhs_len in (0..$n).rev() {
+                        for rhs_shift in 0..=rhs_len {
+                            let lhs = (!0 >> lhs_len) << lhs_shift;
+                            let rhs = (!0 >> rhs_len) << rhs_shift;
+                            if rhs != 0 {
+                                assert_identical(lhs, rhs);
+                            }
+                        }
                     }
                 }
             }
-        )+
+
+            // a condensed version of the random fuzzer in `test!`
+            let mut rng = $crate::fuzz_rng(stringify!($test_name));
+            let mut lhs: $uX = 0;
+            let mut rhs: $uX = 0;
+            let mut alt_ones: $uX = 1;
+            for _ in 0..($n / 2) {
+                alt_ones <<= 2;
+                alt_ones |= 1;
+            }
+            let bit_indexing_mask = $n - 1;
+            for _ in 0..1_000_000 {
+                let ones: $uX = if rng.random() { !0 } else { alt_ones };
+                let r0: u32 = bit_indexing_mask & rng.random::<u32>();
+                let r1: u32 = bit_indexing_mask & rng.random::<u32>();
+                let mask = ones.wrapping_shr(r0).rotate_left(r1);
+                match (rng.random(), rng.random(), rng.random()) {
+                    (false, false, false) => lhs |= mask,
+                    (false, false, true) => lhs &= mask,
+                    (false, true, _) => lhs ^= mask,
+                    (true, false, false) => rhs |= mask,
+                    (true, false, true) => rhs &= mask,
+                    (true, true, _) => rhs ^= mask,
+                }
+                if rhs != 0 {
+                    assert_identical(lhs, rhs);
+                }
+            }
+        }
     }
 }
 
+/// Creates a test function for the wrappers of `variants` (`_checked_div_rem`, `_try_div_rem`,
+/// `_div`, `_div_rem_ceil`, and so on) of a certain size, comparing each against a reference built
+/// from the methods of the primitive integers. The operands are every pair of a set of edge cases
+/// (including zero divisors and the `$iX::MIN / -1` overflow), followed by random operands with
+/// random numbers of leading zeros. The wrappers only adjust the results of algorithms that `test!`
+/// already fuzzes, so this is much lighter than `test!`. The `SPECIALIZED_DIV_REM_SEED` environment
+/// variable works the same as with `test!`.
+#[macro_export]
+macro_rules! test_variants {
+    (
+        $n:expr, // the number of bits in a $iX or $uX
+        $uX:ident, // unsigned integer type
+        $iX:ident, // signed version of $uX
+        $test_name:ident, // name of the test
+        // list of the kinds of variants (see the `@check` rules) followed by their functions
+        $($kind:ident: $($fn_name:ident),+;)+
+    ) => {
+        #[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+        #[cfg_attr(
+            all(target_arch = "wasm32", target_os = "unknown"),
+            wasm_bindgen_test::wasm_bindgen_test
+        )]
+        fn $test_name() {
+            fn check(duo: $uX, div: $uX) {
+                $(
+                    $crate::test_variants!(@check $kind, duo, div, $uX, $iX; $($fn_name),+);
+                )+
+            }
+
+            // `-1`, `-2`, `-7`, and `-8` as `$uX`s are also in here
+            let edge_cases: [$uX; 13] = [
+                0,
+                1,
+                2,
+                3,
+                7,
+                8,
+                $uX::MAX,
+                $uX::MAX - 1,
+                $uX::MAX - 6,
+                $uX::MAX - 7,
+                $iX::MAX as $uX,
+                $iX::MIN as $uX,
+                ($iX::MIN as $uX) + 1,
+            ];
+            for duo in edge_cases {
+                for div in edge_cases {
+                    check(duo, div);
+                }
+            }
+
+            let mut rng = $crate::fuzz_rng(stringify!($test_name));
+            let bit_indexing_mask = $n - 1;
+            for _ in 0..10_000 {
+                let duo = rng.random::<$uX>() >> (bit_indexing_mask & rng.random::<u32>());
+                let div = rng.random::<$uX>() >> (bit_indexing_mask & rng.random::<u32>());
+                check(duo, div);
+            }
+        }
+    };
+    (@assert $fn_name:ident($($arg:expr),+), $expected:expr) => {
+        assert_eq!(
+            $fn_name($($arg),+),
+            $expected,
+            "{} failed with arguments {:?}",
+            stringify!($fn_name),
+            ($($arg),+)
+        );
+    };
+    // `_checked_div_rem` (unsigned and signed functions)
+    (@check checked, $duo:ident, $div:ident, $uX:ident, $iX:ident; $u:ident, $i:ident) => {
+        let expected = $duo.checked_div($div).zip($duo.checked_rem($div));
+        $crate::test_variants!(@assert $u($duo, $div), expected);
+        let (duo, div) = ($duo as $iX, $div as $iX);
+        let expected = duo.checked_div(div).zip(duo.checked_rem(div));
+        $crate::test_variants!(@assert $i(duo, div), expected);
+    };
+    // `_try_div_rem` (unsigned and signed functions)
+    (@check result, $duo:ident, $div:ident, $uX:ident, $iX:ident; $u:ident, $i:ident) => {
+        let expected = $duo
+            .checked_div($div)
+            .zip($duo.checked_rem($div))
+            .ok_or($crate::DivError::DivisionByZero);
+        $crate::test_variants!(@assert $u($duo, $div), expected);
+        let (duo, div) = ($duo as $iX, $div as $iX);
+        let expected = if div == 0 {
+            Err($crate::DivError::DivisionByZero)
+        } else {
+            duo.checked_div(div)
+                .zip(duo.checked_rem(div))
+                .ok_or($crate::DivError::Overflow)
+        };
+        $crate::test_variants!(@assert $i(duo, div), expected);
+    };
+    // `_overflowing_div_rem` (signed function)
+    (@check overflowing, $duo:ident, $div:ident, $uX:ident, $iX:ident; $i:ident) => {
+        let (duo, div) = ($duo as $iX, $div as $iX);
+        if div != 0 {
+            let (quo, overflow) = duo.overflowing_div(div);
+            let expected = ((quo, duo.wrapping_rem(div)), overflow);
+            $crate::test_variants!(@assert $i(duo, div), expected);
+        }
+    };
+    // `_div` (unsigned and signed functions)
+    (@check quotient, $duo:ident, $div:ident, $uX:ident, $iX:ident; $u:ident, $i:ident) => {
+        if $div != 0 {
+            $crate::test_variants!(@assert $u($duo, $div), $duo / $div);
+            let (duo, div) = ($duo as $iX, $div as $iX);
+            let expected = duo.wrapping_div(div);
+            $crate::test_variants!(@assert $i(duo, div), expected);
+        }
+    };
+    // `_div_rem_ceil` and `_div_ceil` (unsigned and signed functions)
+    (
+        @check ceil, $duo:ident, $div:ident, $uX:ident, $iX:ident;
+        $u_div_rem:ident, $u_div:ident, $i_div_rem:ident, $i_div:ident
+    ) => {
+        if $div != 0 {
+            let quo = $duo.div_ceil($div);
+            let expected = (quo, quo.wrapping_mul($div).wrapping_sub($duo));
+            $crate::test_variants!(@assert $u_div_rem($duo, $div), expected);
+            $crate::test_variants!(@assert $u_div($duo, $div), quo);
+            let (duo, div) = ($duo as $iX, $div as $iX);
+            let quo = duo.wrapping_div(div);
+            let rem = duo.wrapping_rem(div);
+            let expected = if (rem != 0) && ((rem < 0) == (div < 0)) {
+                (quo + 1, rem - div)
+            } else {
+                (quo, rem)
+            };
+            $crate::test_variants!(@assert $i_div_rem(duo, div), expected);
+            $crate::test_variants!(@assert $i_div(duo, div), expected.0);
+        }
+    };
+    // `_div_rem_euclid` (signed function)
+    (@check euclid, $duo:ident, $div:ident, $uX:ident, $iX:ident; $i:ident) => {
+        let (duo, div) = ($duo as $iX, $div as $iX);
+        if div != 0 {
+            // like the other signed functions, `$iX::MIN / -1` wraps around
+            let expected = (duo.wrapping_div_euclid(div), duo.wrapping_rem_euclid(div));
+            $crate::test_variants!(@assert $i(duo, div), expected);
+        }
+    };
+    // `_div_rem_floor` (signed function)
+    (@check floor, $duo:ident, $div:ident, $uX:ident, $iX:ident; $i:ident) => {
+        let (duo, div) = ($duo as $iX, $div as $iX);
+        if div != 0 {
+            let quo = duo.wrapping_div(div);
+            let rem = duo.wrapping_rem(div);
+            let expected = if (rem != 0) && ((rem < 0) != (div < 0)) {
+                (quo - 1, rem + div)
+            } else {
+                (quo, rem)
+            };
+            $crate::test_variants!(@assert $i(duo, div), expected);
+        }
+    };
+    // `_saturating_div_rem` and `_saturating_div` (signed functions)
+    (
+        @check saturating, $duo:ident, $div:ident, $uX:ident, $iX:ident;
+        $i_div_rem:ident, $i_div:ident
+    ) => {
+        let (duo, div) = ($duo as $iX, $div as $iX);
+        if div != 0 {
+            let quo = duo.saturating_div(div);
+            let expected = (quo, duo.wrapping_rem(div));
+            $crate::test_variants!(@assert $i_div_rem(duo, div), expected);
+            $crate::test_variants!(@assert $i_div(duo, div), quo);
+        }
+    };
+    // `_div_rem_round` (unsigned and signed functions)
+    (@check round, $duo:ident, $div:ident, $uX:ident, $iX:ident; $u:ident, $i:ident) => {
+        if $div != 0 {
+            use $crate::Round;
+            let rounds = [Round::Trunc, Round::Floor, Round::Ceil, Round::HalfUp, Round::HalfEven];
+            for round in rounds {
+                // `div - rem` is compared instead of doubling `rem`, which could overflow
+                let quo = $duo / $div;
+                let rem = $duo % $div;
+                let round_up = match round {
+                    Round::Trunc | Round::Floor => false,
+                    Round::Ceil => rem != 0,
+                    Round::HalfUp => rem >= ($div - rem),
+                    Round::HalfEven => {
+                        (rem > ($div - rem)) || ((rem == ($div - rem)) && ((quo & 1) != 0))
+                    }
+                };
+                let expected = if round_up {
+                    (quo + 1, rem.wrapping_sub($div))
+                } else {
+                    (quo, rem)
+                };
+                $crate::test_variants!(@assert $u($duo, $div, round), expected);
+
+                let (duo, div) = ($duo as $iX, $div as $iX);
+                let quo = duo.wrapping_div(div);
+                let rem = duo.wrapping_rem(div);
+                // whether incrementing the magnitude of the quotient decrements it
+                let neg = (duo < 0) != (div < 0);
+                let (rem_abs, div_abs) = (rem.unsigned_abs(), div.unsigned_abs());
+                let round_up = match round {
+                    Round::Trunc => false,
+                    Round::Floor => (rem != 0) && neg,
+                    Round::Ceil => (rem != 0) && !neg,
+                    Round::HalfUp => rem_abs >= (div_abs - rem_abs),
+                    Round::HalfEven => {
+                        (rem_abs > (div_abs - rem_abs))
+                            || ((rem_abs == (div_abs - rem_abs)) && ((quo & 1) != 0))
+                    }
+                };
+                let expected = match (round_up, neg) {
+                    (false, _) => (quo, rem),
+                    (true, false) => (quo.wrapping_add(1), rem.wrapping_sub(div)),
+                    (true, true) => (quo.wrapping_sub(1), rem.wrapping_add(div)),
+                };
+                $crate::test_variants!(@assert $i(duo, div, round), expected);
+            }
+        }
+    };
+}
+
+/// Creates a test function for the normalization shift functions created by
+/// `impl_normalization_shift!`. Both the `leading_zeros` and software bisection modes are
+/// instantiated and checked against the documented normalization invariants, against each other,
+/// and against `$leading_zeros`, which should be one of the crate's software `leading_zeros`
+/// functions for `$uX`. The `SPECIALIZED_DIV_REM_SEED` environment variable works the same as with
+/// `test!`.
+#[macro_export]
+macro_rules! test_normalization_shift {
+    (
+        $n:tt, // the number of bits in a $iX or $uX
+        $uX:ident, // unsigned integer that will be shifted
+        $iX:ident, // signed version of $uX
+        $test_name:ident, // name of the test
+        $leading_zeros:ident, // software `leading_zeros` function for $uX
+    ) => {
+        #[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+        #[cfg_attr(
+            all(target_arch = "wasm32", target_os = "unknown"),
+            wasm_bindgen_test::wasm_bindgen_test
+        )]
+        fn $test_name() {
+            const USE_LZ: bool = true;
+            const NO_LZ: bool = false;
+            $crate::impl_normalization_shift!(shift_lz, USE_LZ, $n, $uX, $iX,);
+            $crate::impl_normalization_shift!(shift_bisection, NO_LZ, $n, $uX, $iX,);
+
+            fn assert_normalized(duo: $uX, div: $uX, shl: usize, full: bool, name: &str) {
+                let fail = || -> ! {
+                    panic!(
+                        "{} failed with duo:{} div:{} full_normalization:{} shl:{}",
+                        name, duo, div, full, shl
+                    )
+                };
+                // no set bits of `div` can be shifted out
+                if shl >= $n || ((div << shl) >> shl) != div {
+                    fail()
+                }
+                let tmp = div << shl;
+                if full && duo < tmp {
+                    fail()
+                }
+                if duo.leading_zeros() != tmp.leading_zeros() {
+                    if (duo.leading_zeros() + 1) != tmp.leading_zeros() || duo >= (tmp << 1) {
+                        fail()
+                    }
+                }
+            }
+
+            fn assert_invariants(duo: $uX, div: $uX) {
+                // the different `leading_zeros` implementations have to agree
+                assert_eq!($leading_zeros(duo), duo.leading_zeros());
+                assert_eq!($leading_zeros(div), div.leading_zeros());
+                if div == 0 || duo < div {
+                    return;
+                }
+                let lz_partial = shift_lz(duo, div, false);
+                let lz_full = shift_lz(duo, div, true);
+                let bisection_partial = shift_bisection(duo, div, false);
+                let bisection_full = shift_bisection(duo, div, true);
+                assert_normalized(duo, div, lz_partial, false, "shift_lz");
+                assert_normalized(duo, div, lz_full, true, "shift_lz");
+                assert_normalized(duo, div, bisection_partial, false, "shift_bisection");
+                assert_normalized(duo, div, bisection_full, true, "shift_bisection");
+                // the partial shift of the `leading_zeros` mode only aligns the msbs
+                assert_eq!(
+                    lz_partial,
+                    ($leading_zeros(div) - $leading_zeros(duo)) as usize
+                );
+                // full normalization is unique, and the bisection mode always fully normalizes
+                assert_eq!(lz_full, bisection_full);
+                assert_eq!(lz_full, bisection_partial);
+            }
+
+            // the same brute force fuzzer as in `test!`
+            for duo_len in (0..$n).rev() {
+                for duo_shift in 0..=duo_len {
+                    for div_len in (0..$n).rev() {
+                        for div_shift in 0..=div_len {
+                            let duo: $uX = (!0 >> duo_len) << duo_shift;
+                            let div: $uX = (!0 >> div_len) << div_shift;
+                            assert_invariants(duo, div);
+                        }
+                    }
+                }
+            }
+
+            let mut rng = $crate::fuzz_rng(stringify!($test_name));
+            let bit_indexing_mask = $n - 1;
+            for _ in 0..100_000 {
+                // random values with random numbers of leading zeros, so that all shifts are hit
+                let duo = rng.random::<$uX>() >> (bit_indexing_mask & rng.random::<u32>());
+                let div = rng.random::<$uX>() >> (bit_indexing_mask & rng.random::<u32>());
+                assert_invariants(duo, div);
+                assert_invariants(div, duo);
+            }
+        }
+    };
+}
+
 /// Creates test functions for asserting that division by zero causes a panic
 #[macro_export]
 macro_rules! test_div_by_zero {
@@ -149,7 +670,11 @@ macro_rules! test_div_by_zero {
         $($test_name:ident, $fn:ident);+;
     ) => {
         $(
-            #[test]
+            #[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+            #[cfg_attr(
+                all(target_arch = "wasm32", target_os = "unknown"),
+                wasm_bindgen_test::wasm_bindgen_test
+            )]
             #[should_panic]
             fn $test_name() {
                 $fn(1, 0);
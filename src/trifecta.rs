@@ -2,6 +2,9 @@
 /// larger than the largest hardware integer division supported. These functions use large radix
 /// division algorithms that require both fast division and very fast widening multiplication on the
 /// target microarchitecture. Otherwise, `impl_delegate` should be used instead.
+///
+/// On targets without a CLZ instruction, a software `leading_zeros` function for `$uD` (such as
+/// the ones from `impl_bit_scan`) can be passed with `leading_zeros = ` after `$half_division`.
 #[macro_export]
 macro_rules! impl_trifecta {
     (
@@ -9,6 +12,7 @@ macro_rules! impl_trifecta {
         $signed_name:ident, // name of the signed division function
         $zero_div_fn:ident, // function called when division by zero is attempted
         $half_division:ident, // function for division of a $uX by a $uX
+        leading_zeros = $leading_zeros:path, // function for the leading zeros of a $uD
         $n_h:expr, // the number of bits in $iH or $uH
         $uH:ident, // unsigned integer with half the bit width of $uX
         $uX:ident, // unsigned integer with half the bit width of $uD
@@ -29,42 +33,98 @@ macro_rules! impl_trifecta {
 
             // This replicates `carrying_mul` (rust-lang rfc #2417). LLVM correctly optimizes this
             // to use a widening multiply to 128 bits on the relevant architectures.
+            //
+            // On 32 bit targets, these were measured against versions composed of explicit `$uH`
+            // limb products with manual carries (in the form that maps to `UMULL` and `UMAAL` on
+            // ARM). For `u128_div_rem_trifecta` on `thumbv7em-none-eabi`, the explicit versions
+            // increased the function from 626 to 641-659 instructions and the stack frame from 380
+            // to 388 bytes, because LLVM already lowers these zero extended multiplications to
+            // `UMULL` and `UMAAL`. The spilling in that function instead comes from the `$uD`
+            // sized state that has to stay live across the `$half_division` calls.
             fn carrying_mul(lhs: $uX, rhs: $uX) -> ($uX, $uX) {
-                let tmp = (lhs as $uD).wrapping_mul(rhs as $uD);
-                (tmp as $uX, (tmp >> ($n_h * 2)) as $uX)
+                let tmp = $crate::cast!(lhs, $uD).wrapping_mul($crate::cast!(rhs, $uD));
+                ($crate::cast!(tmp, $uX), $crate::cast!(tmp >> ($n_h * 2), $uX))
             }
             fn carrying_mul_add(lhs: $uX, mul: $uX, add: $uX) -> ($uX, $uX) {
-                let tmp = (lhs as $uD).wrapping_mul(mul as $uD).wrapping_add(add as $uD);
-                (tmp as $uX, (tmp >> ($n_h * 2)) as $uX)
+                let tmp = $crate::cast!(lhs, $uD)
+                    .wrapping_mul($crate::cast!(mul, $uD))
+                    .wrapping_add($crate::cast!(add, $uD));
+                ($crate::cast!(tmp, $uX), $crate::cast!(tmp >> ($n_h * 2), $uX))
+            }
+
+            // Short division of a `$uD` by a `$uH`. This works for any `duo`.
+            //
+            // It is barely possible to improve the performance of this by calculating the
+            // reciprocal and removing one `$half_division`, but only if the CPU can do fast
+            // multiplications in parallel. Other reciprocal based methods can remove two
+            // `$half_division`s, but have multiplications that cannot be done in parallel and
+            // reduce performance. I have decided to use this trivial short division method and
+            // rely on the CPU having quick divisions.
+            #[inline(always)]
+            fn short_division(duo: $uD, div: $uH) -> ($uD, $uD) {
+                let duo_hi = $crate::cast!(duo >> ($n_h * 2), $uX);
+                let div_0 = $crate::cast!(div, $uX);
+                let (quo_hi, rem_3) = $half_division(duo_hi, div_0);
+
+                let duo_mid =
+                    $crate::cast!($crate::cast!(duo >> $n_h, $uH), $uX)
+                    | (rem_3 << $n_h);
+                let (quo_1, rem_2) = $half_division(duo_mid, div_0);
+
+                let duo_lo =
+                    $crate::cast!($crate::cast!(duo, $uH), $uX)
+                    | (rem_2 << $n_h);
+                let (quo_0, rem_1) = $half_division(duo_lo, div_0);
+
+                (
+                    $crate::cast!(quo_0, $uD)
+                    | ($crate::cast!(quo_1, $uD) << $n_h)
+                    | ($crate::cast!(quo_hi, $uD) << ($n_h * 2)),
+                    $crate::cast!(rem_1, $uD)
+                )
             }
 
             // the number of bits in a $uX
             let n = $n_h * 2;
 
-            if div == 0 {
+            if $crate::hint::unlikely(div == $crate::cast!(0u8, $uD)) {
                 $zero_div_fn()
             }
 
+            // For workloads of small operands (see `$crate::hint`), the smaller division branch is
+            // moved in front of the `leading_zeros` calculations
+            if $crate::hint::SMALL_OPERANDS && (((duo | div) >> n) == $crate::cast!(0u8, $uD)) {
+                let (quo, rem) = $half_division($crate::cast!(duo, $uX), $crate::cast!(div, $uX));
+                return ($crate::cast!(quo, $uD), $crate::cast!(rem, $uD))
+            }
+
             // Trying to use a normalization shift function will cause inelegancies in the code and
             // inefficiencies for architectures with a native count leading zeros instruction. The
             // undersubtracting algorithm needs both values (keeping the original `div_lz` but
-            // updating `duo_lz` multiple times), so we assume hardware support for fast
-            // `leading_zeros` calculation.
-            let div_lz = div.leading_zeros();
-            let mut duo_lz = duo.leading_zeros();
+            // updating `duo_lz` multiple times), so we use `$leading_zeros` directly, which is
+            // either the hardware instruction or a software bisection for targets without one.
+            let div_lz = $leading_zeros(div);
+            let mut duo_lz = $leading_zeros(duo);
 
             // the possible ranges of `duo` and `div` at this point:
             // `0 <= duo < 2^n_d`
             // `1 <= div < 2^n_d`
 
+            // For quotient-heavy workloads (see `$crate::hint`), the short division branch (see
+            // below) is moved in front of the branches for small quotients. This is valid because
+            // short division does not depend on the ranges that the other branches exclude.
+            if $crate::hint::QUOTIENT_HEAVY && (div_lz >= (n + $n_h)) {
+                return short_division(duo, $crate::cast!(div, $uH))
+            }
+
             // quotient is 0 or 1 branch
             if div_lz <= duo_lz {
                 // The quotient cannot be more than 1. The highest set bit of `duo` needs to be at
                 // least one place higher than `div` for the quotient to be more than 1.
                 if duo >= div {
-                    return (1, duo - div)
+                    return ($crate::cast!(1u8, $uD), duo - div)
                 } else {
-                    return (0, duo)
+                    return ($crate::cast!(0u8, $uD), duo)
                 }
             }
 
@@ -75,10 +135,10 @@ macro_rules! impl_trifecta {
             if duo_lz >= n {
                 // `duo < 2^n` so it will fit in a $uX. `div` will also fit in a $uX (because of the
                 // `div_lz <= duo_lz` branch) so no numerical error.
-                let (quo, rem) = $half_division(duo as $uX, div as $uX);
+                let (quo, rem) = $half_division($crate::cast!(duo, $uX), $crate::cast!(div, $uX));
                 return (
-                    quo as $uD,
-                    rem as $uD
+                    $crate::cast!(quo, $uD),
+                    $crate::cast!(rem, $uD)
                 )
             }
 
@@ -87,34 +147,7 @@ macro_rules! impl_trifecta {
             // short division branch
             if div_lz >= (n + $n_h) {
                 // `1 <= div < {2^duo_sb, 2^n_h}`
-
-                // It is barely possible to improve the performance of this by calculating the
-                // reciprocal and removing one `$half_division`, but only if the CPU can do fast
-                // multiplications in parallel. Other reciprocal based methods can remove two
-                // `$half_division`s, but have multiplications that cannot be done in parallel and
-                // reduce performance. I have decided to use this trivial short division method and
-                // rely on the CPU having quick divisions.
-
-                let duo_hi = (duo >> n) as $uX;
-                let div_0 = div as $uH as $uX;
-                let (quo_hi, rem_3) = $half_division(duo_hi, div_0);
-
-                let duo_mid =
-                    ((duo >> $n_h) as $uH as $uX)
-                    | (rem_3 << $n_h);
-                let (quo_1, rem_2) = $half_division(duo_mid, div_0);
-
-                let duo_lo =
-                    (duo as $uH as $uX)
-                    | (rem_2 << $n_h);
-                let (quo_0, rem_1) = $half_division(duo_lo, div_0);
-
-                return (
-                    (quo_0 as $uD)
-                    | ((quo_1 as $uD) << $n_h)
-                    | ((quo_hi as $uD) << n),
-                    rem_1 as $uD
-                )
+                return short_division(duo, $crate::cast!(div, $uH))
             }
 
             // relative leading significant bits, cannot overflow because of above branches
@@ -221,27 +254,27 @@ macro_rules! impl_trifecta {
                 // duo - ((quo - 1)*div) == duo - (quo*div - div) == duo + div - quo*div`.
                 // If `duo - (quo*div)` did not overflow, then we have the correct answer.
                 let shift = n - duo_lz;
-                let duo_sig_n = (duo >> shift) as $uX;
-                let div_sig_n = (div >> shift) as $uX;
+                let duo_sig_n = $crate::cast!(duo >> shift, $uX);
+                let div_sig_n = $crate::cast!(div >> shift, $uX);
                 let quo = $half_division(duo_sig_n, div_sig_n).0;
 
                 // The larger `quo` value can overflow `$uD` in the right circumstances. This is a
                 // manual `carrying_mul_add` with overflow checking.
-                let div_lo = div as $uX;
-                let div_hi = (div >> n) as $uX;
+                let div_lo = $crate::cast!(div, $uX);
+                let div_hi = $crate::cast!(div >> n, $uX);
                 let (tmp_lo, carry) = carrying_mul(quo, div_lo);
                 let (tmp_hi, overflow) = carrying_mul_add(quo, div_hi, carry);
-                let tmp = (tmp_lo as $uD) | ((tmp_hi as $uD) << n);
-                if (overflow != 0) || (duo < tmp) {
+                let tmp = $crate::cast!(tmp_lo, $uD) | ($crate::cast!(tmp_hi, $uD) << n);
+                if (overflow != $crate::cast!(0u8, $uX)) || (duo < tmp) {
                     return (
-                        (quo - 1) as $uD,
+                        $crate::cast!(quo - $crate::cast!(1u8, $uX), $uD),
                         // Both the addition and subtraction can overflow, but when combined end up
                         // as a correct positive number.
                         duo.wrapping_add(div).wrapping_sub(tmp)
                     )
                 } else {
                     return (
-                        quo as $uD,
+                        $crate::cast!(quo, $uD),
                         duo - tmp
                     )
                 }
@@ -322,16 +355,30 @@ macro_rules! impl_trifecta {
             //       169 <- the remainder
 
             let mut duo = duo;
-            let mut quo: $uD = 0;
+            let mut quo: $uD = $crate::cast!(0u8, $uD);
 
             // The number of lesser significant bits not a part of `div_sig_n_h`
             let div_extra = (n + $n_h) - div_lz;
 
             // The most significant `n_h` bits of div
-            let div_sig_n_h = (div >> div_extra) as $uH;
+            let div_sig_n_h = $crate::cast!(div >> div_extra, $uH);
 
             // This needs to be a `$uX` in case of overflow from the increment
-            let div_sig_n_h_add1 = (div_sig_n_h as $uX) + 1;
+            let div_sig_n_h_add1 = $crate::cast!(div_sig_n_h, $uX) + $crate::cast!(1u8, $uX);
+
+            // `div_sig_n_h_add1` is invariant across the undersubtracting steps, so instead of a
+            // `$half_division` per step, its reciprocal is calculated once and each step uses a
+            // widening multiplication and a fixup. The `lz_diff < n_h` branch above was not taken,
+            // so the first step is always an undersubtracting step and this division is never
+            // wasted.
+            //
+            // Let `d = div_sig_n_h_add1` and `x = duo_sig_n < 2^n`, and `recip = (2^n - 1) / d`
+            // (all `/` operators here are floored divisions). Then `(2^n - d) / d <= recip <=
+            // 2^n / d` (the left side being `2^n / d - 1`), and multiplying by `x / 2^n`:
+            // `x / d - x / 2^n <= (x * recip) / 2^n <= x / d`. Because `x / 2^n < 1`, the
+            // estimate `(x * recip) >> n` is either the true quotient `x / d` or one less than it,
+            // and a single comparison of the remainder fixes it.
+            let div_recip = $half_division($uX::MAX, div_sig_n_h_add1).0;
 
             // `{2^n, 2^(div_sb + n_h)} <= duo < 2^n_d`
             // `2^n_h <= div < {2^(duo_sb - n_h), 2^n}`
@@ -340,55 +387,83 @@ macro_rules! impl_trifecta {
                 let duo_extra = n - duo_lz;
 
                 // The most significant `n` bits of `duo`
-                let duo_sig_n = (duo >> duo_extra) as $uX;
+                let duo_sig_n = $crate::cast!(duo >> duo_extra, $uX);
 
                 // the two possibility algorithm requires that the difference between msbs is less
                 // than `n_h`, so the comparison is `<=` here.
                 if div_extra <= duo_extra {
                     // Undersubtracting long division step
-                    let quo_part = $half_division(duo_sig_n, div_sig_n_h_add1).0 as $uD;
+                    $crate::instrument::step($crate::instrument::Loop::Undersubtracting);
+                    let mut quo_part = carrying_mul(duo_sig_n, div_recip).1;
+                    if (duo_sig_n - quo_part.wrapping_mul(div_sig_n_h_add1)) >= div_sig_n_h_add1 {
+                        quo_part += $crate::cast!(1u8, $uX);
+                    }
+                    let quo_part = $crate::cast!(quo_part, $uD);
                     let extra_shl = duo_extra - div_extra;
 
                     // Addition to the quotient.
                     quo += (quo_part << extra_shl);
 
-                    // Subtraction from `duo`. At least `n_h - 1` bits are cleared from `duo` here.
+                    // Subtraction from `duo`. At least `n_h - 2` bits are cleared from `duo` here
+                    // (see below).
                     duo -= (div.wrapping_mul(quo_part) << extra_shl);
+
+                    // Instead of recalculating `duo.leading_zeros()` from scratch, which dominates
+                    // the cost of this loop on architectures without a CLZ instruction, we track
+                    // how many bits this step cleared. `duo_sig_n` has exactly `n` significant
+                    // bits and `div_sig_n_h` has exactly `n_h` significant bits. If `rem_part` is
+                    // the remainder of `duo_sig_n` divided by `div_sig_n_h_add1`, then the new
+                    // `duo` is less than `(rem_part + quo_part + 1) << duo_extra`.
+                    // `rem_part <= div_sig_n_h` and `quo_part < 2^n / div_sig_n_h_add1`, which
+                    // with `2^(n_h - 1) <= div_sig_n_h` is bounded by `2^(n_h + 2) << duo_extra`,
+                    // meaning at least `n - n_h - 2` bits are cleared. The new most significant
+                    // bit is usually within a few places of this bound, and otherwise (e.x. in
+                    // near exact divisions) a real `leading_zeros` is used. `duo_lz < n` at the
+                    // start of every iteration, so none of these shifts can overflow.
+                    let lz = duo_lz + ($n_h - 2);
+                    duo_lz = if (duo >> ((n * 2) - 1 - lz)) != $crate::cast!(0u8, $uD) {
+                        lz
+                    } else if (duo >> ((n * 2) - 2 - lz)) != $crate::cast!(0u8, $uD) {
+                        lz + 1
+                    } else if (duo >> ((n * 2) - 3 - lz)) != $crate::cast!(0u8, $uD) {
+                        lz + 2
+                    } else {
+                        $leading_zeros(duo)
+                    };
+                    debug_assert_eq!(duo_lz, duo.leading_zeros());
                 } else {
                     // Two possibility algorithm
                     let shift = n - duo_lz;
-                    let duo_sig_n = (duo >> shift) as $uX;
-                    let div_sig_n = (div >> shift) as $uX;
+                    let duo_sig_n = $crate::cast!(duo >> shift, $uX);
+                    let div_sig_n = $crate::cast!(div >> shift, $uX);
                     let quo_part = $half_division(duo_sig_n, div_sig_n).0;
-                    let div_lo = div as $uX;
-                    let div_hi = (div >> n) as $uX;
+                    let div_lo = $crate::cast!(div, $uX);
+                    let div_hi = $crate::cast!(div >> n, $uX);
 
                     let (tmp_lo, carry) = carrying_mul(quo_part, div_lo);
                     // The undersubtracting long division algorithm has already run once, so
                     // overflow beyond `$uD` bits is not possible here
                     let (tmp_hi, _) = carrying_mul_add(quo_part, div_hi, carry);
-                    let tmp = (tmp_lo as $uD) | ((tmp_hi as $uD) << n);
+                    let tmp = $crate::cast!(tmp_lo, $uD) | ($crate::cast!(tmp_hi, $uD) << n);
 
                     if duo < tmp {
                         return (
-                            quo + ((quo_part - 1) as $uD),
+                            quo + $crate::cast!(quo_part - $crate::cast!(1u8, $uX), $uD),
                             duo.wrapping_add(div).wrapping_sub(tmp)
                         )
                     } else {
                         return (
-                            quo + (quo_part as $uD),
+                            quo + $crate::cast!(quo_part, $uD),
                             duo - tmp
                         )
                     }
                 }
 
-                duo_lz = duo.leading_zeros();
-
                 if div_lz <= duo_lz {
                     // quotient can have 0 or 1 added to it
                     if div <= duo {
                         return (
-                            quo + 1,
+                            quo + $crate::cast!(1u8, $uD),
                             duo - div
                         )
                     } else {
@@ -403,10 +478,10 @@ macro_rules! impl_trifecta {
                 // branches), but it is not worth it to unroll further.
                 if n <= duo_lz {
                     // simple division and addition
-                    let tmp = $half_division(duo as $uX, div as $uX);
+                    let tmp = $half_division($crate::cast!(duo, $uX), $crate::cast!(div, $uX));
                     return (
-                        quo + (tmp.0 as $uD),
-                        tmp.1 as $uD
+                        quo + $crate::cast!(tmp.0, $uD),
+                        $crate::cast!(tmp.1, $uD)
                     )
                 }
             }
@@ -418,8 +493,8 @@ macro_rules! impl_trifecta {
             #[$signed_attr]
         )*
         pub fn $signed_name(duo: $iD, div: $iD) -> ($iD, $iD) {
-            let duo_neg = duo < 0;
-            let div_neg = div < 0;
+            let duo_neg = duo.is_negative();
+            let div_neg = div.is_negative();
             let mut duo = duo;
             let mut div = div;
             if duo_neg {
@@ -428,9 +503,9 @@ macro_rules! impl_trifecta {
             if div_neg {
                 div = div.wrapping_neg();
             }
-            let t = $unsigned_name(duo as $uD, div as $uD);
-            let mut quo = t.0 as $iD;
-            let mut rem = t.1 as $iD;
+            let t = $unsigned_name($crate::cast!(duo, $uD), $crate::cast!(div, $uD));
+            let mut quo = $crate::cast!(t.0, $iD);
+            let mut rem = $crate::cast!(t.1, $iD);
             if duo_neg {
                 rem = rem.wrapping_neg();
             }
@@ -439,5 +514,34 @@ macro_rules! impl_trifecta {
             }
             (quo, rem)
         }
-    }
+    };
+    // the default, which uses the `leading_zeros` method of `$uD`
+    (
+        $unsigned_name:ident, // name of the unsigned division function
+        $signed_name:ident, // name of the signed division function
+        $zero_div_fn:ident, // function called when division by zero is attempted
+        $half_division:ident, // function for division of a $uX by a $uX
+        $n_h:expr, // the number of bits in $iH or $uH
+        $uH:ident, // unsigned integer with half the bit width of $uX
+        $uX:ident, // unsigned integer with half the bit width of $uD
+        $uD:ident, // unsigned integer type for the inputs and outputs of `$unsigned_name`
+        $iD:ident, // signed integer type for the inputs and outputs of `$signed_name`
+        $($unsigned_attr:meta),*; // attributes for the unsigned function
+        $($signed_attr:meta),* // attributes for the signed function
+    ) => {
+        $crate::impl_trifecta!(
+            $unsigned_name,
+            $signed_name,
+            $zero_div_fn,
+            $half_division,
+            leading_zeros = $uD::leading_zeros,
+            $n_h,
+            $uH,
+            $uX,
+            $uD,
+            $iD,
+            $($unsigned_attr),*;
+            $($signed_attr),*
+        );
+    };
 }
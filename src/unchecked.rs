@@ -0,0 +1,48 @@
+//! Helpers for the functions with preconditions that are not checked, such as the asymmetric
+//! divisions whose quotients have to fit in the smaller integer. These functions are `unsafe`
+//! like the hardware division instructions with the same preconditions. The `no-unsafe` feature
+//! cannot make them safe without changing their public signatures, so it leaves them out instead:
+//! they become safe functions that are private to the crate defining them, which the algorithms of
+//! this crate (and the macros it exports) still use internally.
+
+/// Defines a function that is `unsafe`, or a crate private safe function with the `no-unsafe`
+/// feature
+#[cfg(not(feature = "no-unsafe"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unchecked_fn {
+    ($(#[$attr:meta])* $vis:vis fn $($rest:tt)*) => {
+        $(#[$attr])* $vis unsafe fn $($rest)*
+    };
+}
+
+/// Defines a function that is `unsafe`, or a crate private safe function with the `no-unsafe`
+/// feature
+#[cfg(feature = "no-unsafe")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unchecked_fn {
+    ($(#[$attr:meta])* $vis:vis fn $($rest:tt)*) => {
+        $(#[$attr])* #[allow(dead_code)] pub(crate) fn $($rest)*
+    };
+}
+
+/// Calls a function defined with `unchecked_fn`
+#[cfg(not(feature = "no-unsafe"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unchecked_call {
+    ($call:expr) => {
+        unsafe { $call }
+    };
+}
+
+/// Calls a function defined with `unchecked_fn`
+#[cfg(feature = "no-unsafe")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unchecked_call {
+    ($call:expr) => {
+        $call
+    };
+}
@@ -0,0 +1,537 @@
+/// The rounding modes of the quotient for the `_div_rem_round` functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Round {
+    /// Rounds toward zero, which is the same as the `/` operator
+    Trunc,
+    /// Rounds toward negative infinity
+    Floor,
+    /// Rounds toward positive infinity
+    Ceil,
+    /// Rounds to the nearest integer, with ties rounded away from zero
+    HalfUp,
+    /// Rounds to the nearest integer, with ties rounded to the even integer (banker's rounding)
+    HalfEven,
+}
+
+/// The reasons that a division can fail, returned by the `_try_div_rem` functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DivError {
+    /// The divisor was zero
+    DivisionByZero,
+    /// The quotient does not fit in the type, which only happens for `iX::MIN / -1`
+    Overflow,
+}
+
+impl core::fmt::Display for DivError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DivError::DivisionByZero => f.write_str("attempt to divide by zero"),
+            DivError::Overflow => f.write_str("attempt to divide with overflow"),
+        }
+    }
+}
+
+impl core::error::Error for DivError {}
+
+/// Creates wrappers of a pair of unsigned and signed division functions that return `None` instead
+/// of panicking when the divisor is zero. The signed wrapper also returns `None` for
+/// `$iX::MIN / -1`, whose quotient does not fit in a `$iX`. This is for fallible code paths that
+/// would otherwise have to test the divisor before every call.
+#[macro_export]
+macro_rules! impl_checked_div_rem {
+    (
+        $unsigned_name:ident, // name of the unsigned checked function
+        $signed_name:ident, // name of the signed checked function
+        $unsigned_fn:ident, // unsigned division function that is wrapped
+        $signed_fn:ident, // signed division function that is wrapped
+        $uX:ident, // unsigned integer type for the inputs and outputs of `$unsigned_name`
+        $iX:ident, // signed integer type for the inputs and outputs of `$signed_name`
+        $($unsigned_attr:meta),*; // attributes for the unsigned function
+        $($signed_attr:meta),* // attributes for the signed function
+    ) => {
+        /// Computes the quotient and remainder of `duo` divided by `div` and returns them as a
+        /// tuple, or returns `None` if `div == 0`.
+        $(
+            #[$unsigned_attr]
+        )*
+        pub fn $unsigned_name(duo: $uX, div: $uX) -> Option<($uX, $uX)> {
+            if div == $crate::cast!(0u8, $uX) {
+                None
+            } else {
+                Some($unsigned_fn(duo, div))
+            }
+        }
+
+        /// Computes the quotient and remainder of `duo` divided by `div` and returns them as a
+        /// tuple, or returns `None` if `div == 0` or if the quotient overflows (which only happens
+        /// for `iX::MIN / -1`).
+        $(
+            #[$signed_attr]
+        )*
+        pub fn $signed_name(duo: $iX, div: $iX) -> Option<($iX, $iX)> {
+            if (div == $crate::cast!(0i8, $iX))
+                || ((duo == $iX::MIN) && (div == $crate::cast!(-1i8, $iX)))
+            {
+                None
+            } else {
+                Some($signed_fn(duo, div))
+            }
+        }
+    };
+}
+
+/// Creates wrappers of a signed division function that define the overflowing `$iX::MIN / -1`
+/// exactly like the `wrapping_div`, `wrapping_rem`, `overflowing_div`, and `overflowing_rem`
+/// methods of `core`, instead of relying on how the wrapped function handles it.
+#[macro_export]
+macro_rules! impl_wrapping_div_rem {
+    (
+        $wrapping_name:ident, // name of the wrapping function
+        $overflowing_name:ident, // name of the overflowing function
+        $signed_fn:ident, // signed division function that is wrapped
+        $iX:ident, // signed integer type for the inputs and outputs
+        $($attr:meta),* // attributes for both functions
+    ) => {
+        /// Computes the quotient and remainder of `duo` divided by `div` and returns them as a
+        /// tuple. `iX::MIN / -1` wraps around to `(iX::MIN, 0)`, like `iX::wrapping_div` and
+        /// `iX::wrapping_rem`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$attr]
+        )*
+        pub fn $wrapping_name(duo: $iX, div: $iX) -> ($iX, $iX) {
+            if div == $crate::cast!(-1i8, $iX) {
+                // `x % -1 == 0` for every `x`, and only the negation of `$iX::MIN` wraps
+                (duo.wrapping_neg(), $crate::cast!(0i8, $iX))
+            } else {
+                $signed_fn(duo, div)
+            }
+        }
+
+        /// Computes the quotient and remainder of `duo` divided by `div`, and returns them as a
+        /// tuple along with a boolean indicating if the division overflowed. `iX::MIN / -1` is the
+        /// only case that overflows, for which `((iX::MIN, 0), true)` is returned, like
+        /// `iX::overflowing_div` and `iX::overflowing_rem`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$attr]
+        )*
+        pub fn $overflowing_name(duo: $iX, div: $iX) -> (($iX, $iX), bool) {
+            let overflow = (duo == $iX::MIN) && (div == $crate::cast!(-1i8, $iX));
+            ($wrapping_name(duo, div), overflow)
+        }
+    };
+}
+
+/// Creates a signed Euclidean division function from an unsigned division function. The sign
+/// corrections are applied to the unsigned quotient and remainder directly, instead of computing
+/// the truncated signed result first and correcting it afterwards.
+#[macro_export]
+macro_rules! impl_euclid_div_rem {
+    (
+        $name:ident, // name of the Euclidean division function
+        $unsigned_fn:ident, // unsigned division function that is used
+        $uX:ident, // unsigned version of $iX
+        $iX:ident, // signed integer type for the inputs and outputs
+        $($attr:meta),* // attributes for the function
+    ) => {
+        /// Computes the Euclidean quotient and remainder of `duo` divided by `div` and returns
+        /// them as a tuple, like `iX::div_euclid` and `iX::rem_euclid`. The remainder is never
+        /// negative, and `duo == quo * div + rem`. `iX::MIN / -1` wraps around to `(iX::MIN, 0)`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$attr]
+        )*
+        pub fn $name(duo: $iX, div: $iX) -> ($iX, $iX) {
+            let duo_neg = duo.is_negative();
+            let div_neg = div.is_negative();
+            let div_abs = div.unsigned_abs();
+            let (mut quo, mut rem) = $unsigned_fn(duo.unsigned_abs(), div_abs);
+            if duo_neg && (rem != $crate::cast!(0u8, $uX)) {
+                // `-|duo| == -(quo + 1)*|div| + (|div| - rem)`, and `quo + 1` cannot overflow
+                // because `rem != 0` implies `|div| > 1`
+                quo += $crate::cast!(1u8, $uX);
+                rem = div_abs - rem;
+            }
+            let mut quo = $crate::cast!(quo, $iX);
+            if duo_neg != div_neg {
+                quo = quo.wrapping_neg();
+            }
+            (quo, $crate::cast!(rem, $iX))
+        }
+    };
+}
+
+/// Creates a signed floored division function from an unsigned division function, where the
+/// quotient is rounded toward negative infinity and the remainder has the sign of the divisor (as
+/// in Python and for the `mod` of calendar and grid computations)
+#[macro_export]
+macro_rules! impl_floor_div_rem {
+    (
+        $name:ident, // name of the floored division function
+        $unsigned_fn:ident, // unsigned division function that is used
+        $uX:ident, // unsigned version of $iX
+        $iX:ident, // signed integer type for the inputs and outputs
+        $($attr:meta),* // attributes for the function
+    ) => {
+        /// Computes the floored quotient and remainder of `duo` divided by `div` and returns them
+        /// as a tuple. The quotient is rounded toward negative infinity, the remainder is zero or
+        /// has the sign of `div`, and `duo == quo * div + rem`. `iX::MIN / -1` wraps around to
+        /// `(iX::MIN, 0)`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$attr]
+        )*
+        pub fn $name(duo: $iX, div: $iX) -> ($iX, $iX) {
+            let div_neg = div.is_negative();
+            let quo_neg = duo.is_negative() != div_neg;
+            let div_abs = div.unsigned_abs();
+            let (mut quo, mut rem) = $unsigned_fn(duo.unsigned_abs(), div_abs);
+            if quo_neg && (rem != $crate::cast!(0u8, $uX)) {
+                // the truncated quotient is rounded toward zero, which is one more than the
+                // floored quotient for negative quotients
+                quo += $crate::cast!(1u8, $uX);
+                rem = div_abs - rem;
+            }
+            let mut quo = $crate::cast!(quo, $iX);
+            let mut rem = $crate::cast!(rem, $iX);
+            if quo_neg {
+                quo = quo.wrapping_neg();
+            }
+            if div_neg {
+                rem = rem.wrapping_neg();
+            }
+            (quo, rem)
+        }
+    };
+}
+
+/// Creates unsigned and signed ceiling division functions from an unsigned division function. The
+/// quotient is rounded toward positive infinity by correcting the truncated quotient with the
+/// remainder of the same division, so no second division is needed.
+#[macro_export]
+macro_rules! impl_ceil_div_rem {
+    (
+        $unsigned_div_rem_name:ident, // name of the unsigned quotient and remainder function
+        $unsigned_div_name:ident, // name of the unsigned quotient function
+        $signed_div_rem_name:ident, // name of the signed quotient and remainder function
+        $signed_div_name:ident, // name of the signed quotient function
+        $unsigned_fn:ident, // unsigned division function that is used
+        $uX:ident, // unsigned integer type for the inputs and outputs of the unsigned functions
+        $iX:ident, // signed integer type for the inputs and outputs of the signed functions
+        $($attr:meta),* // attributes for the functions
+    ) => {
+        /// Computes the quotient of `duo` divided by `div` rounded toward positive infinity, and
+        /// returns it along with `quo * div - duo` (the amount that `duo` would have to be padded
+        /// by to be a multiple of `div`, which is less than `div`) as a tuple.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$attr]
+        )*
+        pub fn $unsigned_div_rem_name(duo: $uX, div: $uX) -> ($uX, $uX) {
+            let (quo, rem) = $unsigned_fn(duo, div);
+            if rem == $crate::cast!(0u8, $uX) {
+                (quo, rem)
+            } else {
+                // this cannot overflow, because `rem != 0` implies `div > 1`
+                (quo + $crate::cast!(1u8, $uX), div - rem)
+            }
+        }
+
+        /// Computes the quotient of `duo` divided by `div` rounded toward positive infinity.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$attr]
+        )*
+        pub fn $unsigned_div_name(duo: $uX, div: $uX) -> $uX {
+            $unsigned_div_rem_name(duo, div).0
+        }
+
+        /// Computes the quotient of `duo` divided by `div` rounded toward positive infinity and
+        /// the remainder `duo - quo * div`, and returns them as a tuple. The remainder is zero or
+        /// has the opposite sign of `div`. `iX::MIN / -1` wraps around to `(iX::MIN, 0)`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$attr]
+        )*
+        pub fn $signed_div_rem_name(duo: $iX, div: $iX) -> ($iX, $iX) {
+            let div_neg = div.is_negative();
+            let quo_neg = duo.is_negative() != div_neg;
+            let div_abs = div.unsigned_abs();
+            let (mut quo, mut rem) = $unsigned_fn(duo.unsigned_abs(), div_abs);
+            if !quo_neg && (rem != $crate::cast!(0u8, $uX)) {
+                // the truncated quotient is rounded toward zero, which is one less than the
+                // ceiling for positive quotients
+                quo += $crate::cast!(1u8, $uX);
+                rem = div_abs - rem;
+            }
+            let mut quo = $crate::cast!(quo, $iX);
+            let mut rem = $crate::cast!(rem, $iX);
+            if quo_neg {
+                quo = quo.wrapping_neg();
+            }
+            if !div_neg {
+                rem = rem.wrapping_neg();
+            }
+            (quo, rem)
+        }
+
+        /// Computes the quotient of `duo` divided by `div` rounded toward positive infinity.
+        /// `iX::MIN / -1` wraps around to `iX::MIN`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$attr]
+        )*
+        pub fn $signed_div_name(duo: $iX, div: $iX) -> $iX {
+            $signed_div_rem_name(duo, div).0
+        }
+    };
+}
+
+/// Creates unsigned and signed division functions with a `Round` parameter for the rounding mode of
+/// the quotient. The truncated quotient and remainder of one unsigned division are adjusted for the
+/// rounding mode.
+#[macro_export]
+macro_rules! impl_round_div_rem {
+    (
+        $unsigned_name:ident, // name of the unsigned function
+        $signed_name:ident, // name of the signed function
+        $unsigned_fn:ident, // unsigned division function that is used
+        $uX:ident, // unsigned integer type for the inputs and outputs of `$unsigned_name`
+        $iX:ident, // signed integer type for the inputs and outputs of `$signed_name`
+        $($attr:meta),* // attributes for the functions
+    ) => {
+        /// Computes the quotient of `duo` divided by `div` rounded with `round`, and returns it
+        /// along with the remainder `duo - quo * div` as a tuple. If the quotient is rounded up,
+        /// the remainder is negative, and it is returned in two's complement (so
+        /// `rem.wrapping_neg() == quo * div - duo`). In other words,
+        /// `duo == quo.wrapping_mul(div).wrapping_add(rem)` always holds.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$attr]
+        )*
+        pub fn $unsigned_name(duo: $uX, div: $uX, round: $crate::Round) -> ($uX, $uX) {
+            let (quo, rem) = $unsigned_fn(duo, div);
+            let zero = $crate::cast!(0u8, $uX);
+            let one = $crate::cast!(1u8, $uX);
+            let round_up = match round {
+                $crate::Round::Trunc | $crate::Round::Floor => false,
+                $crate::Round::Ceil => rem != zero,
+                // `2 * rem` could overflow, so `rem` is compared to `div - rem` instead
+                $crate::Round::HalfUp => rem >= (div - rem),
+                $crate::Round::HalfEven => {
+                    (rem > (div - rem)) || ((rem == (div - rem)) && ((quo & one) != zero))
+                }
+            };
+            if round_up {
+                // `rem != 0` or a tie implies `div > 1`, so this cannot overflow
+                (quo + one, rem.wrapping_sub(div))
+            } else {
+                (quo, rem)
+            }
+        }
+
+        /// Computes the quotient of `duo` divided by `div` rounded with `round`, and returns it
+        /// along with the remainder `duo - quo * div` as a tuple. `Round::HalfUp` rounds ties
+        /// away from zero. `iX::MIN / -1` wraps around to `(iX::MIN, 0)`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$attr]
+        )*
+        pub fn $signed_name(duo: $iX, div: $iX, round: $crate::Round) -> ($iX, $iX) {
+            let duo_neg = duo.is_negative();
+            let quo_neg = duo_neg != div.is_negative();
+            let div_abs = div.unsigned_abs();
+            let (mut quo, mut rem) = $unsigned_fn(duo.unsigned_abs(), div_abs);
+            let zero = $crate::cast!(0u8, $uX);
+            let one = $crate::cast!(1u8, $uX);
+            // if the magnitude of the truncated quotient needs to be incremented
+            let round_up = match round {
+                $crate::Round::Trunc => false,
+                $crate::Round::Floor => quo_neg && (rem != zero),
+                $crate::Round::Ceil => !quo_neg && (rem != zero),
+                $crate::Round::HalfUp => rem >= (div_abs - rem),
+                $crate::Round::HalfEven => {
+                    (rem > (div_abs - rem)) || ((rem == (div_abs - rem)) && ((quo & one) != zero))
+                }
+            };
+            // the remainder has the sign of `duo`, unless the magnitude of the quotient is
+            // incremented past it
+            let mut rem_neg = duo_neg;
+            if round_up {
+                quo += one;
+                rem = div_abs - rem;
+                rem_neg = !duo_neg;
+            }
+            let mut quo = $crate::cast!(quo, $iX);
+            let mut rem = $crate::cast!(rem, $iX);
+            if quo_neg {
+                quo = quo.wrapping_neg();
+            }
+            if rem_neg {
+                rem = rem.wrapping_neg();
+            }
+            (quo, rem)
+        }
+    };
+}
+
+/// Creates quotient-only functions from a pair of unsigned and signed division functions. If the
+/// wrapped functions are instantiated with `inline(always)`, LLVM can remove all of the
+/// instructions that are only needed for computing the remainder.
+#[macro_export]
+macro_rules! impl_div {
+    (
+        $unsigned_name:ident, // name of the unsigned quotient function
+        $signed_name:ident, // name of the signed quotient function
+        $unsigned_fn:ident, // unsigned division function that is wrapped
+        $signed_fn:ident, // signed division function that is wrapped
+        $uX:ident, // unsigned integer type for the inputs and outputs of `$unsigned_name`
+        $iX:ident, // signed integer type for the inputs and outputs of `$signed_name`
+        $($unsigned_attr:meta),*; // attributes for the unsigned function
+        $($signed_attr:meta),* // attributes for the signed function
+    ) => {
+        /// Returns the quotient of `duo` divided by `div`
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$unsigned_attr]
+        )*
+        pub fn $unsigned_name(duo: $uX, div: $uX) -> $uX {
+            $unsigned_fn(duo, div).0
+        }
+
+        /// Returns the quotient of `duo` divided by `div`. `iX::MIN / -1` wraps around to
+        /// `iX::MIN`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$signed_attr]
+        )*
+        pub fn $signed_name(duo: $iX, div: $iX) -> $iX {
+            $signed_fn(duo, div).0
+        }
+    };
+}
+
+/// Creates saturating wrappers of a signed division function, where `$iX::MIN / -1` saturates to
+/// `$iX::MAX` like `iX::saturating_div` of `core` instead of wrapping around
+#[macro_export]
+macro_rules! impl_saturating_div_rem {
+    (
+        $div_rem_name:ident, // name of the quotient and remainder function
+        $div_name:ident, // name of the quotient function
+        $signed_fn:ident, // signed division function that is wrapped
+        $iX:ident, // signed integer type for the inputs and outputs
+        $($attr:meta),* // attributes for the functions
+    ) => {
+        /// Computes the quotient and remainder of `duo` divided by `div` and returns them as a
+        /// tuple. `iX::MIN / -1` saturates to `(iX::MAX, 0)`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$attr]
+        )*
+        pub fn $div_rem_name(duo: $iX, div: $iX) -> ($iX, $iX) {
+            if (duo == $iX::MIN) && (div == $crate::cast!(-1i8, $iX)) {
+                ($iX::MAX, $crate::cast!(0i8, $iX))
+            } else {
+                $signed_fn(duo, div)
+            }
+        }
+
+        /// Computes the quotient of `duo` divided by `div`, like `iX::saturating_div`.
+        /// `iX::MIN / -1` saturates to `iX::MAX`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `div == 0`.
+        $(
+            #[$attr]
+        )*
+        pub fn $div_name(duo: $iX, div: $iX) -> $iX {
+            $div_rem_name(duo, div).0
+        }
+    };
+}
+
+/// Creates wrappers of a pair of unsigned and signed division functions that return a `DivError`
+/// instead of panicking, which tells apart a zero divisor and the overflowing `$iX::MIN / -1`.
+#[macro_export]
+macro_rules! impl_try_div_rem {
+    (
+        $unsigned_name:ident, // name of the unsigned fallible function
+        $signed_name:ident, // name of the signed fallible function
+        $unsigned_fn:ident, // unsigned division function that is wrapped
+        $signed_fn:ident, // signed division function that is wrapped
+        $uX:ident, // unsigned integer type for the inputs and outputs of `$unsigned_name`
+        $iX:ident, // signed integer type for the inputs and outputs of `$signed_name`
+        $($unsigned_attr:meta),*; // attributes for the unsigned function
+        $($signed_attr:meta),* // attributes for the signed function
+    ) => {
+        /// Computes the quotient and remainder of `duo` divided by `div` and returns them as a
+        /// tuple, or returns `DivError::DivisionByZero` if `div == 0`.
+        $(
+            #[$unsigned_attr]
+        )*
+        pub fn $unsigned_name(duo: $uX, div: $uX) -> Result<($uX, $uX), $crate::DivError> {
+            if div == $crate::cast!(0u8, $uX) {
+                Err($crate::DivError::DivisionByZero)
+            } else {
+                Ok($unsigned_fn(duo, div))
+            }
+        }
+
+        /// Computes the quotient and remainder of `duo` divided by `div` and returns them as a
+        /// tuple, or returns `DivError::DivisionByZero` if `div == 0` and `DivError::Overflow` for
+        /// `iX::MIN / -1`.
+        $(
+            #[$signed_attr]
+        )*
+        pub fn $signed_name(duo: $iX, div: $iX) -> Result<($iX, $iX), $crate::DivError> {
+            if div == $crate::cast!(0i8, $iX) {
+                Err($crate::DivError::DivisionByZero)
+            } else if (duo == $iX::MIN) && (div == $crate::cast!(-1i8, $iX)) {
+                Err($crate::DivError::Overflow)
+            } else {
+                Ok($signed_fn(duo, div))
+            }
+        }
+    };
+}
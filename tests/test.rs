@@ -1,13 +1,24 @@
-use rand::random;
-
 #[rustfmt::skip]
 use specialized_div_rem::{
+    Random,
+    Rng,
     test,
     test_div_by_zero,
+    test_differential,
+    test_normalization_shift,
     u8_div_rem_binary_long,
     i8_div_rem_binary_long,
     u16_div_rem_binary_long,
     i16_div_rem_binary_long,
+    u8_div_rem_table,
+    i8_div_rem_table,
+    u16_div_rem_table,
+    i16_div_rem_table,
+    i8_wrapping_div_rem,
+    i16_wrapping_div_rem,
+    i32_wrapping_div_rem,
+    i64_wrapping_div_rem,
+    i128_wrapping_div_rem,
     u32_div_rem_binary_long,
     i32_div_rem_binary_long,
     u32_div_rem_delegate,
@@ -18,17 +29,102 @@ use specialized_div_rem::{
     i64_div_rem_delegate,
     u64_div_rem_trifecta,
     i64_div_rem_trifecta,
+    u64_div_rem_trifecta_soft_lz,
+    i64_div_rem_trifecta_soft_lz,
     u64_div_rem_asymmetric,
     i64_div_rem_asymmetric,
+    u64_div_rem_float_reciprocal,
+    i64_div_rem_float_reciprocal,
     u128_div_rem_delegate,
     i128_div_rem_delegate,
     u128_div_rem_trifecta,
     i128_div_rem_trifecta,
+    u128_div_rem_trifecta_soft_lz,
+    i128_div_rem_trifecta_soft_lz,
     u128_div_rem_asymmetric,
     i128_div_rem_asymmetric,
+    u128_div_rem_asymmetric_float_reciprocal,
+    i128_div_rem_asymmetric_float_reciprocal,
     u128_div_asymmetric,
+    u64_gcd,
+    u128_gcd,
+    U64Ratio,
+    U128Ratio,
+    U32InvariantRem,
+    U64InvariantRem,
+    U128Digits,
+    U32MagicTable,
+    U64MagicTable,
+    PrecomputedDivisor64,
+    PrecomputedDivisor128,
+    PrecomputedDivisorI64,
+    PrecomputedDivisorI128,
+    u64_div_rem_slice,
+    u128_div_rem_slice,
+    u32_exact_div,
+    u64_exact_div,
+    u128_exact_div,
+    u32_is_multiple_of,
+    u64_is_multiple_of,
+    u128_is_multiple_of,
+    Uint,
+    u8_leading_zeros,
+    u16_leading_zeros,
+    u32_leading_zeros,
+    u64_leading_zeros,
+    u128_leading_zeros,
+    usize_leading_zeros,
+};
+
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+use specialized_div_rem::{
+    i32_div_rem_hardware, i64_div_rem_hardware, u32_div_rem_hardware, u64_div_rem_hardware,
+};
+
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "x86"))]
+use specialized_div_rem::{i128_div_rem_trifecta_asymmetric, u128_div_rem_trifecta_asymmetric};
+
+#[cfg(all(target_arch = "arm", target_feature = "mclass", target_feature = "v7"))]
+use specialized_div_rem::{i128_div_rem_trifecta_delegate, u128_div_rem_trifecta_delegate};
+
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "riscv32",
+    not(target_feature = "m")
+))]
+use specialized_div_rem::{i32_div_rem_binary_long_asm, u32_div_rem_binary_long_asm};
+
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "arm",
+    any(not(target_feature = "thumb-mode"), target_feature = "v6t2")
+))]
+use specialized_div_rem::{
+    i32_div_rem_binary_long_arm, i64_div_rem_delegate_arm, u32_div_rem_binary_long_arm,
+    u64_div_rem_delegate_arm,
 };
 
+/// Returns a random value from a generator that is seeded once per thread
+fn random<T: Random>() -> T {
+    use std::{
+        cell::RefCell,
+        hash::{BuildHasher, Hasher},
+    };
+
+    thread_local! {
+        static RNG: RefCell<Rng> = RefCell::new(Rng::new(
+            std::collections::hash_map::RandomState::new().build_hasher().finish(),
+        ));
+    }
+    RNG.with(|rng| rng.borrow_mut().random())
+}
+
 test!(
     8,
     u8,
@@ -36,6 +132,12 @@ test!(
     div_rem_binary_long_8,
     u8_div_rem_binary_long,
     i8_div_rem_binary_long;
+    div_rem_wrapping_8,
+    u8_div_rem_binary_long,
+    i8_wrapping_div_rem;
+    div_rem_table_8,
+    u8_div_rem_table,
+    i8_div_rem_table;
 );
 test!(
     16,
@@ -44,6 +146,12 @@ test!(
     div_rem_binary_long_16,
     u16_div_rem_binary_long,
     i16_div_rem_binary_long;
+    div_rem_wrapping_16,
+    u16_div_rem_binary_long,
+    i16_wrapping_div_rem;
+    div_rem_table_16,
+    u16_div_rem_table,
+    i16_div_rem_table;
 );
 test!(
     32,
@@ -52,6 +160,9 @@ test!(
     div_rem_binary_long_32,
     u32_div_rem_binary_long,
     i32_div_rem_binary_long;
+    div_rem_wrapping_32,
+    u32_div_rem_delegate,
+    i32_wrapping_div_rem;
     div_rem_delegate_32,
     u32_div_rem_delegate,
     i32_div_rem_delegate;
@@ -63,15 +174,24 @@ test!(
     div_rem_binary_long_64,
     u64_div_rem_binary_long,
     i64_div_rem_binary_long;
+    div_rem_wrapping_64,
+    u64_div_rem_delegate,
+    i64_wrapping_div_rem;
     div_rem_delegate_64,
     u64_div_rem_delegate,
     i64_div_rem_delegate;
     div_rem_trifecta_64,
     u64_div_rem_trifecta,
     i64_div_rem_trifecta;
+    div_rem_trifecta_soft_lz_64,
+    u64_div_rem_trifecta_soft_lz,
+    i64_div_rem_trifecta_soft_lz;
     div_rem_asymmetric_64,
     u64_div_rem_asymmetric,
     i64_div_rem_asymmetric;
+    div_rem_float_reciprocal_64,
+    u64_div_rem_float_reciprocal,
+    i64_div_rem_float_reciprocal;
 );
 test!(
     128,
@@ -80,14 +200,452 @@ test!(
     div_rem_delegate_128,
     u128_div_rem_delegate,
     i128_div_rem_delegate;
+    div_rem_wrapping_128,
+    u128_div_rem_trifecta,
+    i128_wrapping_div_rem;
     div_rem_trifecta_128,
     u128_div_rem_trifecta,
     i128_div_rem_trifecta;
+    div_rem_trifecta_soft_lz_128,
+    u128_div_rem_trifecta_soft_lz,
+    i128_div_rem_trifecta_soft_lz;
     div_rem_asymmetric_128,
     u128_div_rem_asymmetric,
     i128_div_rem_asymmetric;
+    div_rem_asymmetric_float_reciprocal_128,
+    u128_div_rem_asymmetric_float_reciprocal,
+    i128_div_rem_asymmetric_float_reciprocal;
+);
+
+test_differential!(
+    32,
+    u32,
+    i32,
+    differential_32,
+    u32_div_rem_binary_long,
+    i32_div_rem_binary_long;
+    u32_div_rem_delegate,
+    i32_div_rem_delegate;
+);
+test_differential!(
+    64,
+    u64,
+    i64,
+    differential_64,
+    u64_div_rem_binary_long,
+    i64_div_rem_binary_long;
+    u64_div_rem_delegate,
+    i64_div_rem_delegate;
+    u64_div_rem_trifecta,
+    i64_div_rem_trifecta;
+    u64_div_rem_asymmetric,
+    i64_div_rem_asymmetric;
+    u64_div_rem_float_reciprocal,
+    i64_div_rem_float_reciprocal;
+);
+test_differential!(
+    128,
+    u128,
+    i128,
+    differential_128,
+    u128_div_rem_delegate,
+    i128_div_rem_delegate;
+    u128_div_rem_trifecta,
+    i128_div_rem_trifecta;
+    u128_div_rem_asymmetric,
+    i128_div_rem_asymmetric;
+    u128_div_rem_asymmetric_float_reciprocal,
+    i128_div_rem_asymmetric_float_reciprocal;
+);
+
+// compares against the hardware division instructions as an oracle independent of LLVM
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+test_differential!(
+    32,
+    u32,
+    i32,
+    hardware_differential_32,
+    u32_div_rem_hardware,
+    i32_div_rem_hardware;
+    u32_div_rem_binary_long,
+    i32_div_rem_binary_long;
+    u32_div_rem_delegate,
+    i32_div_rem_delegate;
+);
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+test_differential!(
+    64,
+    u64,
+    i64,
+    hardware_differential_64,
+    u64_div_rem_hardware,
+    i64_div_rem_hardware;
+    u64_div_rem_binary_long,
+    i64_div_rem_binary_long;
+    u64_div_rem_delegate,
+    i64_div_rem_delegate;
+    u64_div_rem_trifecta,
+    i64_div_rem_trifecta;
+    u64_div_rem_asymmetric,
+    i64_div_rem_asymmetric;
+);
+
+test_normalization_shift!(8, u8, i8, normalization_shift_8, u8_leading_zeros,);
+test_normalization_shift!(16, u16, i16, normalization_shift_16, u16_leading_zeros,);
+test_normalization_shift!(32, u32, i32, normalization_shift_32, u32_leading_zeros,);
+test_normalization_shift!(64, u64, i64, normalization_shift_64, u64_leading_zeros,);
+test_normalization_shift!(128, u128, i128, normalization_shift_128, u128_leading_zeros,);
+#[cfg(target_pointer_width = "16")]
+test_normalization_shift!(
+    16,
+    usize,
+    isize,
+    normalization_shift_usize,
+    usize_leading_zeros,
+);
+#[cfg(target_pointer_width = "32")]
+test_normalization_shift!(
+    32,
+    usize,
+    isize,
+    normalization_shift_usize,
+    usize_leading_zeros,
+);
+#[cfg(target_pointer_width = "64")]
+test_normalization_shift!(
+    64,
+    usize,
+    isize,
+    normalization_shift_usize,
+    usize_leading_zeros,
+);
+
+// the 128 bit chain of the 64 by 32 bit `div` instruction on 32 bit x86
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "x86"))]
+test!(
+    128,
+    u128,
+    i128,
+    div_rem_trifecta_asymmetric_128,
+    u128_div_rem_trifecta_asymmetric,
+    i128_div_rem_trifecta_asymmetric;
+);
+
+// the 128 bit chain of the 32 bit hardware division on Cortex-M3 and above
+#[cfg(all(target_arch = "arm", target_feature = "mclass", target_feature = "v7"))]
+test!(
+    128,
+    u128,
+    i128,
+    div_rem_trifecta_delegate_128,
+    u128_div_rem_trifecta_delegate,
+    i128_div_rem_trifecta_delegate;
+);
+
+// the RISC-V assembly loop for cores without the M extension
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "riscv32",
+    not(target_feature = "m")
+))]
+test!(
+    32,
+    u32,
+    i32,
+    div_rem_binary_long_asm_32,
+    u32_div_rem_binary_long_asm,
+    i32_div_rem_binary_long_asm;
 );
 
+// the ARM assembly loop and `_delegate` on top of it
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "arm",
+    any(not(target_feature = "thumb-mode"), target_feature = "v6t2")
+))]
+test!(
+    32,
+    u32,
+    i32,
+    div_rem_binary_long_arm_32,
+    u32_div_rem_binary_long_arm,
+    i32_div_rem_binary_long_arm;
+);
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "arm",
+    any(not(target_feature = "thumb-mode"), target_feature = "v6t2")
+))]
+test!(
+    64,
+    u64,
+    i64,
+    div_rem_delegate_arm_64,
+    u64_div_rem_delegate_arm,
+    i64_div_rem_delegate_arm;
+);
+
+// the ARM assembly loop against the Rust loop that it is written from
+#[cfg(all(
+    feature = "asm",
+    not(feature = "no-unsafe"),
+    target_arch = "arm",
+    any(not(target_feature = "thumb-mode"), target_feature = "v6t2")
+))]
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn binary_long_arm_against_rust() {
+    for _ in 0..100_000 {
+        let duo = random::<u32>() >> (random::<u32>() % 32);
+        let div = (random::<u32>() >> (random::<u32>() % 32)) | 1;
+        assert_eq!(
+            u32_div_rem_binary_long_arm(duo, div),
+            u32_div_rem_binary_long(duo, div)
+        );
+        let duo = random::<u64>() >> (random::<u32>() % 64);
+        let div = (random::<u64>() >> (random::<u32>() % 64)) | 1;
+        assert_eq!(
+            u64_div_rem_delegate_arm(duo, div),
+            u64_div_rem_delegate(duo, div)
+        );
+    }
+}
+
+mod target_defaults {
+    use specialized_div_rem::{target_defaults::*, test};
+
+    test!(
+        32,
+        u32,
+        i32,
+        div_rem_target_default_32,
+        u32_div_rem,
+        i32_div_rem;
+    );
+    test!(
+        64,
+        u64,
+        i64,
+        div_rem_target_default_64,
+        u64_div_rem,
+        i64_div_rem;
+    );
+    test!(
+        128,
+        u128,
+        i128,
+        div_rem_target_default_128,
+        u128_div_rem,
+        i128_div_rem;
+    );
+
+    #[cfg(target_arch = "x86_64")]
+    #[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen_test::wasm_bindgen_test
+    )]
+    fn selected_backend_test() {
+        assert_eq!(selected_backend(32), BackendKind::Native);
+        assert_eq!(selected_backend(64), BackendKind::Native);
+        let expected = if cfg!(feature = "slow-mul") {
+            BackendKind::Delegate
+        } else {
+            BackendKind::Asymmetric
+        };
+        assert_eq!(selected_backend(128), expected);
+    }
+
+    #[cfg(target_arch = "powerpc64")]
+    #[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen_test::wasm_bindgen_test
+    )]
+    fn selected_backend_powerpc64_test() {
+        assert_eq!(selected_backend(32), BackendKind::Native);
+        assert_eq!(selected_backend(64), BackendKind::Native);
+        let expected = if cfg!(feature = "slow-mul") {
+            BackendKind::Delegate
+        } else {
+            BackendKind::Trifecta
+        };
+        assert_eq!(selected_backend(128), expected);
+    }
+
+    #[cfg(target_arch = "s390x")]
+    #[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen_test::wasm_bindgen_test
+    )]
+    fn selected_backend_s390x_test() {
+        assert_eq!(selected_backend(32), BackendKind::Native);
+        assert_eq!(selected_backend(64), BackendKind::Native);
+        let expected = if cfg!(feature = "slow-mul") {
+            BackendKind::Delegate
+        } else if cfg!(all(feature = "asm", not(feature = "no-unsafe"))) {
+            BackendKind::Asymmetric
+        } else {
+            BackendKind::Trifecta
+        };
+        assert_eq!(selected_backend(128), expected);
+    }
+
+    #[cfg(target_pointer_width = "16")]
+    #[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen_test::wasm_bindgen_test
+    )]
+    fn selected_backend_16_test() {
+        assert_eq!(selected_backend(32), BackendKind::Delegate);
+        assert_eq!(selected_backend(64), BackendKind::Delegate);
+        assert_eq!(selected_backend(128), BackendKind::Delegate);
+    }
+}
+
+mod tuner {
+    use specialized_div_rem::{test, tuner::*};
+
+    // the tuner measures with `std::time::Instant`, which `wasm32-unknown-unknown` does not have
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    #[test]
+    fn tune_test() {
+        // The winner depends on the machine, so only check that tuning completes and that the
+        // tuned functions are still correct after it (the `test!`s below may run before or after)
+        AutoTuner::new().samples(100).seed(1).tune_all();
+        assert_eq!(u32_div_rem_tuned(100, 7), (14, 2));
+        assert_eq!(i64_div_rem_tuned(-100, 7), (-14, -2));
+        assert_eq!(i64_div_rem_tuned(i64::MIN, -1), (i64::MIN, 0));
+        assert_eq!(u128_div_rem_tuned(u128::MAX, 3), (u128::MAX / 3, 0));
+    }
+
+    test!(
+        32,
+        u32,
+        i32,
+        div_rem_tuned_32,
+        u32_div_rem_tuned,
+        i32_div_rem_tuned;
+    );
+    test!(
+        64,
+        u64,
+        i64,
+        div_rem_tuned_64,
+        u64_div_rem_tuned,
+        i64_div_rem_tuned;
+    );
+    test!(
+        128,
+        u128,
+        i128,
+        div_rem_tuned_128,
+        u128_div_rem_tuned,
+        i128_div_rem_tuned;
+    );
+}
+
+// a mock backend that counts the calls to each of the smaller divisions
+mod backend {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use specialized_div_rem::{impl_div_rem_backend, test, DivRemBackend};
+
+    static HALF_CALLS: AtomicUsize = AtomicUsize::new(0);
+    static ASYMMETRIC_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counting;
+
+    impl DivRemBackend for Counting {
+        fn u32_div_rem(duo: u32, div: u32) -> (u32, u32) {
+            HALF_CALLS.fetch_add(1, Ordering::Relaxed);
+            (duo / div, duo % div)
+        }
+
+        fn u64_by_u32_div_rem(duo: u64, div: u32) -> (u32, u32) {
+            assert!(((duo >> 32) as u32) < div);
+            ASYMMETRIC_CALLS.fetch_add(1, Ordering::Relaxed);
+            ((duo / (div as u64)) as u32, (duo % (div as u64)) as u32)
+        }
+    }
+
+    impl_div_rem_backend!(counting, Counting);
+    use counting::*;
+
+    #[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen_test::wasm_bindgen_test
+    )]
+    fn backend_test() {
+        let half = HALF_CALLS.load(Ordering::Relaxed);
+        assert_eq!(
+            counting::u64_div_rem_delegate(1 << 40, 3),
+            ((1 << 40) / 3, 1)
+        );
+        assert!(HALF_CALLS.load(Ordering::Relaxed) > half);
+        let asymmetric = ASYMMETRIC_CALLS.load(Ordering::Relaxed);
+        assert_eq!(
+            counting::u64_div_rem_asymmetric(1 << 40, 3),
+            ((1 << 40) / 3, 1)
+        );
+        assert!(ASYMMETRIC_CALLS.load(Ordering::Relaxed) > asymmetric);
+    }
+
+    test!(
+        32,
+        u32,
+        i32,
+        div_rem_backend_delegate_32,
+        u32_div_rem_delegate,
+        i32_div_rem_delegate;
+    );
+    test!(
+        64,
+        u64,
+        i64,
+        div_rem_backend_delegate_64,
+        u64_div_rem_delegate,
+        i64_div_rem_delegate;
+        div_rem_backend_trifecta_64,
+        u64_div_rem_trifecta,
+        i64_div_rem_trifecta;
+        div_rem_backend_asymmetric_64,
+        u64_div_rem_asymmetric,
+        i64_div_rem_asymmetric;
+    );
+    test!(
+        128,
+        u128,
+        i128,
+        div_rem_backend_delegate_128,
+        u128_div_rem_delegate,
+        i128_div_rem_delegate;
+        div_rem_backend_trifecta_128,
+        u128_div_rem_trifecta,
+        i128_div_rem_trifecta;
+        div_rem_backend_asymmetric_128,
+        u128_div_rem_asymmetric,
+        i128_div_rem_asymmetric;
+    );
+}
+
 test_div_by_zero!(
     dbz0, u8_div_rem_binary_long;
     dbz1, i8_div_rem_binary_long;
@@ -111,9 +669,3391 @@ test_div_by_zero!(
     dbz19, i128_div_rem_trifecta;
     dbz20, u128_div_rem_asymmetric;
     dbz21, i128_div_rem_asymmetric;
+    dbz22, u64_div_rem_float_reciprocal;
+    dbz23, i64_div_rem_float_reciprocal;
+    dbz24, u128_div_rem_asymmetric_float_reciprocal;
+    dbz25, i128_div_rem_asymmetric_float_reciprocal;
+    dbz26, u8_div_rem_table;
+    dbz27, i8_div_rem_table;
+    dbz28, u16_div_rem_table;
+    dbz29, i16_div_rem_table;
 );
 
-#[test]
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
 fn sanity_test() {
     assert_eq!(u128_div_asymmetric(1337 << 63, 42), 293610676506543696554);
 }
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn ratio_test() {
+    assert_eq!(u64_gcd(0, 0), 0);
+    assert_eq!(u64_gcd(0, 7), 7);
+    assert_eq!(u128_gcd(1337 << 70, 42 << 65), 7 << 66);
+
+    let x = U64Ratio::new(84, 36).reduce();
+    assert_eq!((x.num, x.den), (7, 3));
+    assert_eq!(U64Ratio::new(1, 2), U64Ratio::new(3, 6));
+    assert!(U64Ratio::new(u64::MAX - 1, u64::MAX) < U64Ratio::new(u64::MAX, u64::MAX - 1));
+    assert!(
+        U128Ratio::new(u128::MAX - 1, u128::MAX) > U128Ratio::new(u128::MAX - 2, u128::MAX - 1)
+    );
+    // 100/7 == 2*5 + 30/7
+    let (quo, rem) = U64Ratio::new(100, 7).div_rem(5);
+    assert_eq!((quo, rem.num, rem.den), (2, 30, 7));
+    let (quo, rem) = U128Ratio::new(u128::MAX, 3).div_rem(1 << 100);
+    assert_eq!(quo, (u128::MAX / 3) >> 100);
+    assert_eq!(rem, U128Ratio::new(u128::MAX - quo * 3 * (1 << 100), 3));
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn table_test() {
+    for duo in 0..=u8::MAX {
+        for div in 1..=u8::MAX {
+            assert_eq!(u8_div_rem_table(duo, div), (duo / div, duo % div));
+        }
+    }
+    // The estimates for divisors of more than 8 bits are corrected the most for divisors just above
+    // 256, where rounding up the upper 8 bits changes the divisor the most
+    for duo in 0..=u16::MAX {
+        for div in [
+            1,
+            7,
+            255,
+            256,
+            257,
+            258,
+            260,
+            383,
+            511,
+            512,
+            513,
+            4097,
+            32769,
+            u16::MAX,
+        ] {
+            assert_eq!(u16_div_rem_table(duo, div), (duo / div, duo % div));
+        }
+    }
+}
+
+#[cfg(all(feature = "asm", not(feature = "no-unsafe"), target_arch = "x86_64"))]
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn hardware_differential_128_by_64() {
+    use specialized_div_rem::u128_by_u64_div_rem_hardware;
+    for _ in 0..1_000_000 {
+        let div = random::<u64>() >> (random::<u32>() & 63);
+        if div == 0 {
+            continue;
+        }
+        // keep the quotient within the range of the hardware instruction
+        let duo_hi = random::<u64>() % div;
+        let duo = (random::<u64>() as u128) | ((duo_hi as u128) << 64);
+        let (quo, rem) = u128_by_u64_div_rem_hardware(duo, div);
+        let expected = (quo as u128, rem as u128);
+        assert_eq!(u128_div_rem_delegate(duo, div as u128), expected);
+        assert_eq!(u128_div_rem_trifecta(duo, div as u128), expected);
+        assert_eq!(u128_div_rem_asymmetric(duo, div as u128), expected);
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn checked_64_by_32() {
+    use specialized_div_rem::u64_by_u32_div_rem_checked;
+    for _ in 0..1_000_000 {
+        let duo = random::<u64>() >> (random::<u32>() & 63);
+        let div = random::<u32>() >> (random::<u32>() & 31);
+        if div == 0 {
+            continue;
+        }
+        assert_eq!(
+            u64_by_u32_div_rem_checked(duo, div),
+            (duo / (div as u64), (duo % (div as u64)) as u32)
+        );
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn checked_asymmetric_primitives() {
+    use specialized_div_rem::{u128_by_u64_div_rem, u64_by_u32_div_rem};
+    for _ in 0..1_000_000 {
+        let duo = random::<u128>() >> (random::<u32>() & 127);
+        let div = random::<u64>() >> (random::<u32>() & 63);
+        let expected = if div == 0 {
+            None
+        } else {
+            let quo = duo / (div as u128);
+            u64::try_from(quo)
+                .ok()
+                .map(|quo| (quo, (duo % (div as u128)) as u64))
+        };
+        assert_eq!(u128_by_u64_div_rem(duo, div), expected);
+
+        let duo = duo as u64;
+        let div = div as u32;
+        let expected = if div == 0 {
+            None
+        } else {
+            let quo = duo / (div as u64);
+            u32::try_from(quo)
+                .ok()
+                .map(|quo| (quo, (duo % (div as u64)) as u32))
+        };
+        assert_eq!(u64_by_u32_div_rem(duo, div), expected);
+    }
+    assert_eq!(u128_by_u64_div_rem(u128::MAX >> 64, 1), Some((u64::MAX, 0)));
+    assert_eq!(u128_by_u64_div_rem(1 << 64, 1), None);
+    assert_eq!(u64_by_u32_div_rem(0, 0), None);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn mixed_width_short_division() {
+    use specialized_div_rem::{u128_div_rem_by_u32, u128_div_rem_by_u64, u64_div_rem_by_u16};
+    for _ in 0..1_000_000 {
+        let duo = random::<u128>() >> (random::<u32>() & 127);
+        let div = random::<u64>() >> (random::<u32>() & 63);
+        if div != 0 {
+            let expected = (duo / (div as u128), duo % (div as u128));
+            let (quo, rem) = u128_div_rem_by_u64(duo, div);
+            assert_eq!((quo, rem as u128), expected);
+        }
+        let div = (div as u32) | 1;
+        let (quo, rem) = u128_div_rem_by_u32(duo, div);
+        assert_eq!(
+            (quo, rem as u128),
+            (duo / (div as u128), duo % (div as u128))
+        );
+        let duo = duo as u64;
+        let div = div as u16;
+        if div != 0 {
+            let (quo, rem) = u64_div_rem_by_u16(duo, div);
+            assert_eq!((quo, rem as u64), (duo / (div as u64), duo % (div as u64)));
+        }
+    }
+    assert_eq!(u128_div_rem_by_u32(u128::MAX, 10), (u128::MAX / 10, 5));
+    assert_eq!(
+        u64_div_rem_by_u16(u64::MAX, u16::MAX),
+        (u64::MAX / 65535, 0)
+    );
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+#[should_panic(expected = "attempt to divide by zero")]
+fn mixed_width_short_division_by_zero() {
+    specialized_div_rem::u128_div_rem_by_u32(1, 0);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn div_rem_bits_test() {
+    use specialized_div_rem::div_rem_bits;
+    fn check<const BITS: u32>(duo: u128, div: u128) {
+        let mask = u128::MAX >> (128 - BITS);
+        let (duo_m, div_m) = (duo & mask, div & mask);
+        if let Some(quo) = duo_m.checked_div(div_m) {
+            assert_eq!(div_rem_bits::<BITS>(duo, div), (quo, duo_m % div_m));
+        }
+    }
+    for _ in 0..100_000 {
+        let duo = random::<u128>() >> (random::<u32>() & 127);
+        let div = random::<u128>() >> (random::<u32>() & 127);
+        check::<1>(duo, div);
+        check::<7>(duo, div);
+        check::<32>(duo, div);
+        check::<33>(duo, div);
+        check::<48>(duo, div);
+        check::<64>(duo, div);
+        check::<96>(duo, div);
+        check::<112>(duo, div);
+        check::<128>(duo, div);
+    }
+    // both steps of the `u128_by_u64` path for widths up to 96 bits
+    for _ in 0..100_000 {
+        let duo = random::<u128>();
+        let div = random::<u128>() >> (64 + (random::<u32>() & 63));
+        check::<65>(duo, div);
+        check::<80>(duo, div);
+        check::<96>(duo, div);
+    }
+    // the upper bits are ignored
+    assert_eq!(
+        div_rem_bits::<48>(u128::MAX, (1 << 48) | 10),
+        (((1 << 48) - 1) / 10, 5)
+    );
+}
+
+mod variants {
+    use specialized_div_rem::*;
+
+    test_variants!(
+        8,
+        u8,
+        i8,
+        variants_8,
+        checked: u8_checked_div_rem_binary_long, i8_checked_div_rem_binary_long;
+        checked: u8_checked_div_rem_table, i8_checked_div_rem_table;
+        result: u8_try_div_rem_binary_long, i8_try_div_rem_binary_long;
+        result: u8_try_div_rem_table, i8_try_div_rem_table;
+        overflowing: i8_overflowing_div_rem;
+        quotient: u8_div_binary_long, i8_div_binary_long;
+        ceil:
+            u8_div_rem_ceil_binary_long, u8_div_ceil_binary_long,
+            i8_div_rem_ceil_binary_long, i8_div_ceil_binary_long;
+        ceil: u8_div_rem_ceil_table, u8_div_ceil_table, i8_div_rem_ceil_table, i8_div_ceil_table;
+        floor: i8_div_rem_floor_binary_long;
+        floor: i8_div_rem_floor_table;
+        saturating: i8_saturating_div_rem_binary_long, i8_saturating_div_binary_long;
+        saturating: i8_saturating_div_rem_table, i8_saturating_div_table;
+        round: u8_div_rem_round, i8_div_rem_round;
+    );
+    test_variants!(
+        16,
+        u16,
+        i16,
+        variants_16,
+        checked: u16_checked_div_rem_binary_long, i16_checked_div_rem_binary_long;
+        checked: u16_checked_div_rem_table, i16_checked_div_rem_table;
+        result: u16_try_div_rem_binary_long, i16_try_div_rem_binary_long;
+        result: u16_try_div_rem_table, i16_try_div_rem_table;
+        overflowing: i16_overflowing_div_rem;
+        quotient: u16_div_binary_long, i16_div_binary_long;
+        ceil:
+            u16_div_rem_ceil_binary_long, u16_div_ceil_binary_long,
+            i16_div_rem_ceil_binary_long, i16_div_ceil_binary_long;
+        ceil:
+            u16_div_rem_ceil_table, u16_div_ceil_table,
+            i16_div_rem_ceil_table, i16_div_ceil_table;
+        floor: i16_div_rem_floor_binary_long;
+        floor: i16_div_rem_floor_table;
+        saturating: i16_saturating_div_rem_binary_long, i16_saturating_div_binary_long;
+        saturating: i16_saturating_div_rem_table, i16_saturating_div_table;
+        round: u16_div_rem_round, i16_div_rem_round;
+    );
+    test_variants!(
+        32,
+        u32,
+        i32,
+        variants_32,
+        checked: u32_checked_div_rem_binary_long, i32_checked_div_rem_binary_long;
+        checked: u32_checked_div_rem_delegate, i32_checked_div_rem_delegate;
+        result: u32_try_div_rem_binary_long, i32_try_div_rem_binary_long;
+        result: u32_try_div_rem_delegate, i32_try_div_rem_delegate;
+        overflowing: i32_overflowing_div_rem;
+        quotient: u32_div_binary_long, i32_div_binary_long;
+        quotient: u32_div_delegate, i32_div_delegate;
+        ceil:
+            u32_div_rem_ceil_binary_long, u32_div_ceil_binary_long,
+            i32_div_rem_ceil_binary_long, i32_div_ceil_binary_long;
+        ceil:
+            u32_div_rem_ceil_delegate, u32_div_ceil_delegate,
+            i32_div_rem_ceil_delegate, i32_div_ceil_delegate;
+        floor: i32_div_rem_floor_binary_long;
+        floor: i32_div_rem_floor_delegate;
+        saturating: i32_saturating_div_rem_binary_long, i32_saturating_div_binary_long;
+        saturating: i32_saturating_div_rem_delegate, i32_saturating_div_delegate;
+        round: u32_div_rem_round, i32_div_rem_round;
+    );
+    test_variants!(
+        64,
+        u64,
+        i64,
+        variants_64,
+        checked: u64_checked_div_rem_binary_long, i64_checked_div_rem_binary_long;
+        checked: u64_checked_div_rem_delegate, i64_checked_div_rem_delegate;
+        checked: u64_checked_div_rem_trifecta, i64_checked_div_rem_trifecta;
+        checked: u64_checked_div_rem_trifecta_soft_lz, i64_checked_div_rem_trifecta_soft_lz;
+        checked: u64_checked_div_rem_asymmetric, i64_checked_div_rem_asymmetric;
+        checked: u64_checked_div_rem_float_reciprocal, i64_checked_div_rem_float_reciprocal;
+        result: u64_try_div_rem_binary_long, i64_try_div_rem_binary_long;
+        result: u64_try_div_rem_delegate, i64_try_div_rem_delegate;
+        result: u64_try_div_rem_trifecta, i64_try_div_rem_trifecta;
+        result: u64_try_div_rem_trifecta_soft_lz, i64_try_div_rem_trifecta_soft_lz;
+        result: u64_try_div_rem_asymmetric, i64_try_div_rem_asymmetric;
+        result: u64_try_div_rem_float_reciprocal, i64_try_div_rem_float_reciprocal;
+        overflowing: i64_overflowing_div_rem;
+        quotient: u64_div_binary_long, i64_div_binary_long;
+        quotient: u64_div_delegate, i64_div_delegate;
+        quotient: u64_div_trifecta, i64_div_trifecta;
+        quotient: u64_div_trifecta_soft_lz, i64_div_trifecta_soft_lz;
+        quotient: u64_div_asymmetric, i64_div_asymmetric;
+        quotient: u64_div_float_reciprocal, i64_div_float_reciprocal;
+        ceil:
+            u64_div_rem_ceil_binary_long, u64_div_ceil_binary_long,
+            i64_div_rem_ceil_binary_long, i64_div_ceil_binary_long;
+        ceil:
+            u64_div_rem_ceil_delegate, u64_div_ceil_delegate,
+            i64_div_rem_ceil_delegate, i64_div_ceil_delegate;
+        ceil:
+            u64_div_rem_ceil_trifecta, u64_div_ceil_trifecta,
+            i64_div_rem_ceil_trifecta, i64_div_ceil_trifecta;
+        ceil:
+            u64_div_rem_ceil_trifecta_soft_lz, u64_div_ceil_trifecta_soft_lz,
+            i64_div_rem_ceil_trifecta_soft_lz, i64_div_ceil_trifecta_soft_lz;
+        ceil:
+            u64_div_rem_ceil_asymmetric, u64_div_ceil_asymmetric,
+            i64_div_rem_ceil_asymmetric, i64_div_ceil_asymmetric;
+        ceil:
+            u64_div_rem_ceil_float_reciprocal, u64_div_ceil_float_reciprocal,
+            i64_div_rem_ceil_float_reciprocal, i64_div_ceil_float_reciprocal;
+        euclid: i64_div_rem_euclid_binary_long;
+        euclid: i64_div_rem_euclid_delegate;
+        euclid: i64_div_rem_euclid_trifecta;
+        euclid: i64_div_rem_euclid_trifecta_soft_lz;
+        euclid: i64_div_rem_euclid_asymmetric;
+        euclid: i64_div_rem_euclid_float_reciprocal;
+        floor: i64_div_rem_floor_binary_long;
+        floor: i64_div_rem_floor_delegate;
+        floor: i64_div_rem_floor_trifecta;
+        floor: i64_div_rem_floor_trifecta_soft_lz;
+        floor: i64_div_rem_floor_asymmetric;
+        floor: i64_div_rem_floor_float_reciprocal;
+        saturating: i64_saturating_div_rem_binary_long, i64_saturating_div_binary_long;
+        saturating: i64_saturating_div_rem_delegate, i64_saturating_div_delegate;
+        saturating: i64_saturating_div_rem_trifecta, i64_saturating_div_trifecta;
+        saturating: i64_saturating_div_rem_trifecta_soft_lz, i64_saturating_div_trifecta_soft_lz;
+        saturating: i64_saturating_div_rem_asymmetric, i64_saturating_div_asymmetric;
+        saturating: i64_saturating_div_rem_float_reciprocal, i64_saturating_div_float_reciprocal;
+        round: u64_div_rem_round, i64_div_rem_round;
+    );
+    test_variants!(
+        128,
+        u128,
+        i128,
+        variants_128,
+        checked: u128_checked_div_rem_delegate, i128_checked_div_rem_delegate;
+        checked: u128_checked_div_rem_trifecta, i128_checked_div_rem_trifecta;
+        checked: u128_checked_div_rem_trifecta_soft_lz, i128_checked_div_rem_trifecta_soft_lz;
+        checked: u128_checked_div_rem_asymmetric, i128_checked_div_rem_asymmetric;
+        checked:
+            u128_checked_div_rem_asymmetric_float_reciprocal,
+            i128_checked_div_rem_asymmetric_float_reciprocal;
+        result: u128_try_div_rem_delegate, i128_try_div_rem_delegate;
+        result: u128_try_div_rem_trifecta, i128_try_div_rem_trifecta;
+        result: u128_try_div_rem_trifecta_soft_lz, i128_try_div_rem_trifecta_soft_lz;
+        result: u128_try_div_rem_asymmetric, i128_try_div_rem_asymmetric;
+        result:
+            u128_try_div_rem_asymmetric_float_reciprocal,
+            i128_try_div_rem_asymmetric_float_reciprocal;
+        overflowing: i128_overflowing_div_rem;
+        quotient: u128_div_delegate, i128_div_delegate;
+        quotient: u128_div_trifecta, i128_div_trifecta;
+        quotient: u128_div_trifecta_soft_lz, i128_div_trifecta_soft_lz;
+        quotient: u128_div_asymmetric, i128_div_asymmetric;
+        quotient: u128_div_asymmetric_float_reciprocal, i128_div_asymmetric_float_reciprocal;
+        ceil:
+            u128_div_rem_ceil_delegate, u128_div_ceil_delegate,
+            i128_div_rem_ceil_delegate, i128_div_ceil_delegate;
+        ceil:
+            u128_div_rem_ceil_trifecta, u128_div_ceil_trifecta,
+            i128_div_rem_ceil_trifecta, i128_div_ceil_trifecta;
+        ceil:
+            u128_div_rem_ceil_trifecta_soft_lz, u128_div_ceil_trifecta_soft_lz,
+            i128_div_rem_ceil_trifecta_soft_lz, i128_div_ceil_trifecta_soft_lz;
+        ceil:
+            u128_div_rem_ceil_asymmetric, u128_div_ceil_asymmetric,
+            i128_div_rem_ceil_asymmetric, i128_div_ceil_asymmetric;
+        ceil:
+            u128_div_rem_ceil_asymmetric_float_reciprocal,
+            u128_div_ceil_asymmetric_float_reciprocal,
+            i128_div_rem_ceil_asymmetric_float_reciprocal,
+            i128_div_ceil_asymmetric_float_reciprocal;
+        euclid: i128_div_rem_euclid_delegate;
+        euclid: i128_div_rem_euclid_trifecta;
+        euclid: i128_div_rem_euclid_trifecta_soft_lz;
+        euclid: i128_div_rem_euclid_asymmetric;
+        euclid: i128_div_rem_euclid_asymmetric_float_reciprocal;
+        floor: i128_div_rem_floor_delegate;
+        floor: i128_div_rem_floor_trifecta;
+        floor: i128_div_rem_floor_trifecta_soft_lz;
+        floor: i128_div_rem_floor_asymmetric;
+        floor: i128_div_rem_floor_asymmetric_float_reciprocal;
+        saturating: i128_saturating_div_rem_delegate, i128_saturating_div_delegate;
+        saturating: i128_saturating_div_rem_trifecta, i128_saturating_div_trifecta;
+        saturating: i128_saturating_div_rem_trifecta_soft_lz, i128_saturating_div_trifecta_soft_lz;
+        saturating: i128_saturating_div_rem_asymmetric, i128_saturating_div_asymmetric;
+        saturating:
+            i128_saturating_div_rem_asymmetric_float_reciprocal,
+            i128_saturating_div_asymmetric_float_reciprocal;
+        round: u128_div_rem_round, i128_div_rem_round;
+    );
+
+    // `u256` and the wider types do not have the methods of the primitive integers that the
+    // references of `test_variants!` are built from
+    #[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+    #[cfg_attr(
+        all(target_arch = "wasm32", target_os = "unknown"),
+        wasm_bindgen_test::wasm_bindgen_test
+    )]
+    fn variants_wide() {
+        let neg_one = i256::from(-1i128);
+        let zero = i256::from(0i128);
+        assert_eq!(u256_checked_div_rem_trifecta(u256::MAX, u256::MIN), None);
+        assert_eq!(i256_checked_div_rem_delegate(i256::MIN, neg_one), None);
+        assert_eq!(
+            i256_checked_div_rem_delegate(i256::MIN, i256::MAX),
+            Some((neg_one, neg_one))
+        );
+        assert_eq!(
+            u256_try_div_rem_trifecta(u256::MAX, u256::MIN),
+            Err(DivError::DivisionByZero)
+        );
+        assert_eq!(
+            i256_try_div_rem_delegate(i256::MIN, neg_one),
+            Err(DivError::Overflow)
+        );
+        assert_eq!(
+            i256_saturating_div_rem_trifecta(i256::MIN, neg_one),
+            (i256::MAX, zero)
+        );
+        assert_eq!(
+            i256_saturating_div_delegate(i256::MAX, neg_one),
+            i256::MAX.wrapping_neg()
+        );
+
+        let (duo, div) = (u256::MAX, u256::from(u128::MAX));
+        let expected = u256::from_halves(1, 1);
+        assert_eq!(u256_div_delegate(duo, div), expected);
+        assert_eq!(u256_div_trifecta(duo, div), expected);
+        assert_eq!(u256_div_asymmetric(duo, div), expected);
+        let (duo, div) = (u512::MAX, u512::from(u256::MAX));
+        let expected = u512::from_halves(u256::from(1u128), u256::from(1u128));
+        assert_eq!(u512_div_delegate(duo, div), expected);
+        assert_eq!(u512_div_trifecta(duo, div), expected);
+
+        let two = u256::from(2u128);
+        assert_eq!(u256_div_ceil_trifecta(u256::MAX, two), !(u256::MAX >> 1u32));
+        assert_eq!(
+            u512_div_ceil_delegate(u512::MAX, two.into()),
+            !(u512::MAX >> 1u32)
+        );
+
+        let (seven, two) = (i256::from(7i128), i256::from(2i128));
+        type Fn256 = fn(i256, i256) -> (i256, i256);
+        type Fn512 = fn(i512, i512) -> (i512, i512);
+        let floor_fns: [Fn256; 3] = [
+            i256_div_rem_floor_delegate,
+            i256_div_rem_floor_trifecta,
+            i256_div_rem_floor_asymmetric,
+        ];
+        for floor_fn in floor_fns {
+            let (quo, rem) = floor_fn(seven.wrapping_neg(), two);
+            assert_eq!((quo, rem), (i256::from(-4i128), i256::from(1i128)));
+            let (quo, rem) = floor_fn(seven, two.wrapping_neg());
+            assert_eq!((quo, rem), (i256::from(-4i128), i256::from(-1i128)));
+        }
+        let (seven, two) = (i512::from(seven), i512::from(two));
+        let floor_fns: [Fn512; 2] = [i512_div_rem_floor_delegate, i512_div_rem_floor_trifecta];
+        for floor_fn in floor_fns {
+            let (quo, rem) = floor_fn(seven.wrapping_neg(), two);
+            assert_eq!(
+                (quo, rem),
+                (
+                    i512::from(i256::from(-4i128)),
+                    i512::from(i256::from(1i128))
+                )
+            );
+        }
+
+        assert_eq!(
+            DivError::DivisionByZero.to_string(),
+            "attempt to divide by zero"
+        );
+        assert_eq!(
+            DivError::Overflow.to_string(),
+            "attempt to divide with overflow"
+        );
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn u128_by_u64_div_rem_reciprocal_test() {
+    use specialized_div_rem::u128_by_u64_div_rem_reciprocal;
+
+    assert_eq!(u128_by_u64_div_rem_reciprocal(0, 0), None);
+    assert_eq!(u128_by_u64_div_rem_reciprocal(1 << 64, 1), None);
+    assert_eq!(u128_by_u64_div_rem_reciprocal(u128::MAX, u64::MAX), None);
+    assert_eq!(
+        u128_by_u64_div_rem_reciprocal(u128::MAX >> 1, 1 << 63),
+        Some((u64::MAX, (1 << 63) - 1))
+    );
+    let mut divs = vec![1, 2, 3, 1 << 63, (1 << 63) + 1, u64::MAX - 1, u64::MAX];
+    for i in 1..64 {
+        divs.push((1 << i) - 1);
+        divs.push((1 << i) + 1);
+    }
+    for _ in 0..10_000 {
+        divs.push(random::<u64>() >> (random::<u32>() & 63));
+    }
+    for div in divs {
+        for _ in 0..10 {
+            let duo = random::<u128>() >> (random::<u32>() & 127);
+            let expected = if ((duo >> 64) as u64) < div {
+                Some(((duo / (div as u128)) as u64, (duo % (div as u128)) as u64))
+            } else {
+                None
+            };
+            assert_eq!(u128_by_u64_div_rem_reciprocal(duo, div), expected);
+        }
+        if div != 0 {
+            // the largest dividend whose quotient fits
+            let duo = ((div as u128) << 64) - 1;
+            let expected = Some((u64::MAX, div - 1));
+            assert_eq!(u128_by_u64_div_rem_reciprocal(duo, div), expected);
+        }
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn checked_i128_by_i64() {
+    use specialized_div_rem::i128_by_i64_div_rem_checked;
+
+    // the `i128` operators are the oracle, with the quotient range checked by `i64::try_from`
+    let expected = |duo: i128, div: i64| -> Option<(i64, i64)> {
+        if div == 0 {
+            return None;
+        }
+        // `i128::MIN / -1` overflows even the `i128` oracle
+        let quo = i64::try_from(duo.checked_div(div as i128)?).ok()?;
+        Some((quo, (duo % (div as i128)) as i64))
+    };
+    let edges: [i128; 8] = [
+        i128::MIN,
+        i128::MAX,
+        (i64::MIN as i128) << 64,
+        ((i64::MAX as i128) << 64) | (u64::MAX as i128),
+        i64::MIN as i128,
+        i64::MAX as i128,
+        -1,
+        0,
+    ];
+    for duo in edges {
+        for div in [i64::MIN, i64::MAX, -1, 0, 1, 2, -2] {
+            assert_eq!(i128_by_i64_div_rem_checked(duo, div), expected(duo, div));
+        }
+    }
+    for _ in 0..1_000_000 {
+        let duo = random::<i128>() >> (random::<u32>() & 127);
+        let div = random::<i64>() >> (random::<u32>() & 63);
+        assert_eq!(i128_by_i64_div_rem_checked(duo, div), expected(duo, div));
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn msvc_div128_test() {
+    use specialized_div_rem::{_div128, _udiv128, i128_by_i64_div_rem_checked};
+
+    for _ in 0..100_000 {
+        let div = random::<u64>() >> (random::<u32>() & 63);
+        // the quotient has to fit
+        let hi = random::<u64>() % div.max(1);
+        let lo = random::<u64>();
+        if div != 0 {
+            let duo = ((hi as u128) << 64) | (lo as u128);
+            let mut rem = 0;
+            assert_eq!(_udiv128(hi, lo, div, &mut rem) as u128, duo / (div as u128));
+            assert_eq!(rem as u128, duo % (div as u128));
+        }
+
+        let duo = random::<i128>() >> (random::<u32>() & 127);
+        let div = random::<i64>() >> (random::<u32>() & 63);
+        if let Some((quo, rem)) = i128_by_i64_div_rem_checked(duo, div) {
+            let mut found_rem = 0;
+            assert_eq!(
+                _div128((duo >> 64) as i64, duo as i64, div, &mut found_rem),
+                quo
+            );
+            assert_eq!(found_rem, rem);
+        }
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+#[should_panic]
+fn msvc_udiv128_overflow() {
+    let mut rem = 0;
+    specialized_div_rem::_udiv128(1, 0, 1, &mut rem);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+#[should_panic]
+fn msvc_div128_overflow() {
+    let mut rem = 0;
+    specialized_div_rem::_div128(i64::MIN, 0, -1, &mut rem);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn invariant_rem_test() {
+    for div in [1, 2, 3, 7, u32::MAX - 1, u32::MAX] {
+        let inv = U32InvariantRem::new(div);
+        for duo in [0, 1, div - 1, div, u32::MAX - 1, u32::MAX] {
+            assert_eq!(inv.rem_of(duo), duo % div);
+        }
+    }
+    for _ in 0..100_000 {
+        let div = random::<u32>() >> (random::<u32>() & 31);
+        let duo = random::<u32>();
+        if div != 0 {
+            assert_eq!(U32InvariantRem::new(div).rem_of(duo), duo % div);
+        }
+        let div = random::<u64>() >> (random::<u32>() & 63);
+        let duo = random::<u64>();
+        if div != 0 {
+            assert_eq!(U64InvariantRem::new(div).rem_of(duo), duo % div);
+        }
+    }
+    for div in [1, 2, 3, 7, u64::MAX - 1, u64::MAX] {
+        let inv = U64InvariantRem::new(div);
+        for duo in [0, 1, div - 1, div, u64::MAX - 1, u64::MAX] {
+            assert_eq!(inv.rem_of(duo), duo % div);
+        }
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn precomputed_divisor_test() {
+    let mut divs = vec![
+        1,
+        2,
+        3,
+        5,
+        6,
+        7,
+        10,
+        1 << 63,
+        (1 << 63) + 1,
+        u64::MAX - 1,
+        u64::MAX,
+    ];
+    for i in 1..64 {
+        divs.push((1 << i) - 1);
+        divs.push((1 << i) + 1);
+    }
+    for _ in 0..1000 {
+        let div = random::<u64>() >> (random::<u32>() & 63);
+        if div != 0 {
+            divs.push(div);
+        }
+    }
+    for div in divs {
+        let pre = PrecomputedDivisor64::new(div);
+        assert_eq!(pre.divisor(), div);
+        let mut duos = vec![
+            0,
+            1,
+            div - 1,
+            div,
+            div.wrapping_add(1),
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+        for _ in 0..100 {
+            duos.push(random::<u64>() >> (random::<u32>() & 63));
+        }
+        for duo in duos {
+            assert_eq!(pre.div_rem_of(duo), (duo / div, duo % div));
+            assert_eq!(pre.div_of(duo), duo / div);
+            assert_eq!(pre.rem_of(duo), duo % div);
+        }
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn precomputed_divisor_128_test() {
+    let mut divs = vec![
+        1,
+        2,
+        3,
+        7,
+        10,
+        1 << 127,
+        (1 << 127) + 1,
+        u128::MAX - 1,
+        u128::MAX,
+    ];
+    for i in 1..128 {
+        divs.push((1 << i) - 1);
+        divs.push((1 << i) + 1);
+    }
+    for _ in 0..1000 {
+        let div = random::<u128>() >> (random::<u32>() & 127);
+        if div != 0 {
+            divs.push(div);
+        }
+    }
+    for div in divs {
+        let pre = PrecomputedDivisor128::new(div);
+        assert_eq!(pre.divisor(), div);
+        let mut duos = vec![
+            0,
+            1,
+            div - 1,
+            div,
+            div.wrapping_add(1),
+            u128::MAX - 1,
+            u128::MAX,
+        ];
+        for _ in 0..100 {
+            duos.push(random::<u128>() >> (random::<u32>() & 127));
+        }
+        for duo in duos {
+            assert_eq!(pre.div_rem_of(duo), (duo / div, duo % div));
+            assert_eq!(pre.div_of(duo), duo / div);
+        }
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn signed_precomputed_divisor_test() {
+    macro_rules! check {
+        ($iX:ident, $name:ident) => {
+            let mut divs = vec![1, -1, 2, -2, 3, -3, 7, -7, $iX::MIN, $iX::MIN + 1, $iX::MAX];
+            for i in 1..($iX::BITS - 1) {
+                for div in [(1 << i) - 1, 1 << i, (1 << i) + 1] {
+                    divs.push(div);
+                    divs.push(-div);
+                }
+            }
+            for _ in 0..1000 {
+                let div = random::<$iX>() >> (random::<u32>() % $iX::BITS);
+                if div != 0 {
+                    divs.push(div);
+                }
+            }
+            for div in divs {
+                let pre = $name::new(div);
+                assert_eq!(pre.divisor(), div);
+                let mut duos = vec![0, 1, -1, div, div.wrapping_neg(), $iX::MIN, $iX::MAX];
+                duos.extend([div.wrapping_sub(1), div.wrapping_add(1), $iX::MIN + 1]);
+                for _ in 0..100 {
+                    duos.push(random::<$iX>() >> (random::<u32>() % $iX::BITS));
+                }
+                for duo in duos {
+                    let expected = (duo.wrapping_div(div), duo.wrapping_rem(div));
+                    assert_eq!(pre.div_rem_of(duo), expected, "{} / {}", duo, div);
+                    assert_eq!(pre.div_of(duo), expected.0);
+                    assert_eq!(pre.rem_of(duo), expected.1);
+                }
+            }
+        };
+    }
+    check!(i64, PrecomputedDivisorI64);
+    check!(i128, PrecomputedDivisorI128);
+
+    const D: PrecomputedDivisorI64 = PrecomputedDivisorI64::new(-1_000_000_007);
+    assert_eq!(D.div_rem_of(-5_000_000_036), (5, -1));
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn precomputed_divisor_const_test() {
+    const D: PrecomputedDivisor64 = PrecomputedDivisor64::new(1_000_000_007);
+    static E: PrecomputedDivisor128 = PrecomputedDivisor128::new(10u128.pow(30) + 7);
+    // evaluated entirely at compile time
+    const QUO: u64 = D.div_of(u64::MAX);
+    assert_eq!(QUO, u64::MAX / 1_000_000_007);
+    assert_eq!(
+        D,
+        PrecomputedDivisor64::new(std::hint::black_box(1_000_000_007))
+    );
+    assert_eq!(
+        E,
+        PrecomputedDivisor128::new(std::hint::black_box(10u128.pow(30) + 7))
+    );
+    for _ in 0..10_000 {
+        let duo = random::<u64>();
+        assert_eq!(
+            D.div_rem_of(duo),
+            (duo / 1_000_000_007, duo % 1_000_000_007)
+        );
+        let duo = random::<u128>();
+        let div = 10u128.pow(30) + 7;
+        assert_eq!(E.div_rem_of(duo), (duo / div, duo % div));
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn div_rem_slice_test() {
+    for len in [0, 1, 7, 100] {
+        let div = (random::<u64>() >> (random::<u32>() & 63)).max(1);
+        let duos: Vec<u64> = (0..len).map(|_| random()).collect();
+        let mut quos = vec![0; len];
+        let mut rems = vec![0; len];
+        u64_div_rem_slice(&duos, div, &mut quos, &mut rems);
+        for i in 0..len {
+            assert_eq!((quos[i], rems[i]), (duos[i] / div, duos[i] % div));
+        }
+
+        let div = (random::<u128>() >> (random::<u32>() & 127)).max(1);
+        let duos: Vec<u128> = (0..len).map(|_| random()).collect();
+        let mut quos = vec![0; len];
+        let mut rems = vec![0; len];
+        u128_div_rem_slice(&duos, div, &mut quos, &mut rems);
+        for i in 0..len {
+            assert_eq!((quos[i], rems[i]), (duos[i] / div, duos[i] % div));
+        }
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+#[should_panic]
+fn div_rem_slice_length_mismatch() {
+    u64_div_rem_slice(&[1, 2, 3], 2, &mut [0; 3], &mut [0; 2]);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn exact_div_test() {
+    macro_rules! check {
+        ($uX:ident, $exact_div:ident) => {
+            for div in [1, 2, 3, 6, 7, 64, $uX::MAX] {
+                for quo in [0, 1, $uX::MAX / div / 2, $uX::MAX / div] {
+                    assert_eq!($exact_div(quo * div, div), quo);
+                }
+            }
+            for _ in 0..10_000 {
+                let div = (random::<$uX>() >> (random::<u32>() % $uX::BITS)).max(1);
+                let quo = random::<$uX>() % ($uX::MAX / div);
+                assert_eq!($exact_div(quo * div, div), quo);
+            }
+        };
+    }
+    check!(u32, u32_exact_div);
+    check!(u64, u64_exact_div);
+    check!(u128, u128_exact_div);
+
+    const QUO: u64 = u64_exact_div(1_000_000_007 * 12, 12);
+    assert_eq!(QUO, 1_000_000_007);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn is_multiple_of_test() {
+    macro_rules! check {
+        ($uX:ident, $is_multiple_of:ident) => {
+            for duo in 0..200 {
+                for div in 0..50 {
+                    assert_eq!($is_multiple_of(duo, div), duo.is_multiple_of(div));
+                }
+            }
+            for div in [1, 2, 3, 6, 7, 64, $uX::MAX - 1, $uX::MAX] {
+                for duo in [0, 1, div - 1, div, $uX::MAX - 1, $uX::MAX] {
+                    assert_eq!($is_multiple_of(duo, div), duo.is_multiple_of(div));
+                }
+                let quo = random::<$uX>() % ($uX::MAX / div);
+                assert!($is_multiple_of(quo * div, div));
+            }
+            for _ in 0..10_000 {
+                let div = random::<$uX>() >> (random::<u32>() % $uX::BITS);
+                let duo = random::<$uX>() >> (random::<u32>() % $uX::BITS);
+                assert_eq!($is_multiple_of(duo, div), duo.is_multiple_of(div));
+                if div != 0 {
+                    let quo = random::<$uX>() % ($uX::MAX / div);
+                    assert!($is_multiple_of(quo * div, div));
+                    assert_eq!($is_multiple_of(quo * div + 1, div), div == 1);
+                }
+            }
+        };
+    }
+    check!(u32, u32_is_multiple_of);
+    check!(u64, u64_is_multiple_of);
+    check!(u128, u128_is_multiple_of);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn exact_div_inexact() {
+    u64_exact_div(7, 2);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn barrett_test() {
+    use specialized_div_rem::{
+        barrett::{Barrett128, Barrett64},
+        u128_mul_mod, u256, u256_div_rem_trifecta,
+    };
+
+    let mut mods = vec![1, 2, 3, 7, 1 << 63, (1 << 63) + 1, u64::MAX - 1, u64::MAX];
+    for _ in 0..1000 {
+        mods.push((random::<u64>() >> (random::<u32>() & 63)).max(1));
+    }
+    for m in mods {
+        let bar = Barrett64::new(m);
+        assert_eq!(bar.modulus(), m);
+        let mut xs = vec![0, 1, m as u128, (m as u128) * (m as u128) - 1, u128::MAX];
+        for _ in 0..100 {
+            xs.push(random::<u128>() >> (random::<u32>() & 127));
+        }
+        for x in xs {
+            assert_eq!(bar.reduce_u128(x), (x % (m as u128)) as u64);
+        }
+        let (a, b) = (random::<u64>(), random::<u64>());
+        assert_eq!(
+            bar.mul_mod(a, b),
+            ((a as u128) * (b as u128) % (m as u128)) as u64
+        );
+    }
+
+    let mut mods = vec![
+        1,
+        2,
+        3,
+        7,
+        1 << 127,
+        (1 << 127) + 1,
+        u128::MAX - 1,
+        u128::MAX,
+    ];
+    for _ in 0..1000 {
+        mods.push((random::<u128>() >> (random::<u32>() & 127)).max(1));
+    }
+    for m in mods {
+        let bar = Barrett128::new(m);
+        assert_eq!(bar.modulus(), m);
+        for _ in 0..100 {
+            let x = u256::from_halves(
+                random::<u128>() >> (random::<u32>() & 127),
+                random::<u128>() >> (random::<u32>() & 127),
+            );
+            let expected = u256_div_rem_trifecta(x, u256::from(m)).1;
+            assert_eq!(u256::from(bar.reduce_u256(x)), expected);
+            let (a, b) = (x.lo, x.hi);
+            assert_eq!(bar.mul_mod(a, b), u128_mul_mod(a, b, m));
+        }
+    }
+
+    const BAR: Barrett64 = Barrett64::new(1_000_000_007);
+    assert_eq!(BAR.mul_mod(1_000_000_006, 1_000_000_006), 1);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn montgomery_test() {
+    use specialized_div_rem::{
+        montgomery::{Montgomery128, Montgomery64},
+        u128_mul_mod, u256, u256_div_rem_trifecta,
+    };
+
+    let mut mods = vec![1, 3, 7, (1 << 63) + 1, u64::MAX - 2, u64::MAX];
+    for _ in 0..1000 {
+        mods.push((random::<u64>() >> (random::<u32>() & 63)) | 1);
+    }
+    for m in mods {
+        let mont = Montgomery64::new(m);
+        assert_eq!(mont.modulus(), m);
+        for _ in 0..100 {
+            let (a, b) = (random::<u64>(), random::<u64>());
+            let (a_m, b_m) = (mont.to_mont(a), mont.to_mont(b));
+            assert!((a_m < m) && (b_m < m));
+            assert_eq!(mont.from_mont(a_m), a % m);
+            let expected = ((a as u128) * (b as u128) % (m as u128)) as u64;
+            assert_eq!(mont.from_mont(mont.mont_mul(a_m, b_m)), expected);
+            // `from_mont` is the same as reducing a value without an upper half
+            let x = (random::<u128>() % (m as u128)) << 64 | (a as u128);
+            let r = mont.mont_reduce(x);
+            assert_eq!(mont.to_mont(r), (x % (m as u128)) as u64);
+        }
+    }
+
+    let mut mods = vec![1, 3, 7, (1 << 127) + 1, u128::MAX - 2, u128::MAX];
+    for _ in 0..1000 {
+        mods.push((random::<u128>() >> (random::<u32>() & 127)) | 1);
+    }
+    for m in mods {
+        let mont = Montgomery128::new(m);
+        for _ in 0..20 {
+            let (a, b) = (random::<u128>(), random::<u128>());
+            let (a_m, b_m) = (mont.to_mont(a), mont.to_mont(b));
+            assert_eq!(mont.from_mont(a_m), a % m);
+            assert_eq!(
+                mont.from_mont(mont.mont_mul(a_m, b_m)),
+                u128_mul_mod(a, b, m)
+            );
+            let x = u256::from_halves(a, b % m);
+            let r = mont.mont_reduce(x);
+            let expected = u256_div_rem_trifecta(x, u256::from(m)).1;
+            assert_eq!(u256::from(mont.to_mont(r)), expected);
+        }
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+#[should_panic]
+fn montgomery_even_modulus() {
+    specialized_div_rem::montgomery::Montgomery64::new(10);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn uint_test() {
+    // full product of little endian limbs, truncated to the length of `lhs`
+    fn mul<const LIMBS: usize>(lhs: Uint<LIMBS>, rhs: Uint<LIMBS>) -> Uint<LIMBS> {
+        let mut res = Uint::<LIMBS>::ZERO;
+        for i in 0..LIMBS {
+            let mut carry = 0u128;
+            for j in 0..(LIMBS - i) {
+                let tmp = (lhs.limbs[i] as u128) * (rhs.limbs[j] as u128)
+                    + (res.limbs[i + j] as u128)
+                    + carry;
+                res.limbs[i + j] = tmp as u64;
+                carry = tmp >> 64;
+            }
+        }
+        res
+    }
+
+    for _ in 0..10_000 {
+        // against `u128`
+        let duo = random::<u128>() >> (random::<u32>() & 127);
+        let div = random::<u128>() >> (random::<u32>() & 127);
+        if div != 0 {
+            let (quo, rem) = Uint::<2>::from_limbs([duo as u64, (duo >> 64) as u64])
+                .div_rem(Uint::from_limbs([div as u64, (div >> 64) as u64]));
+            assert_eq!(quo.limbs, [(duo / div) as u64, ((duo / div) >> 64) as u64]);
+            assert_eq!(rem.limbs, [(duo % div) as u64, ((duo % div) >> 64) as u64]);
+        }
+
+        // 384 bits, checking `duo == quo * div + rem` with `quo * div` not overflowing
+        let mut div = Uint::<6>::ZERO;
+        let mut quo = Uint::<6>::ZERO;
+        let div_len = 1 + (random::<usize>() % 6);
+        for i in 0..div_len {
+            div.limbs[i] = random::<u64>() >> (random::<u32>() & 63);
+        }
+        for i in 0..(6 - div_len) {
+            quo.limbs[i] = random::<u64>() >> (random::<u32>() & 63);
+        }
+        if div_len == 6 {
+            // a full length divisor leaves room for a single limb quotient
+            div.limbs[5] >>= 32 + (random::<u32>() & 31);
+            quo.limbs[0] = random::<u32>() as u64;
+        }
+        if div.is_zero() {
+            continue;
+        }
+        let mut rem = div;
+        rem.limbs[div_len - 1] >>= 1 + (random::<u32>() % 63);
+        let mut duo = mul(quo, div);
+        let mut carry = false;
+        for i in 0..6 {
+            let (tmp, o0) = duo.limbs[i].overflowing_add(rem.limbs[i]);
+            let (tmp, o1) = tmp.overflowing_add(carry as u64);
+            duo.limbs[i] = tmp;
+            carry = o0 || o1;
+        }
+        if carry || (rem >= div) {
+            continue;
+        }
+        assert_eq!(duo.div_rem(div), (quo, rem));
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn int_256_test() {
+    use specialized_div_rem::{
+        i256, i256_div_rem_asymmetric, i256_div_rem_delegate, i256_div_rem_trifecta, u256,
+        u256_div_rem_asymmetric, u256_div_rem_delegate, u256_div_rem_trifecta,
+    };
+
+    type UnsignedFn = fn(u256, u256) -> (u256, u256);
+    type SignedFn = fn(i256, i256) -> (i256, i256);
+    let unsigned: [(&str, UnsignedFn); 3] = [
+        ("delegate", u256_div_rem_delegate),
+        ("trifecta", u256_div_rem_trifecta),
+        ("asymmetric", u256_div_rem_asymmetric),
+    ];
+    let signed: [(&str, SignedFn); 3] = [
+        ("delegate", i256_div_rem_delegate),
+        ("trifecta", i256_div_rem_trifecta),
+        ("asymmetric", i256_div_rem_asymmetric),
+    ];
+
+    // against the `bignum` limb algorithms
+    fn reference(duo: u256, div: u256) -> (u256, u256) {
+        let (quo, rem) = Uint::<4>::from(duo).div_rem(Uint::<4>::from(div));
+        (quo.into(), rem.into())
+    }
+    fn signed_reference(duo: i256, div: i256) -> (i256, i256) {
+        let (quo, rem) = reference(duo.unsigned_abs(), div.unsigned_abs());
+        let quo = i256::from_halves(quo.lo, quo.hi as i128);
+        let rem = i256::from_halves(rem.lo, rem.hi as i128);
+        let quo = if duo.is_negative() != div.is_negative() {
+            quo.wrapping_neg()
+        } else {
+            quo
+        };
+        let rem = if duo.is_negative() {
+            rem.wrapping_neg()
+        } else {
+            rem
+        };
+        (quo, rem)
+    }
+
+    // the edge cases of the half sized parts
+    let edges = [
+        0,
+        1,
+        2,
+        u64::MAX as u128,
+        1 << 64,
+        (1 << 64) + 1,
+        i128::MAX as u128,
+        1 << 127,
+        u128::MAX - 1,
+        u128::MAX,
+    ];
+    for duo_hi in edges {
+        for duo_lo in edges {
+            for div_hi in edges {
+                for div_lo in edges {
+                    let duo = u256::from_halves(duo_lo, duo_hi);
+                    let div = u256::from_halves(div_lo, div_hi);
+                    if div == u256::MIN {
+                        continue;
+                    }
+                    for (name, f) in unsigned {
+                        assert_eq!(f(duo, div), reference(duo, div), "{name} {duo:?} {div:?}");
+                    }
+                    let duo = i256::from_halves(duo_lo, duo_hi as i128);
+                    let div = i256::from_halves(div_lo, div_hi as i128);
+                    for (name, f) in signed {
+                        let expected = signed_reference(duo, div);
+                        assert_eq!(f(duo, div), expected, "{name} {duo:?} {div:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    for _ in 0..10_000 {
+        let duo = random::<u256>() >> (random::<u32>() & 255);
+        let div = random::<u256>() >> (random::<u32>() & 255);
+        if div == u256::MIN {
+            continue;
+        }
+        let expected = reference(duo, div);
+        for (name, f) in unsigned {
+            assert_eq!(f(duo, div), expected, "{name} {duo:?} {div:?}");
+        }
+        let duo = i256::from_halves(duo.lo, duo.hi as i128);
+        let div = i256::from_halves(div.lo, div.hi as i128);
+        let expected = signed_reference(duo, div);
+        for (name, f) in signed {
+            assert_eq!(f(duo, div), expected, "{name} {duo:?} {div:?}");
+        }
+    }
+
+    // wraps around like the primitives
+    let neg_one = i256::from(-1);
+    for (_, f) in signed {
+        assert_eq!(f(i256::MIN, neg_one), (i256::MIN, i256::from(0)));
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn int_512_test() {
+    use specialized_div_rem::{
+        i512, i512_div_rem_delegate, i512_div_rem_trifecta, u256, u512, u512_div_rem_delegate,
+        u512_div_rem_trifecta,
+    };
+
+    type UnsignedFn = fn(u512, u512) -> (u512, u512);
+    type SignedFn = fn(i512, i512) -> (i512, i512);
+    let unsigned: [(&str, UnsignedFn); 2] = [
+        ("delegate", u512_div_rem_delegate),
+        ("trifecta", u512_div_rem_trifecta),
+    ];
+    let signed: [(&str, SignedFn); 2] = [
+        ("delegate", i512_div_rem_delegate),
+        ("trifecta", i512_div_rem_trifecta),
+    ];
+
+    // against the `bignum` limb algorithms
+    fn reference(duo: u512, div: u512) -> (u512, u512) {
+        let (quo, rem) = Uint::<8>::from(duo).div_rem(Uint::<8>::from(div));
+        (quo.into(), rem.into())
+    }
+    fn signed_reference(duo: i512, div: i512) -> (i512, i512) {
+        let (quo, rem) = reference(duo.unsigned_abs(), div.unsigned_abs());
+        let quo = if duo.is_negative() != div.is_negative() {
+            quo.wrapping_neg()
+        } else {
+            quo
+        };
+        let rem = if duo.is_negative() {
+            rem.wrapping_neg()
+        } else {
+            rem
+        };
+        (quo.cast_signed(), rem.cast_signed())
+    }
+
+    // the edge cases of the quarter sized parts
+    let edges = [
+        0,
+        1,
+        u64::MAX as u128,
+        1 << 64,
+        i128::MAX as u128,
+        1 << 127,
+        u128::MAX,
+    ];
+    let mut halves = Vec::new();
+    for hi in edges {
+        for lo in edges {
+            halves.push(u256::from_halves(lo, hi));
+        }
+    }
+    for duo_hi in &halves {
+        for duo_lo in &halves {
+            for div_hi in edges {
+                for div_lo in &halves {
+                    let duo = u512::from_halves(*duo_lo, *duo_hi);
+                    let div = u512::from_halves(*div_lo, u256::from_halves(div_hi, div_hi));
+                    if div == u512::MIN {
+                        continue;
+                    }
+                    let expected = reference(duo, div);
+                    for (name, f) in unsigned {
+                        assert_eq!(f(duo, div), expected, "{name} {duo:?} {div:?}");
+                    }
+                    let (duo, div) = (duo.cast_signed(), div.cast_signed());
+                    let expected = signed_reference(duo, div);
+                    for (name, f) in signed {
+                        assert_eq!(f(duo, div), expected, "{name} {duo:?} {div:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    for _ in 0..10_000 {
+        let duo = random::<u512>() >> (random::<u32>() & 511);
+        let div = random::<u512>() >> (random::<u32>() & 511);
+        if div == u512::MIN {
+            continue;
+        }
+        let expected = reference(duo, div);
+        for (name, f) in unsigned {
+            assert_eq!(f(duo, div), expected, "{name} {duo:?} {div:?}");
+        }
+        let (duo, div) = (duo.cast_signed(), div.cast_signed());
+        let expected = signed_reference(duo, div);
+        for (name, f) in signed {
+            assert_eq!(f(duo, div), expected, "{name} {duo:?} {div:?}");
+        }
+    }
+
+    // wraps around like the primitives
+    let neg_one = i512::from(specialized_div_rem::i256::from(-1));
+    for (_, f) in signed {
+        assert_eq!(f(i512::MIN, neg_one), (i512::MIN, i512::default()));
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+#[should_panic(expected = "attempt to divide by zero")]
+fn int_256_div_by_zero() {
+    specialized_div_rem::u256_div_rem_trifecta(specialized_div_rem::u256::MAX, 0u128.into());
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn uint_bytes_test() {
+    use specialized_div_rem::{U256, U512};
+
+    for _ in 0..10_000 {
+        // against `u128`
+        let x = random::<u128>() >> (random::<u32>() & 127);
+        let uint = Uint::<2>::from_limbs([x as u64, (x >> 64) as u64]);
+        assert_eq!(Uint::<2>::from_le_bytes(x.to_le_bytes()), uint);
+        assert_eq!(Uint::<2>::from_be_bytes(x.to_be_bytes()), uint);
+        assert_eq!(uint.to_le_bytes(), x.to_le_bytes());
+        assert_eq!(uint.to_be_bytes(), x.to_be_bytes());
+
+        // round trips
+        let limbs: [u64; 8] = core::array::from_fn(|_| random());
+        let x = U512::from_limbs(limbs);
+        assert_eq!(x.into_limbs(), limbs);
+        assert_eq!(U512::from_le_bytes::<64>(x.to_le_bytes()), x);
+        assert_eq!(U512::from_be_bytes::<64>(x.to_be_bytes()), x);
+    }
+    // the most significant byte is first in big endian order, and is in the last limb
+    let mut bytes = [0u8; 32];
+    bytes[0] = 0xab;
+    bytes[31] = 0x01;
+    assert_eq!(
+        U256::from_be_bytes(bytes).into_limbs(),
+        [1, 0, 0, 0xab << 56]
+    );
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn streaming_test() {
+    use specialized_div_rem::bignum::{div_rem_limb_streaming, div_rem_streaming};
+
+    for _ in 0..10_000 {
+        let duo = Uint::<6>::from_limbs(core::array::from_fn(|_| {
+            random::<u64>() >> (random::<u32>() & 63)
+        }));
+        let mut div = Uint::<6>::ZERO;
+        let div_len = 1 + (random::<usize>() % 6);
+        for i in 0..div_len {
+            div.limbs[i] = random::<u64>() >> (random::<u32>() & 63);
+        }
+        if div.is_zero() {
+            continue;
+        }
+        let (quo, rem) = duo.div_rem(div);
+
+        // the quotient limbs are emitted starting from the most significant limb
+        let mut streamed = Vec::new();
+        let mut streamed_rem = Uint::<6>::ZERO;
+        div_rem_streaming(
+            &duo.limbs,
+            &div.limbs[..div_len],
+            &mut streamed_rem.limbs,
+            |x| streamed.push(x),
+        );
+        streamed.reverse();
+        assert_eq!(streamed, quo.limbs);
+        assert_eq!(streamed_rem, rem);
+
+        let div = div.limbs[0];
+        if div != 0 {
+            let (quo, rem) = duo.div_rem(Uint::from(div));
+            let mut streamed = Vec::new();
+            let streamed_rem = div_rem_limb_streaming(&duo.limbs, div, |x| streamed.push(x));
+            streamed.reverse();
+            assert_eq!(streamed, quo.limbs);
+            assert_eq!(streamed_rem, rem.limbs[0]);
+        }
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn limb_utils_test() {
+    use core::cmp::Ordering;
+    use specialized_div_rem::bignum::{bit_len, cmp, shl, shl1, shr, sub_assign, sub_assign_if_ge};
+
+    // two limbs against `u128`
+    let limbs = |x: u128| [x as u64, (x >> 64) as u64];
+    for _ in 0..100_000 {
+        let x = random::<u128>() >> (random::<u32>() & 127);
+        let y = random::<u128>() >> (random::<u32>() & 127);
+        let shift = random::<u32>() & 63;
+
+        assert_eq!(cmp(&limbs(x), &limbs(y)), x.cmp(&y));
+        // extra zero limbs do not change the ordering
+        assert_eq!(cmp(&limbs(x), &[y as u64, (y >> 64) as u64, 0]), x.cmp(&y));
+        assert_eq!(
+            cmp(&[x as u64, (x >> 64) as u64, 1], &limbs(y)),
+            Ordering::Greater
+        );
+        assert_eq!(bit_len(&limbs(x)), (128 - x.leading_zeros()) as usize);
+
+        let mut tmp = limbs(x);
+        let carry = shl(&mut tmp, shift);
+        assert_eq!(tmp, limbs(x << shift));
+        assert_eq!(
+            carry,
+            if shift == 0 {
+                0
+            } else {
+                (x >> (128 - shift)) as u64
+            }
+        );
+        let mut tmp = limbs(x);
+        assert_eq!(shl1(&mut tmp, 1), (x >> 127) as u64);
+        assert_eq!(tmp, limbs((x << 1) | 1));
+
+        let mut tmp = limbs(x);
+        let carry = shr(&mut tmp, shift);
+        assert_eq!(tmp, limbs(x >> shift));
+        assert_eq!(
+            carry,
+            if shift == 0 {
+                0
+            } else {
+                (x as u64) << (64 - shift)
+            }
+        );
+
+        let mut tmp = limbs(x);
+        assert_eq!(sub_assign(&mut tmp, &limbs(y)), x < y);
+        assert_eq!(tmp, limbs(x.wrapping_sub(y)));
+        let mut tmp = limbs(x);
+        assert_eq!(sub_assign_if_ge(&mut tmp, &limbs(y)), x >= y);
+        assert_eq!(tmp, limbs(if x >= y { x - y } else { x }));
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn div_rem_limbs_test() {
+    use specialized_div_rem::bignum::div_rem_limbs;
+
+    // limbs that cause the most corrections of the quotient estimates
+    const EDGES: [u64; 6] = [0, 1, 1 << 63, (1 << 63) - 1, u64::MAX - 1, u64::MAX];
+    for iter in 0..100_000 {
+        let edges = (iter & 1) == 0;
+        let limb = || {
+            if edges {
+                EDGES[random::<usize>() % EDGES.len()]
+            } else {
+                random::<u64>() >> (random::<u32>() & 63)
+            }
+        };
+        // the most significant limb of `duo` is zero so that any quotient fits
+        let duo_len = 1 + (random::<usize>() % 7);
+        let div_len = 1 + (random::<usize>() % 7);
+        let mut duo = [0u64; 8];
+        let mut div = [0u64; 8];
+        duo.iter_mut().take(duo_len).for_each(|x| *x = limb());
+        div.iter_mut().take(div_len).for_each(|x| *x = limb());
+        let div_sig = div.iter().rposition(|x| *x != 0).map_or(0, |i| i + 1);
+        if div_sig == 0 {
+            continue;
+        }
+        let (quo, rem) = Uint::from_limbs(duo).div_rem(Uint::from_limbs(div));
+        let len = (duo_len + 1).max(div_sig);
+        let mut res = duo;
+        assert_eq!(div_rem_limbs(&mut res[..len], &div[..div_len]), div_sig);
+        assert_eq!(res[..div_sig], rem.limbs[..div_sig]);
+        assert_eq!(res[div_sig..len], quo.limbs[..(len - div_sig)]);
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn div_rem_limbs_by_u64_test() {
+    use specialized_div_rem::bignum::div_rem_limbs_by_u64;
+
+    for _ in 0..100_000 {
+        let mut limbs = [0u64; 8];
+        limbs
+            .iter_mut()
+            .for_each(|x| *x = random::<u64>() >> (random::<u32>() & 63));
+        let div = (random::<u64>() >> (random::<u32>() & 63)).max(1);
+        let (quo, rem) =
+            Uint::from_limbs(limbs).div_rem(Uint::from_limbs([div, 0, 0, 0, 0, 0, 0, 0]));
+        assert_eq!(div_rem_limbs_by_u64(&mut limbs, div), rem.limbs[0]);
+        assert_eq!(limbs, quo.limbs);
+    }
+    // the largest power of 10 in a limb, as used for printing
+    let mut limbs = [u64::MAX, u64::MAX];
+    let rem = div_rem_limbs_by_u64(&mut limbs, 10u64.pow(19));
+    let expected = u128::MAX / 10u128.pow(19);
+    assert_eq!(limbs, [expected as u64, (expected >> 64) as u64]);
+    assert_eq!(rem as u128, u128::MAX % 10u128.pow(19));
+    assert_eq!(div_rem_limbs_by_u64(&mut [], 3), 0);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+#[should_panic(expected = "quotient does not fit")]
+fn div_rem_limbs_overflow() {
+    specialized_div_rem::bignum::div_rem_limbs(&mut [0, 5], &[5]);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+#[should_panic]
+fn uint_div_by_zero() {
+    Uint::<3>::from(1).div_rem(Uint::ZERO);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn magic_table_test() {
+    static SMALL: U32MagicTable<4096> = U32MagicTable::new(1);
+    const LARGE: U64MagicTable<64> = U64MagicTable::new(u64::MAX - 63);
+    assert_eq!(SMALL.get(0), None);
+    assert_eq!(SMALL.get(4097), None);
+    assert_eq!(LARGE.get(u64::MAX - 64), None);
+    for div in 1..=4096 {
+        for duo in [0, 1, div - 1, div, div + 1, u32::MAX - 1, u32::MAX] {
+            assert_eq!(SMALL.div_by_table(duo, div), duo / div);
+        }
+        let duo = random::<u32>() >> (random::<u32>() % 32);
+        assert_eq!(SMALL.div_by_table(duo, div), duo / div);
+    }
+    for div in (u64::MAX - 63)..=u64::MAX {
+        for duo in [0, 1, div - 1, div, u64::MAX, random::<u64>()] {
+            assert_eq!(LARGE.div_by_table(duo, div), duo / div);
+        }
+    }
+    // the divisors `2^(n - 1) + 1` have the largest multipliers
+    let table = U64MagicTable::<3>::new((1 << 63) - 1);
+    for _ in 0..10_000 {
+        let duo = random::<u64>();
+        for div in ((1 << 63) - 1)..=((1 << 63) + 1) {
+            assert_eq!(table.div_by_table(duo, div), duo / div);
+        }
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+#[should_panic(expected = "attempt to add with overflow")]
+fn magic_table_overflow() {
+    // a runtime call, which has to panic in release builds too
+    U64MagicTable::<2>::new(std::hint::black_box(u64::MAX));
+}
+
+#[cfg(feature = "portable-simd")]
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn simd_invariant_test() {
+    use specialized_div_rem::u32_div_invariant_simd;
+    for len in 0..40 {
+        let div = random::<u32>() >> (random::<u32>() & 31);
+        if div == 0 {
+            continue;
+        }
+        let duo: Vec<u32> = (0..len)
+            .map(|_| random::<u32>() >> (random::<u32>() & 31))
+            .collect();
+        let mut quo = duo.clone();
+        u32_div_invariant_simd(&mut quo, div);
+        for (duo, quo) in duo.iter().zip(quo.iter()) {
+            assert_eq!(*quo, duo / div);
+        }
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn digits_test() {
+    let mut cases = vec![
+        0,
+        1,
+        9,
+        10,
+        u64::MAX as u128,
+        10u128.pow(19),
+        10u128.pow(38),
+        u128::MAX,
+    ];
+    for _ in 0..10_000 {
+        cases.push(random::<u128>() >> (random::<u32>() & 127));
+    }
+    for x in cases {
+        let expected: Vec<u8> = x.to_string().bytes().map(|b| b - b'0').collect();
+        assert_eq!(U128Digits::new(x).len(), expected.len());
+        assert_eq!(U128Digits::new(x).collect::<Vec<u8>>(), expected);
+        let mut rev = expected.clone();
+        rev.reverse();
+        assert_eq!(U128Digits::new(x).rev().collect::<Vec<u8>>(), rev);
+        // meeting in the middle
+        let mut digits = U128Digits::new(x);
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        while let Some(d) = digits.next() {
+            front.push(d);
+            match digits.next_back() {
+                Some(d) => back.push(d),
+                None => break,
+            }
+        }
+        back.reverse();
+        front.extend(back);
+        assert_eq!(front, expected);
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn factor_test() {
+    use specialized_div_rem::{
+        u128_factor_small_primes, u128_smallest_factor, u64_factor_small_primes,
+        u64_smallest_factor,
+    };
+
+    fn naive_smallest_factor(x: u64) -> u64 {
+        if x < 2 {
+            return x;
+        }
+        let mut d = 2;
+        while d * d <= x {
+            if x.is_multiple_of(d) {
+                return d;
+            }
+            d += 1;
+        }
+        x
+    }
+
+    for x in 0..10_000u64 {
+        assert_eq!(u64_smallest_factor(x), naive_smallest_factor(x));
+        assert_eq!(
+            u128_smallest_factor(x as u128),
+            naive_smallest_factor(x) as u128
+        );
+    }
+    for _ in 0..1000 {
+        let x = random::<u64>() >> (24 + (random::<u32>() % 40));
+        assert_eq!(u64_smallest_factor(x), naive_smallest_factor(x));
+    }
+    // a product of two primes just below `2^32`
+    let p0 = 4294967291u64;
+    let p1 = 4294967279u64;
+    assert_eq!(u64_smallest_factor(p0 * p1), p1);
+    assert_eq!(u64_smallest_factor(p0), p0);
+
+    let mut factors = [(0, 0); 26];
+    // 2^10 * 3 * 61^2 * 67 * 4294967291
+    let x = 1024 * 3 * 61 * 61 * 67 * (p0 as u128);
+    assert_eq!(
+        u128_factor_small_primes(x, 100, &mut factors),
+        (4, p0 as u128)
+    );
+    assert_eq!(&factors[..4], &[(2, 10), (3, 1), (61, 2), (67, 1)]);
+    assert_eq!(
+        u128_factor_small_primes(x, 60, &mut factors),
+        (2, 61 * 61 * 67 * (p0 as u128))
+    );
+    assert_eq!(
+        u128_factor_small_primes(x, 2, &mut factors[..1]),
+        (1, x >> 10)
+    );
+    assert_eq!(u128_factor_small_primes(0, 100, &mut factors), (0, 0));
+    // the remaining prime cofactor is found once the search passes its square root
+    assert_eq!(u128_factor_small_primes(x, u128::MAX, &mut factors), (5, 1));
+    assert_eq!(factors[4], (p0 as u128, 1));
+    let mut factors = [(0, 0); 15];
+    for _ in 0..1000 {
+        let x = random::<u64>() >> (random::<u32>() % 64);
+        let (len, cofactor) = u64_factor_small_primes(x, 1000, &mut factors);
+        let mut product = cofactor;
+        for &(p, multiplicity) in &factors[..len] {
+            assert!(p <= 1000);
+            assert_eq!(naive_smallest_factor(p), p);
+            product *= p.pow(multiplicity);
+        }
+        assert_eq!(product, x);
+        if x != 0 {
+            for d in 2..=1000 {
+                assert!(!cofactor.is_multiple_of(d));
+            }
+        }
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn miller_rabin_test() {
+    use specialized_div_rem::{
+        u128_is_prime, u128_is_probable_prime, u128_mul_mod, u128_pow_mod, u64_is_prime,
+        u64_mul_mod, u64_pow_mod, u64_smallest_factor,
+    };
+
+    for _ in 0..10_000 {
+        let a = random::<u64>();
+        let b = random::<u64>();
+        let m = random::<u64>() >> (random::<u32>() & 63);
+        if m == 0 {
+            continue;
+        }
+        let expected = ((a as u128) * (b as u128)) % (m as u128);
+        assert_eq!(u64_mul_mod(a, b, m) as u128, expected);
+        assert_eq!(u128_mul_mod(a as u128, b as u128, m as u128), expected);
+        assert_eq!(
+            u128_mul_mod(u128::MAX, a as u128, m as u128),
+            (u128::MAX % (m as u128)) * (a as u128) % (m as u128)
+        );
+        let exp = random::<u64>() & 0xff;
+        let mut expected = 1 % (m as u128);
+        for _ in 0..exp {
+            expected = (expected * (a as u128)) % (m as u128);
+        }
+        assert_eq!(u64_pow_mod(a, exp, m) as u128, expected);
+        assert_eq!(u128_pow_mod(a as u128, exp as u128, m as u128), expected);
+    }
+
+    for x in 0..100_000u64 {
+        let expected = x >= 2 && u64_smallest_factor(x) == x;
+        assert_eq!(u64_is_prime(x), expected);
+        assert_eq!(u128_is_prime(x as u128), expected);
+    }
+    // Carmichael numbers and strong pseudoprimes to small bases
+    for x in [
+        561u64,
+        41041,
+        825265,
+        3215031751,
+        2152302898747,
+        3474749660383,
+        341550071728321,
+    ] {
+        assert!(!u64_is_prime(x));
+    }
+    // the largest prime below `2^64`, and the Mersenne primes `2^61 - 1`, `2^89 - 1`, and
+    // `2^127 - 1`
+    assert!(u64_is_prime(u64::MAX - 58));
+    assert!(!u64_is_prime(u64::MAX));
+    assert!(u64_is_prime((1 << 61) - 1));
+    assert!(u128_is_prime((1 << 89) - 1));
+    assert!(u128_is_prime((1 << 127) - 1));
+    assert!(!u128_is_prime((1 << 127) + 1));
+    // `2^67 - 1 = 193707721 * 761838257287`
+    assert!(!u128_is_prime((1 << 67) - 1));
+    // this is a strong pseudoprime to all of the fixed witnesses, which another witness catches
+    let psp = 318665857834031151167461u128;
+    assert!(u128_is_prime(psp));
+    assert!(!u128_is_probable_prime(psp, &[41]));
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn mod_sqrt_test() {
+    use specialized_div_rem::{
+        u128_mod_sqrt, u128_mul_mod, u128_pow_mod, u64_mod_sqrt, u64_mul_mod,
+    };
+
+    assert_eq!(u64_mod_sqrt(0, 7), Some(0));
+    assert_eq!(u64_mod_sqrt(3, 2), Some(1));
+    assert_eq!(u64_mod_sqrt(2, 7), Some(3));
+    assert_eq!(u64_mod_sqrt(3, 7), None);
+    // `p - 1` has a large power of two for the primes `== 1 (mod 4)`, which need the full
+    // Tonelli-Shanks loop
+    for p in [
+        3u64,
+        5,
+        13,
+        17,
+        97,
+        65537,
+        998244353,
+        (1 << 61) - 1,
+        u64::MAX - 58,
+    ] {
+        for _ in 0..1000 {
+            let x = random::<u64>() % p;
+            let a = u64_mul_mod(x, x, p);
+            let r = u64_mod_sqrt(a, p).unwrap();
+            assert_eq!(r, x.min(p - x));
+            assert_eq!(u128_mod_sqrt(a as u128, p as u128), Some(r as u128));
+            let a = random::<u64>();
+            match u64_mod_sqrt(a, p) {
+                Some(r) => assert_eq!(u64_mul_mod(r, r, p), a % p),
+                None => assert_eq!(
+                    u128_pow_mod(a as u128, (p as u128 - 1) / 2, p as u128),
+                    p as u128 - 1
+                ),
+            }
+        }
+    }
+    // `2^127 - 1` and `2^128 - 159`
+    for p in [(1u128 << 127) - 1, u128::MAX - 158] {
+        for _ in 0..1000 {
+            let x = random::<u128>() % p;
+            let a = u128_mul_mod(x, x, p);
+            assert_eq!(u128_mod_sqrt(a, p), Some(x.min(p - x)));
+        }
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn mod_inv_test() {
+    use specialized_div_rem::{
+        u128_batch_invert, u128_mod_inv, u128_mul_mod, u64_batch_invert, u64_gcd, u64_mod_inv,
+        u64_mul_mod, U256,
+    };
+
+    assert_eq!(u64_mod_inv(3, 7), Some(5));
+    assert_eq!(u64_mod_inv(0, 7), None);
+    assert_eq!(u64_mod_inv(4, 6), None);
+    assert_eq!(u64_mod_inv(5, 1), Some(0));
+    for _ in 0..10_000 {
+        let m = (random::<u64>() >> (random::<u32>() % 64)) | 1;
+        let a = random::<u64>();
+        match u64_mod_inv(a, m) {
+            Some(inv) => {
+                assert!(inv < m);
+                assert_eq!(u64_mul_mod(a, inv, m), 1 % m);
+            }
+            None => assert_ne!(u64_gcd(a % m, m), 1),
+        }
+        let m = (random::<u128>() >> (random::<u32>() % 128)) | 1;
+        let a = random::<u128>();
+        if let Some(inv) = u128_mod_inv(a, m) {
+            assert!(inv < m);
+            assert_eq!(u128_mul_mod(a, inv, m), 1 % m);
+        }
+    }
+
+    // more than one chunk
+    let p = u64::MAX - 58;
+    let mut values = [0u64; 150];
+    for x in values.iter_mut() {
+        *x = random::<u64>();
+    }
+    let original = values;
+    assert!(u64_batch_invert(&mut values, p));
+    for i in 0..values.len() {
+        assert_eq!(values[i], u64_mod_inv(original[i], p).unwrap());
+    }
+    // values without inverses are set to 0
+    let mut values = [3u64, 4, 5, 6, 0, 7];
+    assert!(!u64_batch_invert(&mut values, 10));
+    assert_eq!(values, [7, 0, 0, 0, 0, 3]);
+    assert!(u64_batch_invert(&mut [], 10));
+
+    let p = (1u128 << 127) - 1;
+    let mut values = [0u128; 70];
+    for x in values.iter_mut() {
+        *x = random::<u128>();
+    }
+    let original = values;
+    assert!(u128_batch_invert(&mut values, p));
+    for i in 0..values.len() {
+        assert_eq!(u128_mul_mod(values[i], original[i], p), 1);
+    }
+
+    // `2^255 - 19`
+    let p = U256::from_limbs([u64::MAX - 18, u64::MAX, u64::MAX, u64::MAX >> 1]);
+    let one = U256::from(1);
+    let mut values = [U256::ZERO; 70];
+    for x in values.iter_mut() {
+        *x = U256::from_limbs([random(), random(), random(), random()]);
+    }
+    let original = values;
+    assert!(U256::batch_invert(&mut values, p));
+    for i in 0..values.len() {
+        assert_eq!(values[i].mul_mod(original[i], p), one);
+    }
+    assert_eq!(U256::from(3).mod_inv(U256::from(7)), Some(U256::from(5)));
+    assert_eq!(U256::from(4).mod_inv(U256::from(6)), None);
+    let m = U256::from_limbs([random::<u64>() | 1, random(), random(), random()]);
+    let a = U256::from_limbs([random(), random(), random(), random()]);
+    if let Some(inv) = a.mod_inv(m) {
+        assert_eq!(a.mul_mod(inv, m), one);
+    }
+    assert_eq!(
+        U256::from(u64::MAX).mul_mod(U256::from(u64::MAX), U256::MAX),
+        U256::from_limbs([1, u64::MAX - 1, 0, 0])
+    );
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn continued_fraction_test() {
+    use specialized_div_rem::{u128_continued_fraction, u64_continued_fraction};
+
+    let mut quotients = [0; 100];
+    // 415 / 93 = [4; 2, 6, 7]
+    assert_eq!(u64_continued_fraction(415, 93, &mut quotients), 4);
+    assert_eq!(&quotients[..4], &[4, 2, 6, 7]);
+    assert_eq!(u64_continued_fraction(415, 93, &mut quotients[..2]), 2);
+    assert_eq!(&quotients[..2], &[4, 2]);
+    // the only expansions that end in a partial quotient of 0 or 1
+    assert_eq!(u64_continued_fraction(0, 5, &mut quotients), 1);
+    assert_eq!(quotients[0], 0);
+    assert_eq!(u128_continued_fraction(0, u128::MAX, &mut [7; 4]), 1);
+    assert_eq!(u64_continued_fraction(7, 7, &mut quotients), 1);
+    assert_eq!(quotients[0], 1);
+    // consecutive Fibonacci numbers have all ones, except the last which is 2
+    assert_eq!(u64_continued_fraction(89, 55, &mut quotients), 9);
+    assert_eq!(&quotients[..9], &[1, 1, 1, 1, 1, 1, 1, 1, 2]);
+
+    // evaluating the expansion back gives the reduced fraction
+    let mut quotients = [0u128; 200];
+    for i in 0..10_000 {
+        let num = if i == 0 {
+            0
+        } else {
+            random::<u128>() >> (random::<u32>() & 127)
+        };
+        let den = random::<u128>() >> (random::<u32>() & 127);
+        if den == 0 {
+            continue;
+        }
+        let len = u128_continued_fraction(num, den, &mut quotients);
+        assert!(len < quotients.len());
+        if num == 0 {
+            assert_eq!(&quotients[..len], &[0]);
+        } else if num == den {
+            assert_eq!(&quotients[..len], &[1]);
+        } else {
+            assert!(quotients[len - 1] > 1);
+        }
+        // convergents `h / k`, starting from `h_(-1) / k_(-1) = 1 / 0`
+        let (mut h, mut h_prev, mut k, mut k_prev) = (1u128, 0u128, 0u128, 1u128);
+        for &a in &quotients[..len] {
+            let tmp = (
+                a.wrapping_mul(h).wrapping_add(h_prev),
+                a.wrapping_mul(k).wrapping_add(k_prev),
+            );
+            h_prev = h;
+            k_prev = k;
+            h = tmp.0;
+            k = tmp.1;
+        }
+        let gcd = specialized_div_rem::u128_gcd(num, den);
+        assert_eq!((h, k), (num / gcd, den / gcd));
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn best_rational_approx_test() {
+    // pi to 3 / 1, 22 / 7, 333 / 106, 355 / 113
+    let pi = U64Ratio::new(3141592653589793, 1000000000000000);
+    for (max_den, expected) in [
+        (1, (3, 1)),
+        (7, (22, 7)),
+        (106, (333, 106)),
+        (113, (355, 113)),
+    ] {
+        let approx = pi.best_rational_approx(max_den);
+        assert_eq!((approx.num, approx.den), expected);
+    }
+    let approx = U128Ratio::new(u128::MAX, u128::MAX - 1).best_rational_approx(u128::MAX);
+    assert_eq!((approx.num, approx.den), (u128::MAX, u128::MAX - 1));
+
+    // brute force over all denominators
+    for _ in 0..10_000 {
+        let num = random::<u64>() % 2000;
+        let den = 1 + random::<u64>() % 1000;
+        let max_den = 1 + random::<u64>() % 60;
+        let approx = U64Ratio::new(num, den).best_rational_approx(max_den);
+        assert!(approx.den <= max_den);
+        assert_eq!(u64_gcd(approx.num, approx.den), 1);
+        // `|p / q - num / den|` scaled by `den`
+        let dist = |p: u64, q: u64| U64Ratio::new((p * den).abs_diff(q * num), q);
+        let mut best = (0, 1);
+        for q in 1..=max_den {
+            let p = (q * num) / den;
+            for p in [p, p + 1] {
+                if dist(p, q) < dist(best.0, best.1) {
+                    best = (p, q);
+                }
+            }
+        }
+        assert_eq!((approx.num, approx.den), best);
+    }
+}
+
+// The `_nonzero` functions go through the same fuzzers as the other algorithms, since their
+// division by zero path is `unreachable_unchecked`. `test!` only passes nonzero divisors.
+mod nonzero {
+    use core::num::{NonZeroI128, NonZeroI32, NonZeroI64, NonZeroU128, NonZeroU32, NonZeroU64};
+
+    use specialized_div_rem::{
+        i128_div_rem_nonzero, i32_div_rem_nonzero, i64_div_rem_nonzero, test, u128_div_rem_nonzero,
+        u32_div_rem_nonzero, u64_div_rem_nonzero,
+    };
+
+    macro_rules! wrap {
+        ($($name:ident, $fn:ident, $ty:ident, $nonzero:ident;)*) => {
+            $(
+                fn $name(duo: $ty, div: $ty) -> ($ty, $ty) {
+                    $fn(duo, $nonzero::new(div).unwrap())
+                }
+            )*
+        };
+    }
+
+    wrap!(
+        u32_div_rem, u32_div_rem_nonzero, u32, NonZeroU32;
+        i32_div_rem, i32_div_rem_nonzero, i32, NonZeroI32;
+        u64_div_rem, u64_div_rem_nonzero, u64, NonZeroU64;
+        i64_div_rem, i64_div_rem_nonzero, i64, NonZeroI64;
+        u128_div_rem, u128_div_rem_nonzero, u128, NonZeroU128;
+        i128_div_rem, i128_div_rem_nonzero, i128, NonZeroI128;
+    );
+
+    test!(32, u32, i32, div_rem_nonzero_32, u32_div_rem, i32_div_rem;);
+    test!(64, u64, i64, div_rem_nonzero_64, u64_div_rem, i64_div_rem;);
+    test!(128, u128, i128, div_rem_nonzero_128, u128_div_rem, i128_div_rem;);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn breakdown_test() {
+    use specialized_div_rem::{u128_breakdown_nanos, u64_breakdown_secs};
+
+    assert_eq!(u64_breakdown_secs(0), (0, 0, 0, 0));
+    assert_eq!(u64_breakdown_secs(90061), (1, 1, 1, 1));
+    assert_eq!(u128_breakdown_nanos(90_061_000_000_001), (1, 1, 1, 1, 1));
+    let mut cases = vec![u128::MAX, u64::MAX as u128, 86_399_999_999_999];
+    for _ in 0..100_000 {
+        cases.push(random::<u128>() >> (random::<u32>() & 127));
+    }
+    for nanos in cases {
+        let secs = nanos / 1_000_000_000;
+        let expected = (
+            secs / 86400,
+            ((secs % 86400) / 3600) as u8,
+            ((secs % 3600) / 60) as u8,
+            (secs % 60) as u8,
+            (nanos % 1_000_000_000) as u32,
+        );
+        assert_eq!(u128_breakdown_nanos(nanos), expected);
+        let secs = nanos as u64;
+        assert_eq!(
+            u64_breakdown_secs(secs),
+            (
+                secs / 86400,
+                ((secs % 86400) / 3600) as u8,
+                ((secs % 3600) / 60) as u8,
+                (secs % 60) as u8
+            )
+        );
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn div_saturating_dsp_test() {
+    use specialized_div_rem::DivSaturatingDsp;
+
+    assert_eq!(7u8.div_saturating_dsp(0), u8::MAX);
+    assert_eq!((-7i16).div_saturating_dsp(0), i16::MAX);
+    assert_eq!(0u128.div_saturating_dsp(0), u128::MAX);
+    assert_eq!(i32::MIN.div_saturating_dsp(-1), i32::MAX);
+    assert_eq!(i64::MIN.div_saturating_dsp(1), i64::MIN);
+    assert_eq!(i128::MIN.div_saturating_dsp(-1), i128::MAX);
+    for _ in 0..10_000 {
+        let duo = random::<u128>() >> (random::<u32>() & 127);
+        let div = random::<u128>() >> (random::<u32>() & 127);
+        let expected = duo.checked_div(div).unwrap_or(u128::MAX);
+        assert_eq!(duo.div_saturating_dsp(div), expected);
+        let (duo, div) = (duo as i64, div as i64);
+        let expected = if div == 0 {
+            i64::MAX
+        } else {
+            duo.saturating_div(div)
+        };
+        assert_eq!(duo.div_saturating_dsp(div), expected);
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn div_rem_trait_test() {
+    use core::num::{Saturating, Wrapping};
+
+    use specialized_div_rem::DivRem;
+
+    fn generic<T: DivRem<Output = (T, T)>>(duo: T, div: T) -> (T, T) {
+        duo.div_rem(div)
+    }
+
+    assert_eq!(generic(7u8, 2), (3, 1));
+    assert_eq!(generic(-7i16, 2), (-3, -1));
+    assert_eq!(i32::MIN.div_rem(-1), (i32::MIN, 0));
+    assert_eq!(
+        Wrapping(i64::MIN).div_rem(Wrapping(-1)),
+        (Wrapping(i64::MIN), Wrapping(0))
+    );
+    assert_eq!(
+        Saturating(i128::MIN).div_rem(Saturating(-1)),
+        (Saturating(i128::MAX), Saturating(0))
+    );
+    assert_eq!(
+        Saturating(i8::MIN).div_rem(Saturating(-2)),
+        (Saturating(64), Saturating(0))
+    );
+    for _ in 0..10_000 {
+        let duo = random::<u128>() >> (random::<u32>() & 127);
+        let div = (random::<u128>() >> (random::<u32>() & 127)) | 1;
+        assert_eq!(
+            Wrapping(duo).div_rem(Wrapping(div)),
+            (Wrapping(duo / div), Wrapping(duo % div))
+        );
+        let (duo, div) = (duo as i64, div as i64);
+        assert_eq!(
+            Saturating(duo).div_rem(Saturating(div)),
+            (
+                Saturating(duo.saturating_div(div)),
+                Saturating(duo.wrapping_rem(div))
+            )
+        );
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn bit_scan_test() {
+    use specialized_div_rem::*;
+
+    macro_rules! check {
+        ($uX:ident, $lz:ident, $tz:ident, $lo:ident, $to:ident) => {
+            let mut cases = vec![
+                0,
+                1,
+                $uX::MAX,
+                $uX::MAX - 1,
+                $uX::MAX >> 1,
+                !($uX::MAX >> 1),
+            ];
+            for _ in 0..10_000 {
+                // random runs of zeros and ones at both ends
+                let x = random::<$uX>() >> (random::<u32>() % $uX::BITS);
+                let x = x << (random::<u32>() % $uX::BITS);
+                cases.push(x);
+                cases.push(!x);
+            }
+            for x in cases {
+                assert_eq!($lz(x), x.leading_zeros());
+                assert_eq!($tz(x), x.trailing_zeros());
+                assert_eq!($lo(x), x.leading_ones());
+                assert_eq!($to(x), x.trailing_ones());
+            }
+        };
+    }
+
+    check!(
+        u8,
+        u8_leading_zeros,
+        u8_trailing_zeros,
+        u8_leading_ones,
+        u8_trailing_ones
+    );
+    check!(
+        u16,
+        u16_leading_zeros,
+        u16_trailing_zeros,
+        u16_leading_ones,
+        u16_trailing_ones
+    );
+    check!(
+        u32,
+        u32_leading_zeros,
+        u32_trailing_zeros,
+        u32_leading_ones,
+        u32_trailing_ones
+    );
+    check!(
+        u64,
+        u64_leading_zeros,
+        u64_trailing_zeros,
+        u64_leading_ones,
+        u64_trailing_ones
+    );
+    check!(
+        u128,
+        u128_leading_zeros,
+        u128_trailing_zeros,
+        u128_leading_ones,
+        u128_trailing_ones
+    );
+    check!(
+        usize,
+        usize_leading_zeros,
+        usize_trailing_zeros,
+        usize_leading_ones,
+        usize_trailing_ones
+    );
+    // usable in constants
+    const LZ: u32 = u32_leading_zeros(1);
+    assert_eq!(LZ, 31);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn bit_util_test() {
+    use specialized_div_rem::*;
+
+    macro_rules! check {
+        ($uX:ident, $is_pow:ident, $next_pow:ident, $checked_next_pow:ident, $bit_width:ident) => {
+            let mut cases = vec![0, 1, 2, 3, $uX::MAX, $uX::MAX >> 1, ($uX::MAX >> 1) + 2];
+            for _ in 0..10_000 {
+                let x = random::<$uX>() >> (random::<u32>() % $uX::BITS);
+                cases.push(x);
+                cases.push(x & x.wrapping_neg());
+            }
+            for x in cases {
+                assert_eq!($is_pow(x), x.is_power_of_two());
+                assert_eq!($checked_next_pow(x), x.checked_next_power_of_two());
+                assert_eq!($next_pow(x), x.checked_next_power_of_two().unwrap_or(0));
+                assert_eq!($bit_width(x), $uX::BITS - x.leading_zeros());
+            }
+        };
+    }
+
+    check!(
+        u8,
+        u8_is_power_of_two,
+        u8_next_power_of_two,
+        u8_checked_next_power_of_two,
+        u8_bit_width
+    );
+    check!(
+        u16,
+        u16_is_power_of_two,
+        u16_next_power_of_two,
+        u16_checked_next_power_of_two,
+        u16_bit_width
+    );
+    check!(
+        u32,
+        u32_is_power_of_two,
+        u32_next_power_of_two,
+        u32_checked_next_power_of_two,
+        u32_bit_width
+    );
+    check!(
+        u64,
+        u64_is_power_of_two,
+        u64_next_power_of_two,
+        u64_checked_next_power_of_two,
+        u64_bit_width
+    );
+    check!(
+        u128,
+        u128_is_power_of_two,
+        u128_next_power_of_two,
+        u128_checked_next_power_of_two,
+        u128_bit_width
+    );
+    check!(
+        usize,
+        usize_is_power_of_two,
+        usize_next_power_of_two,
+        usize_checked_next_power_of_two,
+        usize_bit_width
+    );
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn mean_test() {
+    use specialized_div_rem::{u128_mean, u64_mean};
+
+    assert_eq!(u64_mean(&[u64::MAX; 5]), (u64::MAX, 0));
+    assert_eq!(u64_mean(&[u64::MAX, u64::MAX - 1]), (u64::MAX - 1, 1));
+    assert_eq!(u128_mean(&[u128::MAX; 5]), (u128::MAX, 0));
+    assert_eq!(u128_mean(&[u128::MAX, u128::MAX - 1]), (u128::MAX - 1, 1));
+    assert_eq!(
+        u128_mean(&[u128::MAX, u128::MAX, 1]),
+        (u128::MAX / 3 * 2, 1)
+    );
+    for _ in 0..1_000 {
+        let len = 1 + (random::<usize>() % 100);
+        let x: Vec<u64> = (0..len).map(|_| random()).collect();
+        let sum: u128 = x.iter().map(|x| *x as u128).sum();
+        let (quo, rem) = u64_mean(&x);
+        assert_eq!(
+            (quo as u128, rem as u128),
+            (sum / (len as u128), sum % (len as u128))
+        );
+        // small enough that the sum does not overflow a `u128`
+        let x: Vec<u128> = (0..len).map(|_| random::<u128>() >> 8).collect();
+        let sum: u128 = x.iter().sum();
+        assert_eq!(u128_mean(&x), (sum / (len as u128), sum % (len as u128)));
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+#[should_panic]
+fn mean_empty() {
+    specialized_div_rem::u128_mean(&[]);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn decompose_test() {
+    use specialized_div_rem::{u64_decompose, U64MixedRadix};
+
+    let mut out = [0; 4];
+    u64_decompose(100_000, &[60, 60, 24], &mut out);
+    assert_eq!(out, [40, 46, 3, 1]);
+    const DHMS: U64MixedRadix<3> = U64MixedRadix::new([60, 60, 24]);
+    let mut out = [0; 4];
+    DHMS.decompose(100_000, &mut out);
+    assert_eq!(out, [40, 46, 3, 1]);
+
+    let mut expected = [0; 4];
+    let mut found = [0; 4];
+    for _ in 0..10_000 {
+        let bases = [
+            random::<u64>() >> (random::<u32>() & 63) | 1,
+            random::<u64>() >> (random::<u32>() & 63) | 1,
+            random::<u64>() >> (random::<u32>() & 63) | 1,
+        ];
+        let x = random::<u64>() >> (random::<u32>() & 63);
+        u64_decompose(x, &bases, &mut expected);
+        U64MixedRadix::new(bases).decompose(x, &mut found);
+        assert_eq!(expected, found);
+        // recompose
+        let mut y = expected[3] as u128;
+        for i in (0..3).rev() {
+            y = (y * bases[i] as u128) + expected[i] as u128;
+        }
+        assert_eq!(y, x as u128);
+        for i in 0..3 {
+            assert!(expected[i] < bases[i]);
+        }
+    }
+    let radix = U64MixedRadix::new([1, u64::MAX, 1000]);
+    radix.decompose(u64::MAX, &mut found);
+    assert_eq!(found, [0, 0, 1, 0]);
+}
+
+// `catch_unwind` needs unwinding, which `wasm32-unknown-unknown` does not have
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn panic_location_test() {
+    use specialized_div_rem::{DivRem, U64Ratio};
+    use std::{cell::Cell, panic};
+
+    thread_local! {
+        static LINE: Cell<u32> = const { Cell::new(0) };
+    }
+    let prev = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let location = info.location().unwrap();
+        if location.file() == file!() {
+            LINE.with(|l| l.set(location.line()));
+        }
+        prev(info)
+    }));
+    // division by zero should be reported at the line of the call, not inside the crate
+    macro_rules! check {
+        ($e:expr) => {
+            LINE.with(|l| l.set(0));
+            let line = line!();
+            assert!(panic::catch_unwind(|| $e).is_err());
+            assert_eq!(LINE.with(|l| l.get()), line);
+        };
+    }
+    check!(u64_div_rem_delegate(1, 0));
+    check!(i64_div_rem_trifecta(1, 0));
+    check!(u128_div_rem_asymmetric(1, 0));
+    check!(i128_div_rem_delegate(1, 0));
+    check!(u8_div_rem_binary_long(1, 0));
+    check!(1u32.div_rem(0));
+    check!(U64Ratio::new(1, 0));
+    check!(U64Ratio::new(1, 2).div_rem(0));
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn rng_test() {
+    // the stream of a seed has to be the same on every platform
+    let mut rng = Rng::new(0);
+    assert_eq!(rng.next_u64(), 0x7bbc_b40d_5506_82d0);
+    assert_eq!(rng.next_u64(), 0xde7f_e413_d00c_c9fd);
+    assert_eq!(rng.next_u64(), 0xb3c6_3835_3c66_8c91);
+    let mut rng0 = Rng::new(12345);
+    let mut rng1 = rng0.clone();
+    for _ in 0..1000 {
+        assert_eq!(rng0.random::<u128>(), rng1.random::<u128>());
+    }
+    // every bit should be set about half of the time
+    let mut counts = [0u32; 128];
+    for _ in 0..10_000 {
+        let x = rng0.random::<u128>();
+        for (i, count) in counts.iter_mut().enumerate() {
+            *count += ((x >> i) & 1) as u32;
+        }
+    }
+    for count in counts {
+        assert!((4_500..5_500).contains(&count));
+    }
+}
+
+#[cfg(feature = "instrument")]
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn iteration_bounds() {
+    use specialized_div_rem::instrument::{take, Loop};
+
+    // The corpus of the brute force fuzzer of `test!` (single continuous strings of ones) and random
+    // values with random numbers of leading zeros
+    macro_rules! corpus {
+        ($uX:ident) => {{
+            let n = $uX::BITS;
+            let mut corpus: Vec<$uX> = Vec::new();
+            for len in 0..n {
+                for shift in 0..=len {
+                    corpus.push(($uX::MAX >> len) << shift);
+                }
+            }
+            for _ in 0..n {
+                corpus.push(random::<$uX>() >> (random::<u32>() % n));
+            }
+            corpus
+        }};
+    }
+
+    // asserts that every division of `$uX`s in the corpus takes at most `$bound` iterations of
+    // `$loop`, and that the bound is reached (so that it is not loose)
+    macro_rules! check {
+        ($uX:ident, $fn:ident, $loop:expr, $bound:expr) => {
+            let corpus = corpus!($uX);
+            let mut max = 0;
+            take($loop);
+            for duo in corpus.iter().copied() {
+                for div in corpus.iter().copied() {
+                    if div != 0 {
+                        $fn(duo, div);
+                        let count = take($loop);
+                        assert!(
+                            count <= $bound,
+                            "{} took {} iterations with duo:{} div:{}",
+                            stringify!($fn),
+                            count,
+                            duo,
+                            div
+                        );
+                        max = max.max(count);
+                    }
+                }
+            }
+            assert_eq!(max, $bound, "{}", stringify!($fn));
+        };
+    }
+
+    // the opening of the SWAR algorithm does the first one or two steps
+    check!(u32, u32_div_rem_binary_long, Loop::BinaryLong, 32 - 2);
+    check!(u64, u64_div_rem_binary_long, Loop::BinaryLong, 64 - 2);
+    // the binary long division in `_delegate` delegates to the half division once `duo` fits in it
+    check!(u64, u64_div_rem_delegate, Loop::Delegate, 32);
+    check!(u128, u128_div_rem_delegate, Loop::Delegate, 64);
+    // at least `n_h - 1` bits are cleared per step, so that there are at most `ceil(n / (n_h - 1))`
+    // steps
+    check!(
+        u64,
+        u64_div_rem_trifecta,
+        Loop::Undersubtracting,
+        32u32.div_ceil(15)
+    );
+    check!(
+        u128,
+        u128_div_rem_trifecta,
+        Loop::Undersubtracting,
+        64u32.div_ceil(31)
+    );
+}
+
+/// Returns the current stack pointer
+#[cfg(any(target_arch = "x86_64", target_arch = "x86", target_arch = "aarch64"))]
+#[inline(always)]
+fn stack_pointer() -> usize {
+    let sp: usize;
+    unsafe {
+        #[cfg(target_arch = "x86_64")]
+        core::arch::asm!("mov {}, rsp", out(reg) sp, options(nomem, nostack, preserves_flags));
+        #[cfg(target_arch = "x86")]
+        core::arch::asm!("mov {}, esp", out(reg) sp, options(nomem, nostack, preserves_flags));
+        #[cfg(target_arch = "aarch64")]
+        core::arch::asm!("mov {}, sp", out(reg) sp, options(nomem, nostack, preserves_flags));
+    }
+    sp
+}
+
+/// Returns the number of bytes of stack used by `f`, measured by painting the stack below the
+/// stack pointer with a pattern and finding the lowest byte that was overwritten. `f` runs on a
+/// new thread with a known stack size, so that the painted bytes are always inside the unused part
+/// of the stack allocation of that thread (with the main test threads, painting below the stack
+/// pointer could run past the end of a small stack or into a guard page).
+#[cfg(any(target_arch = "x86_64", target_arch = "x86", target_arch = "aarch64"))]
+fn stack_usage(f: &(dyn Fn() + Sync)) -> usize {
+    // the painted depth, and the size of the stack of the thread, which also has to fit the
+    // frames of the thread entry point (and the TLS block on some platforms)
+    const DEPTH: usize = 1 << 14;
+    const STACK: usize = 1 << 18;
+    const PAINT: u8 = 0xa5;
+
+    #[inline(never)]
+    fn paint_and_run(f: &dyn Fn()) -> usize {
+        use core::ptr::{read_volatile, write_volatile};
+
+        let bottom = (stack_pointer() - DEPTH) as *mut u8;
+        // no calls are made until `f`, so nothing else uses the painted bytes in between
+        for i in 0..DEPTH {
+            unsafe { write_volatile(bottom.add(i), PAINT) };
+        }
+        f();
+        for i in 0..DEPTH {
+            if unsafe { read_volatile(bottom.add(i)) } != PAINT {
+                return DEPTH - i;
+            }
+        }
+        0
+    }
+
+    std::thread::scope(|scope| {
+        std::thread::Builder::new()
+            .stack_size(STACK)
+            .spawn_scoped(scope, || paint_and_run(f))
+            .unwrap()
+            .join()
+            .unwrap()
+    })
+}
+
+// the `release` job of CI also runs this with optimizations
+#[cfg(any(target_arch = "x86_64", target_arch = "x86", target_arch = "aarch64"))]
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn stack_usage_test() {
+    use std::hint::black_box;
+
+    // The division functions have no recursion or arrays, so their stack usage is constant and
+    // should fit in a 1 KiB interrupt stack with room to spare. On x86_64 every function uses less
+    // than 256 bytes in both the test and release profiles, and the budget leaves room for the less
+    // efficient code of other targets, such as 32 bit microcontrollers where a `u128` takes 4
+    // registers.
+    macro_rules! check {
+        ($uX:ident, $iX:ident, $budget:expr, $($unsigned_fn:ident, $signed_fn:ident);+;) => {
+            // operands exercising the different branches of the algorithms
+            let n = $uX::BITS;
+            let mut operands = vec![0, 1, 3, $uX::MAX];
+            for shift in [n / 4, n / 2 - 1, n / 2, n / 2 + 1, (3 * n) / 4, n - 1] {
+                operands.push($uX::MAX >> shift);
+                operands.push(($uX::MAX >> shift) - 1);
+                operands.push(1 << shift);
+            }
+            $(
+                let mut max = 0;
+                for duo in operands.iter().copied() {
+                    for div in operands.iter().copied().filter(|div| *div != 0) {
+                        max = max.max(stack_usage(&|| {
+                            black_box($unsigned_fn(black_box(duo), black_box(div)));
+                        }));
+                        max = max.max(stack_usage(&|| {
+                            black_box($signed_fn(black_box(duo as $iX), black_box(div as $iX)));
+                        }));
+                    }
+                }
+                assert!(
+                    max <= $budget,
+                    "{} used {} bytes of stack",
+                    stringify!($unsigned_fn),
+                    max
+                );
+            )+
+        };
+    }
+
+    check!(
+        u8,
+        i8,
+        512,
+        u8_div_rem_binary_long, i8_div_rem_binary_long;
+        u8_div_rem_table, i8_div_rem_table;
+    );
+    check!(
+        u16,
+        i16,
+        512,
+        u16_div_rem_binary_long, i16_div_rem_binary_long;
+        u16_div_rem_table, i16_div_rem_table;
+    );
+    check!(
+        u32,
+        i32,
+        512,
+        u32_div_rem_binary_long, i32_div_rem_binary_long;
+        u32_div_rem_delegate, i32_div_rem_delegate;
+    );
+    check!(
+        u64,
+        i64,
+        512,
+        u64_div_rem_binary_long, i64_div_rem_binary_long;
+        u64_div_rem_delegate, i64_div_rem_delegate;
+        u64_div_rem_trifecta, i64_div_rem_trifecta;
+        u64_div_rem_trifecta_soft_lz, i64_div_rem_trifecta_soft_lz;
+        u64_div_rem_asymmetric, i64_div_rem_asymmetric;
+        u64_div_rem_float_reciprocal, i64_div_rem_float_reciprocal;
+    );
+    check!(
+        u128,
+        i128,
+        512,
+        u128_div_rem_delegate, i128_div_rem_delegate;
+        u128_div_rem_trifecta, i128_div_rem_trifecta;
+        u128_div_rem_trifecta_soft_lz, i128_div_rem_trifecta_soft_lz;
+        u128_div_rem_asymmetric, i128_div_rem_asymmetric;
+        u128_div_rem_asymmetric_float_reciprocal, i128_div_rem_asymmetric_float_reciprocal;
+    );
+}
+
+#[cfg(all(feature = "fixed", feature = "width-128", feature = "trifecta"))]
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn fixed_wide_div_test() {
+    use fixed::{
+        types::extra::{U0, U1, U127, U128, U31, U32, U63, U64},
+        FixedI128, FixedI64, FixedU128, FixedU64,
+    };
+    use specialized_div_rem::WideDiv;
+
+    // compares against the division of the `fixed` crate on random values with random numbers of
+    // leading zeros, and on the extreme values
+    macro_rules! check {
+        ($Fixed:ident, $iX:ident, $($Frac:ident),+) => {
+            $(
+                let n = $iX::BITS;
+                let mut corpus = vec![$iX::MIN, $iX::MAX, 0, 1, (-1i8) as $iX, 2];
+                for _ in 0..200 {
+                    corpus.push(random::<$iX>() >> (random::<u32>() % n));
+                }
+                for duo in corpus.iter().copied() {
+                    for div in corpus.iter().copied() {
+                        let duo = $Fixed::<$Frac>::from_bits(duo);
+                        let div = $Fixed::<$Frac>::from_bits(div);
+                        assert_eq!(
+                            duo.checked_wide_div(div),
+                            duo.checked_div(div),
+                            "{}<{}> duo:{:?} div:{:?}",
+                            stringify!($Fixed),
+                            stringify!($Frac),
+                            duo,
+                            div
+                        );
+                    }
+                }
+            )+
+        };
+    }
+
+    check!(FixedU64, u64, U0, U1, U31, U32, U63, U64);
+    check!(FixedI64, i64, U0, U1, U31, U32, U63, U64);
+    check!(FixedU128, u128, U0, U1, U63, U64, U127, U128);
+    check!(FixedI128, i128, U0, U1, U63, U64, U127, U128);
+
+    type Fix = FixedI128<U64>;
+    assert_eq!(
+        Fix::from_num(-3).wide_div(Fix::from_num(0.5)),
+        Fix::from_num(-6)
+    );
+}
+
+#[cfg(feature = "self-check")]
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn self_check_test() {
+    use specialized_div_rem::{
+        i128_div_rem_self_checked, i32_div_rem_self_checked, i8_div_rem_self_checked,
+        u128_div_rem_self_checked, u16_div_rem_self_checked, u64_div_rem_self_checked,
+    };
+
+    assert_eq!(i8_div_rem_self_checked(i8::MIN, -1), (i8::MIN, 0));
+    assert_eq!(u16_div_rem_self_checked(1000, 7), (142, 6));
+    for _ in 0..10_000 {
+        let duo = random::<u128>() >> (random::<u32>() & 127);
+        let div = (random::<u128>() >> (random::<u32>() & 127)) | 1;
+        assert_eq!(u128_div_rem_self_checked(duo, div), (duo / div, duo % div));
+        let (duo, div) = (duo as i128, div as i128);
+        assert_eq!(
+            i128_div_rem_self_checked(duo, div),
+            (duo.wrapping_div(div), duo.wrapping_rem(div))
+        );
+        u64_div_rem_self_checked(duo as u64, div as u64);
+        i32_div_rem_self_checked(duo as i32, div as i32);
+    }
+}
+
+#[cfg(feature = "self-check")]
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+#[should_panic(expected = "attempt to divide by zero")]
+fn self_check_zero() {
+    specialized_div_rem::u64_div_rem_self_checked(1, 0);
+}
+
+#[cfg(feature = "ethnum")]
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn ethnum_test() {
+    use ethnum::{I256, U256};
+    use specialized_div_rem::DivRem;
+
+    // random values with random numbers of leading zeros
+    let mut corpus = vec![U256::MAX, U256::ZERO, U256::ONE, I256::MIN.as_u256()];
+    for _ in 0..200 {
+        corpus.push(U256::from_words(random(), random()) >> (random::<u32>() % 256));
+    }
+    for duo in corpus.iter().copied() {
+        assert_eq!(U256::from(Uint::<4>::from(duo)), duo);
+        for div in corpus.iter().copied() {
+            if div != 0 {
+                assert_eq!(DivRem::div_rem(duo, div), (duo / div, duo % div));
+            }
+            let (duo, div) = (duo.as_i256(), div.as_i256());
+            if div != 0 && !(duo == I256::MIN && div == -1) {
+                assert_eq!(DivRem::div_rem(duo, div), (duo / div, duo % div));
+            }
+        }
+    }
+    assert_eq!(
+        DivRem::div_rem(I256::MIN, I256::MINUS_ONE),
+        (I256::MIN, I256::ZERO)
+    );
+}
+
+#[cfg(feature = "big-digit")]
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn big_digit_test() {
+    use num_bigint::BigUint;
+    use specialized_div_rem::bignum::{div_rem_big_digits, BigDigit};
+
+    #[cfg(target_pointer_width = "64")]
+    fn to_digits(x: &BigUint) -> Vec<BigDigit> {
+        x.to_u64_digits()
+    }
+    #[cfg(not(target_pointer_width = "64"))]
+    fn to_digits(x: &BigUint) -> Vec<BigDigit> {
+        x.to_u32_digits()
+    }
+
+    // random numbers of random lengths, with random numbers of leading zeros, along with runs of
+    // ones that stress the borrows
+    let mut corpus = vec![BigUint::from(1u8)];
+    for len in 1..8 {
+        corpus.push((BigUint::from(1u8) << (64 * len)) - 1u8);
+        for _ in 0..8 {
+            let mut x = BigUint::from(0u8);
+            for _ in 0..len {
+                x = (x << 64) | BigUint::from(random::<u64>());
+            }
+            corpus.push(x >> (random::<u32>() % 64));
+        }
+    }
+    for duo in &corpus {
+        for div in &corpus {
+            if *div == BigUint::from(0u8) {
+                continue;
+            }
+            let duo_digits = to_digits(duo);
+            // extra leading zero limbs are allowed
+            let mut div_digits = to_digits(div);
+            div_digits.push(0);
+            let mut quo = vec![0; duo_digits.len()];
+            let mut rem = vec![0; div_digits.len()];
+            div_rem_big_digits(&duo_digits, &div_digits, &mut quo, &mut rem);
+            let mut expected_quo = to_digits(&(duo / div));
+            expected_quo.resize(quo.len(), 0);
+            let mut expected_rem = to_digits(&(duo % div));
+            expected_rem.resize(rem.len(), 0);
+            assert_eq!(
+                (quo, rem),
+                (expected_quo, expected_rem),
+                "duo:{} div:{}",
+                duo,
+                div
+            );
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn burnikel_ziegler_test() {
+    use num_bigint::BigUint;
+    use specialized_div_rem::bignum::div_rem_burnikel_ziegler;
+
+    fn from_limbs(x: &[u64]) -> BigUint {
+        BigUint::new(
+            x.iter()
+                .flat_map(|l| [*l as u32, (*l >> 32) as u32])
+                .collect(),
+        )
+    }
+
+    // Divisors long enough to recurse several times (including odd lengths that are padded), with
+    // random limbs and runs of ones, along with dividends from shorter than the divisor to several
+    // times as long. The dividends that are multiples of the divisor minus one stress the quotient
+    // estimates that are too large.
+    for div_len in [1, 20, 33, 47, 64, 65, 100, 129, 200] {
+        let mut divs = vec![vec![u64::MAX; div_len]];
+        for _ in 0..3 {
+            let mut div: Vec<u64> = (0..div_len).map(|_| random()).collect();
+            // the most significant limb is kept nonzero, so that the divisor is not 0
+            div[div_len - 1] = (div[div_len - 1] >> (random::<u32>() % 64)) | 1;
+            divs.push(div);
+        }
+        for div in &divs {
+            let div_big = from_limbs(div);
+            for duo_len in [
+                div_len / 2,
+                div_len,
+                div_len + 1,
+                2 * div_len,
+                3 * div_len + 7,
+            ] {
+                let mut duos = Vec::new();
+                let duo: Vec<u64> = (0..duo_len).map(|_| random()).collect();
+                duos.push(from_limbs(&duo));
+                duos.push((BigUint::from(1u8) << (64 * duo_len)) - 1u8);
+                if duo_len >= div_len {
+                    let shift = 64 * (duo_len - div_len);
+                    duos.push(((&div_big << shift) - 1u8) >> (random::<u32>() % 64));
+                    duos.push(&div_big * ((BigUint::from(1u8) << shift) - 1u8));
+                }
+                for duo_big in &duos {
+                    let mut duo = duo_big.to_u64_digits();
+                    // extra leading zero limbs are allowed
+                    duo.push(0);
+                    let mut quo = vec![0; duo.len()];
+                    let mut rem = vec![0; div.len()];
+                    div_rem_burnikel_ziegler(&duo, div, &mut quo, &mut rem);
+                    let mut expected_quo = (duo_big / &div_big).to_u64_digits();
+                    expected_quo.resize(quo.len(), 0);
+                    let mut expected_rem = (duo_big % &div_big).to_u64_digits();
+                    expected_rem.resize(rem.len(), 0);
+                    assert_eq!(
+                        (quo, rem),
+                        (expected_quo, expected_rem),
+                        "duo_len:{}",
+                        duo_len
+                    );
+                }
+            }
+        }
+    }
+}
+
+// the unchecked functions are left out with the `no-unsafe` feature
+#[cfg(not(feature = "no-unsafe"))]
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn normalized_test() {
+    use specialized_div_rem::{
+        u128_by_u64_div_rem_normalized, u32_div_rem_normalized, u64_by_u32_div_rem_normalized,
+        u64_div_rem_normalized,
+    };
+
+    for i in 0..100_000 {
+        // the edge cases of the digit corrections are near the extremes of `div` and `duo`
+        let div: u64 = match i % 4 {
+            0 => random::<u64>() | (1 << 63),
+            1 => (1 << 63) | (random::<u64>() >> (random::<u32>() % 64)),
+            2 => (u64::MAX << (random::<u32>() % 63)) | (1 << 63),
+            _ => (1 << 63) + (random::<u64>() % 4),
+        };
+        let duo_hi = match i % 3 {
+            0 => random::<u64>() % div,
+            1 => div - 1 - (random::<u64>() % 4),
+            _ => 0,
+        };
+        let duo_lo: u64 = if (i % 5) == 0 {
+            u64::MAX - (random::<u64>() % 4)
+        } else {
+            random()
+        };
+        let duo = ((duo_hi as u128) << 64) | (duo_lo as u128);
+        assert_eq!(
+            unsafe { u128_by_u64_div_rem_normalized(duo, div) },
+            ((duo / (div as u128)) as u64, (duo % (div as u128)) as u64)
+        );
+        assert_eq!(
+            unsafe { u64_div_rem_normalized(duo_lo, div) },
+            (duo_lo / div, duo_lo % div)
+        );
+
+        let div = (div >> 32) as u32;
+        let duo_hi = (duo_hi >> 32) as u32 % div;
+        let duo_lo = duo_lo as u32;
+        let duo = ((duo_hi as u64) << 32) | (duo_lo as u64);
+        assert_eq!(
+            unsafe { u64_by_u32_div_rem_normalized(duo, div) },
+            ((duo / (div as u64)) as u32, (duo % (div as u64)) as u32)
+        );
+        assert_eq!(
+            unsafe { u32_div_rem_normalized(duo_lo, div) },
+            (duo_lo / div, duo_lo % div)
+        );
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn fold_test() {
+    use specialized_div_rem::{u128_rem_pow2m1, u128_rem_pow2p1, u64_rem_pow2m1, u64_rem_pow2p1};
+
+    for k in 1..=64 {
+        let m = u64::MAX >> (64 - k);
+        for x in [0, 1, m - 1, m, m.wrapping_add(1), u64::MAX - 1, u64::MAX] {
+            assert_eq!(u64_rem_pow2m1(x, k), x % m);
+        }
+    }
+    for k in 1..=128 {
+        let m = u128::MAX >> (128 - k);
+        for x in [0, 1, m - 1, m, m.wrapping_add(1), u128::MAX - 1, u128::MAX] {
+            assert_eq!(u128_rem_pow2m1(x, k), x % m);
+        }
+    }
+    for k in 1..64 {
+        let m = (1u64 << k) + 1;
+        for x in [0, 1, m - 2, m - 1, m, m + 1, u64::MAX - 1, u64::MAX] {
+            assert_eq!(u64_rem_pow2p1(x, k), x % m);
+        }
+    }
+    for k in 1..128 {
+        let m = (1u128 << k) + 1;
+        for x in [0, 1, m - 2, m - 1, m, m + 1, u128::MAX - 1, u128::MAX] {
+            assert_eq!(u128_rem_pow2p1(x, k), x % m);
+        }
+    }
+    // against the general remainder functions
+    for _ in 0..100_000 {
+        let k = 1 + (random::<u32>() % 63);
+        let x: u64 = random::<u64>() >> (random::<u32>() % 64);
+        assert_eq!(
+            u64_rem_pow2m1(x, k),
+            u64_div_rem_delegate(x, u64::MAX >> (64 - k)).1
+        );
+        assert_eq!(
+            u64_rem_pow2p1(x, k),
+            u64_div_rem_delegate(x, (1 << k) + 1).1
+        );
+        let k = 1 + (random::<u32>() % 127);
+        let x: u128 = random::<u128>() >> (random::<u32>() % 128);
+        assert_eq!(
+            u128_rem_pow2m1(x, k),
+            u128_div_rem_trifecta(x, u128::MAX >> (128 - k)).1
+        );
+        assert_eq!(
+            u128_rem_pow2p1(x, k),
+            u128_div_rem_trifecta(x, (1 << k) + 1).1
+        );
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+#[should_panic]
+fn fold_zero() {
+    specialized_div_rem::u64_rem_pow2m1(1, 0);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn significand_test() {
+    use specialized_div_rem::{u128_div_significand, u64_div_significand, RoundBits};
+
+    // bit serial restoring division of the normalized operands
+    fn reference(duo: u128, div: u128, bits: u32) -> (u128, i32, RoundBits) {
+        let duo_lz = duo.leading_zeros();
+        let div_lz = div.leading_zeros();
+        let div = div << div_lz;
+        let mut rem = duo << duo_lz;
+        let mut pos = 0i32;
+        let mut bit = rem >= div;
+        if bit {
+            rem -= div;
+        }
+        let mut quo: u128 = 0;
+        let mut count = 0;
+        loop {
+            if (quo != 0) || bit {
+                quo = (quo << 1) | (bit as u128);
+                count += 1;
+                if count == bits + 2 {
+                    break;
+                }
+            }
+            pos -= 1;
+            let carry = (rem >> 127) != 0;
+            rem <<= 1;
+            bit = carry || (rem >= div);
+            if bit {
+                rem = rem.wrapping_sub(div);
+            }
+        }
+        let round_bits = RoundBits {
+            guard: (quo & 0b10) != 0,
+            round: (quo & 0b1) != 0,
+            sticky: rem != 0,
+        };
+        (
+            quo >> 2,
+            pos + 2 + (div_lz as i32) - (duo_lz as i32),
+            round_bits,
+        )
+    }
+
+    assert_eq!(u64_div_significand(0, 3, 24), (0, 0, RoundBits::default()));
+    // 1 / 3 = 0b0.010101...
+    assert_eq!(
+        u64_div_significand(1, 3, 4),
+        (
+            0b1010,
+            -5,
+            RoundBits {
+                guard: true,
+                round: false,
+                sticky: true
+            }
+        )
+    );
+    let (quo, exp, round_bits) = u128_div_significand(6, 3, 113);
+    assert_eq!((quo, exp), (1 << 112, -111));
+    assert!(round_bits.is_exact());
+    for _ in 0..100_000 {
+        let duo = random::<u64>() >> (random::<u32>() % 64);
+        let div = (random::<u64>() >> (random::<u32>() % 64)) | 1;
+        let bits = 1 + (random::<u32>() % 62);
+        let (quo, exp, round_bits) = u64_div_significand(duo, div, bits);
+        if duo == 0 {
+            assert_eq!((quo, exp, round_bits), (0, 0, RoundBits::default()));
+            continue;
+        }
+        assert_eq!(
+            (quo as u128, exp, round_bits),
+            reference(duo as u128, div as u128, bits)
+        );
+        // against the exact quotient `floor(duo * 2^(2 - exp) / div)`
+        let shift = 2 - exp;
+        let (tmp, sticky) = if shift >= 0 {
+            let duo = (duo as u128) << shift;
+            (duo / (div as u128), !duo.is_multiple_of(div as u128))
+        } else {
+            let lost = (duo & ((1 << -shift) - 1)) != 0;
+            (
+                ((duo >> -shift) / div) as u128,
+                lost || !(duo >> -shift).is_multiple_of(div),
+            )
+        };
+        assert_eq!(tmp >> 2, quo as u128);
+        assert_eq!(
+            round_bits,
+            RoundBits {
+                guard: (tmp & 0b10) != 0,
+                round: (tmp & 0b1) != 0,
+                sticky
+            }
+        );
+
+        let duo = random::<u128>() >> (random::<u32>() % 128);
+        let div = (random::<u128>() >> (random::<u32>() % 128)) | 1;
+        let bits = 1 + (random::<u32>() % 126);
+        if duo != 0 {
+            assert_eq!(
+                u128_div_significand(duo, div, bits),
+                reference(duo, div, bits)
+            );
+        }
+    }
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn root_test() {
+    use specialized_div_rem::{
+        u128_icbrt, u128_isqrt, u128_nth_root, u64_icbrt, u64_isqrt, u64_nth_root,
+    };
+
+    // checks that `y` is the floor of the `n`th root of `x`
+    fn check(x: u128, n: u32, y: u128) {
+        assert!(y.checked_pow(n).unwrap() <= x, "{} {} {}", x, n, y);
+        if let Some(tmp) = y.checked_add(1).and_then(|y| y.checked_pow(n)) {
+            assert!(tmp > x, "{} {} {}", x, n, y);
+        }
+    }
+
+    for n in 1..=130 {
+        check(0, n, u128_nth_root(0, n));
+        check(u128::MAX, n, u128_nth_root(u128::MAX, n));
+        check(u64::MAX as u128, n, u64_nth_root(u64::MAX, n) as u128);
+        // perfect powers and their neighbors
+        let mut y: u128 = 1;
+        while let Some(x) = y.checked_pow(n) {
+            for x in [x - 1, x, x.saturating_add(1)] {
+                check(x, n, u128_nth_root(x, n));
+                if let Ok(x) = u64::try_from(x) {
+                    check(x as u128, n, u64_nth_root(x, n) as u128);
+                }
+            }
+            if y > (1 << 20) {
+                break;
+            }
+            y += 1 + (y >> 4) + (random::<u128>() % (1 + (y >> 2)));
+        }
+    }
+    for _ in 0..10_000 {
+        let x: u128 = random::<u128>() >> (random::<u32>() % 128);
+        let n = 1 + (random::<u32>() % 20);
+        check(x, n, u128_nth_root(x, n));
+        check(x as u64 as u128, n, u64_nth_root(x as u64, n) as u128);
+        check(x, 2, u128_isqrt(x));
+        check(x, 3, u128_icbrt(x));
+        check(x as u64 as u128, 2, u64_isqrt(x as u64) as u128);
+        check(x as u64 as u128, 3, u64_icbrt(x as u64) as u128);
+    }
+    // the largest perfect squares and cubes
+    assert_eq!(u64_isqrt(u64::MAX), u32::MAX as u64);
+    assert_eq!(u128_isqrt(u128::MAX), u64::MAX as u128);
+    assert_eq!(u64_icbrt(2642245u64.pow(3)), 2642245);
+    assert_eq!(u64_icbrt(2642245u64.pow(3) - 1), 2642244);
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn swar_steps_test() {
+    use specialized_div_rem::{impl_swar_steps, u32_swar_steps, u64_swar_steps};
+
+    // the Rust loops, which `u32_swar_steps` and `u64_swar_steps` replace with assembly on some
+    // targets
+    impl_swar_steps!(rust_u32_swar_steps, 32, u32, i32,);
+    impl_swar_steps!(rust_u64_swar_steps, 64, u64, i64,);
+
+    // the steps are pure bit manipulations, so the outputs have to match for all inputs and not
+    // just the ones that the division setup produces
+    for _ in 0..100_000 {
+        let duo: u64 = random::<u64>() >> (random::<u32>() % 64);
+        let div: u64 = random::<u64>() >> (random::<u32>() % 64);
+        let steps = random::<u32>() % 65;
+        assert_eq!(
+            u64_swar_steps(duo, div, steps),
+            rust_u64_swar_steps(duo, div, steps)
+        );
+        let steps = steps % 33;
+        assert_eq!(
+            u32_swar_steps(duo as u32, div as u32, steps),
+            rust_u32_swar_steps(duo as u32, div as u32, steps)
+        );
+    }
+}